@@ -0,0 +1,617 @@
+//! Off-chain resolution of the authority of a signer.
+//!
+//! `explain_authority` follows the same rules as the program to determine whether
+//! a signer would act as the update authority, holder, metadata delegate or token
+//! delegate of an asset, and the reasons why the remaining authority types do not
+//! apply. This allows clients to report why an instruction would fail before it is
+//! signed.
+
+use solana_program::{clock::Clock, pubkey::Pubkey};
+
+use crate::{
+    accounts::{Metadata, MetadataDelegateRecord, TokenRecord},
+    errors::MplTokenMetadataError,
+    types::{
+        AuthorityType, Collection, CollectionDetailsToggle, CollectionToggle, DelegateArgs,
+        DelegateExpiry, MetadataDelegateRole, TokenDelegateRole, TokenStandard, UpdateArgs,
+        UsesToggle,
+    },
+};
+
+/// Length of an SPL Token account (without extensions).
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Metadata delegate roles that can update an asset.
+const UPDATE_METADATA_DELEGATE_ROLES: &[MetadataDelegateRole] = &[
+    MetadataDelegateRole::AuthorityItem,
+    MetadataDelegateRole::Data,
+    MetadataDelegateRole::DataItem,
+    MetadataDelegateRole::Collection,
+    MetadataDelegateRole::CollectionItem,
+    MetadataDelegateRole::ProgrammableConfig,
+    MetadataDelegateRole::ProgrammableConfigItem,
+];
+
+/// Collection-level metadata delegate roles that can update an asset.
+const UPDATE_COLLECTION_DELEGATE_ROLES: &[MetadataDelegateRole] = &[
+    MetadataDelegateRole::Data,
+    MetadataDelegateRole::Collection,
+    MetadataDelegateRole::ProgrammableConfig,
+];
+
+/// Token delegate roles that can transfer an asset.
+const TRANSFER_TOKEN_DELEGATE_ROLES: &[TokenDelegateRole] = &[
+    TokenDelegateRole::Sale,
+    TokenDelegateRole::Transfer,
+    TokenDelegateRole::LockedTransfer,
+    TokenDelegateRole::Migration,
+];
+
+/// Token account fields used to resolve the holder and token delegate authorities.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenAccount {
+    pub address: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub delegate: Option<Pubkey>,
+    pub delegated_amount: u64,
+}
+
+impl TokenAccount {
+    /// Reads a token account from its (SPL Token or Token-2022) account data.
+    pub fn from_bytes(address: Pubkey, data: &[u8]) -> Result<Self, MplTokenMetadataError> {
+        if data.len() < TOKEN_ACCOUNT_LEN {
+            return Err(MplTokenMetadataError::DataTypeMismatch);
+        }
+
+        let pubkey =
+            |offset: usize| Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap());
+        let u64_at =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Ok(Self {
+            address,
+            mint: pubkey(0),
+            owner: pubkey(32),
+            amount: u64_at(64),
+            delegate: (data[72..76] != [0; 4]).then(|| pubkey(76)),
+            delegated_amount: u64_at(121),
+        })
+    }
+}
+
+/// Operation to resolve the authority for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuthorityOperation {
+    Update(UpdateArgs),
+    Transfer { amount: u64 },
+    Delegate(DelegateArgs),
+}
+
+/// Update scenario used by the program (mirrors the program `UpdateScenario`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpdateScenario {
+    MetadataAuth,
+    Delegate,
+    Proxy,
+}
+
+/// Transfer scenario used to validate the authorization rules of programmable
+/// assets (mirrors the program `TransferScenario`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransferScenario {
+    Holder,
+    TransferDelegate,
+    SaleDelegate,
+    MigrationDelegate,
+}
+
+/// An authority type that does not apply to the signer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RejectedAuthority {
+    pub authority_type: AuthorityType,
+    pub reason: String,
+}
+
+/// Outcome of the authority resolution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorityExplanation {
+    /// Authority type of the signer (`AuthorityType::None` if no type applies).
+    pub authority_type: AuthorityType,
+    /// Role of the metadata delegate record matched.
+    pub metadata_delegate_role: Option<MetadataDelegateRole>,
+    /// Role of the token delegate matched.
+    pub token_delegate_role: Option<TokenDelegateRole>,
+    /// Update scenario (update operations only).
+    pub update_scenario: Option<UpdateScenario>,
+    /// Transfer scenario (transfers of programmable assets only).
+    pub transfer_scenario: Option<TransferScenario>,
+    /// Authority types evaluated that do not apply, in order of precedence.
+    pub rejected: Vec<RejectedAuthority>,
+    /// Error the program is expected to fail with, if any.
+    pub error: Option<MplTokenMetadataError>,
+}
+
+impl AuthorityExplanation {
+    /// Indicates whether the signer is expected to be able to perform the operation.
+    pub fn is_authorized(&self) -> bool {
+        self.error.is_none()
+    }
+
+    fn new() -> Self {
+        Self {
+            authority_type: AuthorityType::None,
+            metadata_delegate_role: None,
+            token_delegate_role: None,
+            update_scenario: None,
+            transfer_scenario: None,
+            rejected: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn reject(&mut self, authority_type: AuthorityType, reason: impl Into<String>) {
+        self.rejected.push(RejectedAuthority {
+            authority_type,
+            reason: reason.into(),
+        });
+    }
+
+    fn fail(mut self, error: MplTokenMetadataError) -> Self {
+        self.error = Some(error);
+        self
+    }
+}
+
+/// Explains which authority a signer would have for an operation over an asset.
+///
+/// The `token_record` must be the token record of `token`; the `delegate_records`
+/// are `(address, account)` pairs of the metadata delegate records that could be
+/// used by the signer. Accounts are assumed to be owned by the expected programs.
+///
+/// Delegate expiries are evaluated against `clock`, which should be the current
+/// `Clock` of the cluster; expired delegates are treated as absent.
+pub fn explain_authority(
+    metadata: &Metadata,
+    token: Option<&TokenAccount>,
+    token_record: Option<&TokenRecord>,
+    delegate_records: &[(Pubkey, MetadataDelegateRecord)],
+    signer: &Pubkey,
+    operation: &AuthorityOperation,
+    clock: &Clock,
+) -> AuthorityExplanation {
+    let context = Context {
+        metadata,
+        token,
+        token_record,
+        delegate_records,
+        signer,
+        clock,
+    };
+
+    match operation {
+        AuthorityOperation::Update(args) => context.explain_update(args),
+        AuthorityOperation::Transfer { amount } => context.explain_transfer(*amount),
+        AuthorityOperation::Delegate(args) => context.explain_delegate(args),
+    }
+}
+
+struct Context<'a> {
+    metadata: &'a Metadata,
+    token: Option<&'a TokenAccount>,
+    token_record: Option<&'a TokenRecord>,
+    delegate_records: &'a [(Pubkey, MetadataDelegateRecord)],
+    signer: &'a Pubkey,
+    clock: &'a Clock,
+}
+
+impl Context<'_> {
+    fn explain_update(&self, args: &UpdateArgs) -> AuthorityExplanation {
+        let mut explanation = AuthorityExplanation::new();
+
+        if let Some(token) = self.token {
+            if token.mint != self.metadata.mint {
+                return explanation.fail(MplTokenMetadataError::MintMismatch);
+            } else if token.amount == 0 {
+                return explanation.fail(MplTokenMetadataError::AmountMustBeGreaterThanZero);
+            }
+        }
+
+        // collection-level delegates are derived from the collection mint
+        let collection_mint = match args {
+            UpdateArgs::V1 { collection, .. }
+            | UpdateArgs::AsUpdateAuthorityV2 { collection, .. }
+            | UpdateArgs::AsCollectionDelegateV2 { collection, .. }
+            | UpdateArgs::AsCollectionItemDelegateV2 { collection, .. } => match collection {
+                CollectionToggle::Set(Collection { key, .. }) => Some(key),
+                _ => self.existing_collection_mint(),
+            },
+            _ => self.existing_collection_mint(),
+        };
+
+        let precedence = [
+            AuthorityType::Metadata,
+            AuthorityType::MetadataDelegate,
+            AuthorityType::Holder,
+        ];
+
+        for authority_type in precedence {
+            let result = match authority_type {
+                AuthorityType::Metadata => self.check_update_authority(),
+                AuthorityType::MetadataDelegate => {
+                    self.check_metadata_delegate(collection_mint).map(|role| {
+                        if explanation.authority_type == AuthorityType::None {
+                            explanation.metadata_delegate_role = Some(role);
+                        }
+                    })
+                }
+                _ => self.check_holder(),
+            };
+            self.resolve(&mut explanation, authority_type, result);
+        }
+
+        match explanation.authority_type {
+            AuthorityType::Metadata => {
+                explanation.update_scenario = Some(UpdateScenario::MetadataAuth);
+            }
+            AuthorityType::MetadataDelegate => {
+                explanation.update_scenario = Some(UpdateScenario::Delegate);
+            }
+            AuthorityType::Holder => {
+                return explanation.fail(MplTokenMetadataError::FeatureNotSupported);
+            }
+            _ => return explanation.fail(MplTokenMetadataError::InvalidAuthorityType),
+        }
+
+        if let Some(role) = &explanation.metadata_delegate_role {
+            if !is_valid_delegate_update(role, args) {
+                return explanation.fail(MplTokenMetadataError::InvalidUpdateArgs);
+            }
+        }
+
+        explanation
+    }
+
+    fn explain_transfer(&self, amount: u64) -> AuthorityExplanation {
+        let mut explanation = AuthorityExplanation::new();
+
+        if amount == 0 {
+            return explanation.fail(MplTokenMetadataError::InvalidAmount);
+        }
+
+        let token = match self.token {
+            Some(token) => token,
+            None => return explanation.fail(MplTokenMetadataError::MissingTokenAccount),
+        };
+
+        let precedence = [
+            AuthorityType::TokenDelegate,
+            AuthorityType::Holder,
+            AuthorityType::MetadataDelegate,
+            AuthorityType::Metadata,
+        ];
+
+        for authority_type in precedence {
+            let result = match authority_type {
+                AuthorityType::TokenDelegate => self
+                    .check_token_delegate(TRANSFER_TOKEN_DELEGATE_ROLES)
+                    .map(|role| {
+                        explanation.token_delegate_role = Some(role);
+                    }),
+                AuthorityType::Holder => self.check_holder(),
+                AuthorityType::MetadataDelegate => {
+                    Err("metadata delegates cannot transfer assets".to_string())
+                }
+                _ => self.check_update_authority(),
+            };
+            self.resolve(&mut explanation, authority_type, result);
+        }
+
+        let programmable = self.is_programmable();
+
+        match explanation.authority_type {
+            AuthorityType::Holder => (),
+            AuthorityType::TokenDelegate => {
+                if token.delegated_amount < amount || token.amount < amount {
+                    return explanation.fail(MplTokenMetadataError::InsufficientTokenBalance);
+                }
+            }
+            _ => {
+                if matches!(
+                    self.metadata.token_standard,
+                    Some(TokenStandard::ProgrammableNonFungible)
+                ) {
+                    return explanation.fail(MplTokenMetadataError::InvalidAuthorityType);
+                }
+
+                // non-programmable assets can be transferred by the token owner or
+                // the spl-token delegate
+                let available_amount = if token.owner == *self.signer {
+                    token.amount
+                } else if token.delegate == Some(*self.signer) {
+                    token.delegated_amount
+                } else {
+                    return explanation.fail(MplTokenMetadataError::InvalidAuthorityType);
+                };
+
+                if available_amount < amount {
+                    return explanation.fail(MplTokenMetadataError::InsufficientTokenBalance);
+                }
+            }
+        }
+
+        if programmable {
+            let token_record = match self.token_record {
+                Some(token_record) => token_record,
+                None => return explanation.fail(MplTokenMetadataError::MissingTokenRecord),
+            };
+
+            let scenario = match explanation.authority_type {
+                AuthorityType::Holder => {
+                    // an expired sale delegate no longer restricts holder transfers
+                    if token_record.delegate_role == Some(TokenDelegateRole::Sale)
+                        && !has_expired(&token_record.delegate_expiry, self.clock)
+                    {
+                        return explanation
+                            .fail(MplTokenMetadataError::OnlySaleDelegateCanTransfer);
+                    }
+                    TransferScenario::Holder
+                }
+                AuthorityType::TokenDelegate => match token_record.delegate_role {
+                    Some(TokenDelegateRole::Sale) => TransferScenario::SaleDelegate,
+                    Some(TokenDelegateRole::Migration) => TransferScenario::MigrationDelegate,
+                    Some(_) => TransferScenario::TransferDelegate,
+                    None => return explanation.fail(MplTokenMetadataError::MissingDelegateRole),
+                },
+                _ => return explanation.fail(MplTokenMetadataError::InvalidTransferAuthority),
+            };
+
+            explanation.transfer_scenario = Some(scenario);
+        }
+
+        explanation
+    }
+
+    fn explain_delegate(&self, args: &DelegateArgs) -> AuthorityExplanation {
+        let mut explanation = AuthorityExplanation::new();
+
+        match args {
+            // token and holder delegates are approved by the token owner
+            DelegateArgs::SaleV1 { .. }
+            | DelegateArgs::SaleV2 { .. }
+            | DelegateArgs::TransferV1 { .. }
+            | DelegateArgs::TransferV2 { .. }
+            | DelegateArgs::UtilityV1 { .. }
+            | DelegateArgs::UtilityV2 { .. }
+            | DelegateArgs::StakingV1 { .. }
+            | DelegateArgs::StakingV2 { .. }
+            | DelegateArgs::StandardV1 { .. }
+            | DelegateArgs::LockedTransferV1 { .. }
+            | DelegateArgs::PrintDelegateV1 { .. } => {
+                explanation.reject(
+                    AuthorityType::Metadata,
+                    "only the token owner can approve token delegates",
+                );
+
+                if self.token.is_none() {
+                    return explanation.fail(MplTokenMetadataError::MissingTokenAccount);
+                }
+
+                let result = self.check_holder();
+                self.resolve(&mut explanation, AuthorityType::Holder, result);
+
+                if explanation.authority_type != AuthorityType::Holder {
+                    return explanation.fail(match args {
+                        DelegateArgs::PrintDelegateV1 { .. } => MplTokenMetadataError::InvalidOwner,
+                        _ => MplTokenMetadataError::IncorrectOwner,
+                    });
+                }
+            }
+            // metadata delegates are approved by the update authority
+            _ => {
+                let result = self.check_update_authority();
+                self.resolve(&mut explanation, AuthorityType::Metadata, result);
+
+                explanation.reject(
+                    AuthorityType::Holder,
+                    "only the update authority can approve metadata delegates",
+                );
+
+                if explanation.authority_type != AuthorityType::Metadata {
+                    return explanation.fail(MplTokenMetadataError::UpdateAuthorityIncorrect);
+                }
+            }
+        }
+
+        explanation
+    }
+
+    /// Records the result of an authority type check, following the order of precedence.
+    fn resolve(
+        &self,
+        explanation: &mut AuthorityExplanation,
+        authority_type: AuthorityType,
+        result: Result<(), String>,
+    ) {
+        match result {
+            Ok(()) if explanation.authority_type == AuthorityType::None => {
+                explanation.authority_type = authority_type;
+            }
+            Ok(()) => {
+                let reason = format!(
+                    "superseded by {:?}, which takes precedence",
+                    explanation.authority_type
+                );
+                explanation.reject(authority_type, reason);
+            }
+            Err(reason) => explanation.reject(authority_type, reason),
+        }
+    }
+
+    fn check_update_authority(&self) -> Result<(), String> {
+        if self.metadata.update_authority == *self.signer {
+            Ok(())
+        } else {
+            Err(format!(
+                "signer is not the update authority ({})",
+                self.metadata.update_authority
+            ))
+        }
+    }
+
+    fn check_holder(&self) -> Result<(), String> {
+        match self.token {
+            Some(token) if token.owner == *self.signer => Ok(()),
+            Some(token) => Err(format!(
+                "signer is not the owner of the token account ({})",
+                token.owner
+            )),
+            None => Err("no token account provided".to_string()),
+        }
+    }
+
+    fn check_metadata_delegate(
+        &self,
+        collection_mint: Option<&Pubkey>,
+    ) -> Result<MetadataDelegateRole, String> {
+        if self.delegate_records.is_empty() {
+            return Err("no metadata delegate record provided".to_string());
+        }
+
+        let mut lookups = vec![(&self.metadata.mint, UPDATE_METADATA_DELEGATE_ROLES)];
+        if let Some(collection_mint) = collection_mint {
+            lookups.push((collection_mint, UPDATE_COLLECTION_DELEGATE_ROLES));
+        }
+
+        let mut expired = false;
+
+        for (address, record) in self.delegate_records {
+            for (mint, roles) in &lookups {
+                for role in *roles {
+                    let (pda, _) = MetadataDelegateRecord::find_pda(
+                        mint,
+                        role.clone(),
+                        &self.metadata.update_authority,
+                        self.signer,
+                    );
+
+                    if pda == *address && record.delegate == *self.signer {
+                        // expired delegates are treated as absent
+                        if has_expired(&record.expiry, self.clock) {
+                            expired = true;
+                        } else {
+                            return Ok(role.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        if expired {
+            Err("metadata delegate record of the signer has expired".to_string())
+        } else {
+            Err("no metadata delegate record of an update role matches the signer".to_string())
+        }
+    }
+
+    fn check_token_delegate(
+        &self,
+        roles: &[TokenDelegateRole],
+    ) -> Result<TokenDelegateRole, String> {
+        let token_record = self
+            .token_record
+            .ok_or_else(|| "no token record provided".to_string())?;
+        let token = self
+            .token
+            .ok_or_else(|| "no token account provided".to_string())?;
+
+        if token_record.delegate != Some(*self.signer) {
+            return Err("signer is not the delegate of the token record".to_string());
+        }
+
+        let role = match &token_record.delegate_role {
+            Some(role) if roles.contains(role) => role.clone(),
+            Some(role) => return Err(format!("token delegate role {:?} is not allowed", role)),
+            None => return Err("token record has no delegate role".to_string()),
+        };
+
+        if token.delegate != token_record.delegate {
+            return Err("token account delegate does not match the token record".to_string());
+        }
+
+        // expired delegates are treated as absent
+        if has_expired(&token_record.delegate_expiry, self.clock) {
+            return Err("token delegate has expired".to_string());
+        }
+
+        Ok(role)
+    }
+
+    fn existing_collection_mint(&self) -> Option<&Pubkey> {
+        self.metadata
+            .collection
+            .as_ref()
+            .map(|collection| &collection.key)
+    }
+
+    fn is_programmable(&self) -> bool {
+        matches!(
+            self.metadata.token_standard,
+            Some(TokenStandard::ProgrammableNonFungible)
+                | Some(TokenStandard::ProgrammableNonFungibleEdition)
+        )
+    }
+}
+
+/// Indicates whether an (optional) expiry has been reached (mirrors the program
+/// `DelegateExpiry::is_expired`).
+fn has_expired(expiry: &Option<DelegateExpiry>, clock: &Clock) -> bool {
+    match expiry {
+        Some(DelegateExpiry::Slot(slot)) => clock.slot >= *slot,
+        Some(DelegateExpiry::UnixTimestamp(timestamp)) => clock.unix_timestamp >= *timestamp,
+        None => false,
+    }
+}
+
+/// Indicates whether a metadata delegate role can use the update args.
+fn is_valid_delegate_update(role: &MetadataDelegateRole, args: &UpdateArgs) -> bool {
+    matches!(
+        (role, args),
+        (
+            MetadataDelegateRole::AuthorityItem,
+            UpdateArgs::AsAuthorityItemDelegateV2 { .. }
+        ) | (
+            MetadataDelegateRole::Data,
+            UpdateArgs::AsDataDelegateV2 { .. }
+        ) | (
+            MetadataDelegateRole::DataItem,
+            UpdateArgs::AsDataItemDelegateV2 { .. }
+        ) | (
+            MetadataDelegateRole::Collection,
+            UpdateArgs::AsCollectionDelegateV2 { .. }
+        ) | (
+            MetadataDelegateRole::CollectionItem,
+            UpdateArgs::AsCollectionItemDelegateV2 { .. }
+        ) | (
+            // V1 is supported for backwards compatibility
+            MetadataDelegateRole::ProgrammableConfig,
+            UpdateArgs::V1 {
+                new_update_authority: None,
+                data: None,
+                primary_sale_happened: None,
+                is_mutable: None,
+                collection: CollectionToggle::None,
+                collection_details: CollectionDetailsToggle::None,
+                uses: UsesToggle::None,
+                ..
+            }
+        ) | (
+            MetadataDelegateRole::ProgrammableConfig,
+            UpdateArgs::AsProgrammableConfigDelegateV2 { .. }
+        ) | (
+            MetadataDelegateRole::ProgrammableConfigItem,
+            UpdateArgs::AsProgrammableConfigItemDelegateV2 { .. }
+        )
+    )
+}
@@ -0,0 +1,163 @@
+//! Typed construction of the `AuthorizationData` validated by rule sets.
+//!
+//! The program adds the amount and the accounts of an operation to the payload before
+//! validating it against the rule set of a programmable asset, using the keys of
+//! `PayloadKey`. Rules that verify a program-owned account (`PDAMatch`) or membership
+//! in a tree (`PubkeyTreeMatch`) also need seeds and Merkle proofs, which can only be
+//! provided by the caller. `AuthorizationDataBuilder` assembles both with the same keys
+//! the program uses.
+
+use std::collections::HashMap;
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    errors::MplTokenMetadataError,
+    types::{AuthorizationData, Payload, PayloadKey, PayloadType, ProofInfo, SeedsVec},
+};
+
+impl PayloadKey {
+    /// Returns the name of the key in the payload map.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PayloadKey::Amount => "Amount",
+            PayloadKey::Authority => "Authority",
+            PayloadKey::AuthoritySeeds => "AuthoritySeeds",
+            PayloadKey::Delegate => "Delegate",
+            PayloadKey::DelegateSeeds => "DelegateSeeds",
+            PayloadKey::Destination => "Destination",
+            PayloadKey::DestinationSeeds => "DestinationSeeds",
+            PayloadKey::Holder => "Holder",
+            PayloadKey::Source => "Source",
+            PayloadKey::SourceSeeds => "SourceSeeds",
+        }
+    }
+
+    /// Returns the key of the derivation seeds of an account key.
+    pub fn seeds_key(&self) -> Option<PayloadKey> {
+        match self {
+            PayloadKey::Authority => Some(PayloadKey::AuthoritySeeds),
+            PayloadKey::Delegate => Some(PayloadKey::DelegateSeeds),
+            PayloadKey::Destination => Some(PayloadKey::DestinationSeeds),
+            PayloadKey::Source => Some(PayloadKey::SourceSeeds),
+            _ => None,
+        }
+    }
+}
+
+/// Builds the `AuthorizationData` of an operation on a programmable asset.
+///
+/// The operation constructors add the same values that the program adds to the
+/// payload, so the resulting data can also be used to simulate the rule set
+/// validation off-chain.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuthorizationDataBuilder {
+    map: HashMap<String, PayloadType>,
+}
+
+impl AuthorizationDataBuilder {
+    /// Creates a builder with an empty payload.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Payload of a `Transfer` operation.
+    ///
+    /// The `source` and `destination` are the owners of the token accounts, not the
+    /// token accounts themselves.
+    pub fn transfer(amount: u64, authority: Pubkey, source: Pubkey, destination: Pubkey) -> Self {
+        Self::new()
+            .number(PayloadKey::Amount, amount)
+            .pubkey(PayloadKey::Authority, authority)
+            .pubkey(PayloadKey::Source, source)
+            .pubkey(PayloadKey::Destination, destination)
+    }
+
+    /// Payload of a `Delegate` operation.
+    pub fn delegate(amount: u64, delegate: Pubkey) -> Self {
+        Self::new()
+            .number(PayloadKey::Amount, amount)
+            .pubkey(PayloadKey::Delegate, delegate)
+    }
+
+    /// Payload of a `Burn` operation.
+    pub fn burn(amount: u64, authority: Pubkey) -> Self {
+        Self::new()
+            .number(PayloadKey::Amount, amount)
+            .pubkey(PayloadKey::Authority, authority)
+    }
+
+    /// Payload of a `Lock` or `Unlock` operation.
+    pub fn lock(authority: Pubkey) -> Self {
+        Self::new().pubkey(PayloadKey::Authority, authority)
+    }
+
+    /// Payload of a `Print` operation.
+    ///
+    /// The `destination` is the owner of the printed edition token account.
+    pub fn print(authority: Pubkey, destination: Pubkey) -> Self {
+        Self::new()
+            .pubkey(PayloadKey::Authority, authority)
+            .pubkey(PayloadKey::Destination, destination)
+    }
+
+    /// Sets a pubkey value.
+    pub fn pubkey(mut self, key: PayloadKey, pubkey: Pubkey) -> Self {
+        self.map
+            .insert(key.as_str().to_string(), PayloadType::Pubkey(pubkey));
+        self
+    }
+
+    /// Sets a number value.
+    pub fn number(mut self, key: PayloadKey, number: u64) -> Self {
+        self.map
+            .insert(key.as_str().to_string(), PayloadType::Number(number));
+        self
+    }
+
+    /// Sets the seeds of a program-owned account.
+    ///
+    /// `key` is the key of the account in the payload (e.g., `Destination`) and the seeds
+    /// are stored under its seeds key (e.g., `DestinationSeeds`). The seeds must not
+    /// include the bump, since rule sets derive the address with `find_program_address`.
+    pub fn program_owned(
+        mut self,
+        key: PayloadKey,
+        address: &Pubkey,
+        program_id: &Pubkey,
+        seeds: Vec<Vec<u8>>,
+    ) -> Result<Self, MplTokenMetadataError> {
+        let seeds_key = key
+            .seeds_key()
+            .ok_or(MplTokenMetadataError::InvalidOperation)?;
+
+        let path: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        match Pubkey::try_find_program_address(&path, program_id) {
+            Some((derived, _)) if derived == *address => (),
+            _ => return Err(MplTokenMetadataError::DerivedKeyInvalid),
+        }
+
+        self.map.insert(
+            seeds_key.as_str().to_string(),
+            PayloadType::Seeds(SeedsVec { seeds }),
+        );
+        Ok(self)
+    }
+
+    /// Sets a Merkle proof.
+    ///
+    /// The `field` must match the proof field of the `PubkeyTreeMatch` rule, which is
+    /// defined by the rule set.
+    pub fn merkle_proof(mut self, field: impl Into<String>, proof: Vec<[u8; 32]>) -> Self {
+        self.map
+            .insert(field.into(), PayloadType::MerkleProof(ProofInfo { proof }));
+        self
+    }
+
+    /// Returns the `AuthorizationData` with the payload.
+    pub fn build(self) -> AuthorizationData {
+        AuthorizationData {
+            payload: Payload { map: self.map },
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Estimation of the lamports paid by create, mint, print and resize operations.
+//!
+//! The program charges a create fee on every new metadata account (`Create`,
+//! `CreateMetadataAccountV3` and print instructions) in addition to the rent of the
+//! accounts it allocates. `CostEstimator` reproduces the account sizes used by the
+//! program to itemize these costs for a batch of operations.
+
+use std::collections::BTreeMap;
+
+use solana_program::{pubkey::Pubkey, rent::Rent};
+
+use crate::{
+    accounts::{EditionMarker, TokenRecord},
+    types::{Key, TokenStandard},
+    utils::{MAX_EDITION_LEN, MAX_MASTER_EDITION_LEN, MAX_METADATA_LEN},
+    EDITION_MARKER_BIT_SIZE, SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID,
+};
+
+/// Number of bytes whose rent is charged as the create fee.
+pub const CREATE_FEE_SCALAR: usize = 1308;
+
+/// Lamports added to the rent of `CREATE_FEE_SCALAR` bytes to compute the create fee.
+pub const CREATE_FEE_OFFSET: u64 = 5440;
+
+/// Length of an SPL Token mint.
+const MINT_LEN: usize = 82;
+
+/// Length of a Token-2022 mint with the `MintCloseAuthority` and `MetadataPointer`
+/// extensions enabled by the program.
+const TOKEN_2022_MINT_LEN: usize = 270;
+
+/// Length of an SPL Token account.
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Length of a Token-2022 associated token account, which has the `ImmutableOwner`
+/// extension.
+const TOKEN_2022_TOKEN_ACCOUNT_LEN: usize = 170;
+
+/// Length of an `EditionMarkerV2` account without its ledger (key and vector length).
+const EDITION_MARKER_V2_BASE_LEN: usize = 1 + 4;
+
+/// Item of a cost estimate.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CostKind {
+    /// Fee charged on the creation of a metadata account.
+    CreateFee,
+    /// Rent of metadata accounts.
+    Metadata,
+    /// Rent of mint accounts.
+    Mint,
+    /// Rent of token accounts.
+    TokenAccount,
+    /// Rent of master edition accounts.
+    MasterEdition,
+    /// Rent of print edition accounts.
+    Edition,
+    /// Rent of edition marker accounts.
+    EditionMarker,
+    /// Rent of token record accounts.
+    TokenRecord,
+}
+
+/// Lamports of a cost item.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CostItem {
+    pub kind: CostKind,
+    /// Number of accounts (or fees) included in the item.
+    pub count: u64,
+    /// Total lamports of the item.
+    pub lamports: u64,
+}
+
+/// Itemized lamports of a set of operations.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CostEstimate {
+    /// Lamports paid by the payer.
+    pub charges: Vec<CostItem>,
+    /// Lamports returned to the payer when accounts are resized.
+    pub refunds: Vec<CostItem>,
+}
+
+impl CostEstimate {
+    /// Returns the total lamports paid by the payer.
+    pub fn total_charges(&self) -> u64 {
+        self.charges.iter().map(|item| item.lamports).sum()
+    }
+
+    /// Returns the total lamports returned to the payer.
+    pub fn total_refunds(&self) -> u64 {
+        self.refunds.iter().map(|item| item.lamports).sum()
+    }
+
+    /// Returns the lamports of the specified charge.
+    pub fn charge(&self, kind: CostKind) -> u64 {
+        self.charges
+            .iter()
+            .find(|item| item.kind == kind)
+            .map_or(0, |item| item.lamports)
+    }
+}
+
+/// Estimates the lamports paid by a set of operations.
+///
+/// Operations are added with the builder methods and the estimate assumes that none
+/// of the accounts they create exist yet.
+#[derive(Clone, Debug)]
+pub struct CostEstimator {
+    rent: Rent,
+    spl_token_program: Pubkey,
+    charges: BTreeMap<CostKind, (u64, u64)>,
+    refunds: BTreeMap<CostKind, (u64, u64)>,
+}
+
+impl CostEstimator {
+    /// Creates an estimator using the specified rent parameters.
+    pub fn new(rent: Rent) -> Self {
+        Self {
+            rent,
+            spl_token_program: SPL_TOKEN_PROGRAM_ID,
+            charges: BTreeMap::new(),
+            refunds: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the SPL Token program of the assets (defaults to the legacy SPL Token program).
+    ///
+    /// Only affects operations added after this call.
+    pub fn spl_token_program(mut self, spl_token_program: Pubkey) -> Self {
+        self.spl_token_program = spl_token_program;
+        self
+    }
+
+    /// Returns the fee charged on the creation of a metadata account.
+    pub fn create_fee(&self) -> u64 {
+        self.rent
+            .minimum_balance(CREATE_FEE_SCALAR)
+            .saturating_add(CREATE_FEE_OFFSET)
+    }
+
+    /// Adds the creation of `count` assets with new mint accounts.
+    ///
+    /// Non-fungible assets also include their master edition accounts.
+    pub fn create(mut self, token_standard: TokenStandard, count: u64) -> Self {
+        self.add_metadata(count);
+        self.add_rent(CostKind::Mint, self.mint_len(), count);
+
+        if matches!(
+            token_standard,
+            TokenStandard::NonFungible | TokenStandard::ProgrammableNonFungible
+        ) {
+            self.add_rent(CostKind::MasterEdition, MAX_MASTER_EDITION_LEN, count);
+        }
+
+        self
+    }
+
+    /// Adds the minting of `count` assets to new associated token accounts.
+    ///
+    /// Programmable assets also include their token record accounts.
+    pub fn mint(mut self, token_standard: TokenStandard, count: u64) -> Self {
+        self.add_rent(CostKind::TokenAccount, self.token_account_len(), count);
+
+        if is_programmable(token_standard) {
+            self = self.token_records(count);
+        }
+
+        self
+    }
+
+    /// Adds the creation of `count` token record accounts.
+    pub fn token_records(mut self, count: u64) -> Self {
+        self.add_rent(CostKind::TokenRecord, TokenRecord::LEN, count);
+        self
+    }
+
+    /// Adds the printing of `count` editions starting at edition number `first_edition`
+    /// from a master edition with the specified token standard.
+    ///
+    /// Editions are assumed to be printed in sequence, so the edition marker of
+    /// `first_edition` already exists unless it is the first edition of its marker.
+    pub fn print(mut self, token_standard: TokenStandard, first_edition: u64, count: u64) -> Self {
+        if count == 0 {
+            return self;
+        }
+
+        let last_edition = first_edition.saturating_add(count - 1);
+
+        self.add_metadata(count);
+        self.add_rent(CostKind::Mint, self.mint_len(), count);
+        self.add_rent(CostKind::TokenAccount, self.token_account_len(), count);
+        self.add_rent(CostKind::Edition, MAX_EDITION_LEN, count);
+
+        if is_programmable(token_standard) {
+            self = self.token_records(count);
+
+            // a single marker whose ledger grows with the edition numbers
+            let current = if first_edition > 1 {
+                self.rent
+                    .minimum_balance(edition_marker_v2_len(first_edition - 1))
+            } else {
+                0
+            };
+            let lamports = self
+                .rent
+                .minimum_balance(edition_marker_v2_len(last_edition))
+                .saturating_sub(current);
+
+            self.add(CostKind::EditionMarker, u64::from(current == 0), lamports);
+        } else {
+            let first_marker = first_edition / EDITION_MARKER_BIT_SIZE;
+            let last_marker = last_edition / EDITION_MARKER_BIT_SIZE;
+            // edition 0 does not exist, so edition 1 is the first of marker 0
+            let first_marker_exists =
+                first_edition > 1 && !first_edition.is_multiple_of(EDITION_MARKER_BIT_SIZE);
+
+            let markers = last_marker - first_marker + 1 - u64::from(first_marker_exists);
+            self.add_rent(CostKind::EditionMarker, EditionMarker::LEN, markers);
+        }
+
+        self
+    }
+
+    /// Adds the resize of an asset whose metadata account has the specified length.
+    ///
+    /// The `edition` is the key and length of the edition account of non-fungible
+    /// assets. The difference in rent is returned to the payer of the `Resize`
+    /// instruction.
+    pub fn resize(mut self, metadata_len: usize, edition: Option<(Key, usize)>) -> Self {
+        self.add_refund(CostKind::Metadata, metadata_len, MAX_METADATA_LEN);
+
+        match edition {
+            Some((Key::MasterEditionV2, len)) => {
+                self.add_refund(CostKind::MasterEdition, len, MAX_MASTER_EDITION_LEN)
+            }
+            Some((Key::EditionV1, len)) => self.add_refund(CostKind::Edition, len, MAX_EDITION_LEN),
+            _ => (),
+        }
+
+        self
+    }
+
+    /// Returns the itemized estimate of the operations.
+    pub fn estimate(&self) -> CostEstimate {
+        let items = |map: &BTreeMap<CostKind, (u64, u64)>| {
+            map.iter()
+                .map(|(kind, (count, lamports))| CostItem {
+                    kind: *kind,
+                    count: *count,
+                    lamports: *lamports,
+                })
+                .collect()
+        };
+
+        CostEstimate {
+            charges: items(&self.charges),
+            refunds: items(&self.refunds),
+        }
+    }
+
+    fn add_metadata(&mut self, count: u64) {
+        let fee = self.create_fee();
+        self.add(CostKind::CreateFee, count, fee.saturating_mul(count));
+        self.add_rent(CostKind::Metadata, MAX_METADATA_LEN, count);
+    }
+
+    fn add_rent(&mut self, kind: CostKind, len: usize, count: u64) {
+        let lamports = self.rent.minimum_balance(len).saturating_mul(count);
+        self.add(kind, count, lamports);
+    }
+
+    fn add(&mut self, kind: CostKind, count: u64, lamports: u64) {
+        if count == 0 && lamports == 0 {
+            return;
+        }
+
+        let entry = self.charges.entry(kind).or_default();
+        entry.0 = entry.0.saturating_add(count);
+        entry.1 = entry.1.saturating_add(lamports);
+    }
+
+    fn add_refund(&mut self, kind: CostKind, current_len: usize, new_len: usize) {
+        // accounts already resized are not modified
+        if current_len <= new_len {
+            return;
+        }
+
+        let lamports = self
+            .rent
+            .minimum_balance(current_len)
+            .saturating_sub(self.rent.minimum_balance(new_len));
+
+        let entry = self.refunds.entry(kind).or_default();
+        entry.0 = entry.0.saturating_add(1);
+        entry.1 = entry.1.saturating_add(lamports);
+    }
+
+    fn mint_len(&self) -> usize {
+        if self.spl_token_program == SPL_TOKEN_2022_PROGRAM_ID {
+            TOKEN_2022_MINT_LEN
+        } else {
+            MINT_LEN
+        }
+    }
+
+    fn token_account_len(&self) -> usize {
+        if self.spl_token_program == SPL_TOKEN_2022_PROGRAM_ID {
+            TOKEN_2022_TOKEN_ACCOUNT_LEN
+        } else {
+            TOKEN_ACCOUNT_LEN
+        }
+    }
+}
+
+fn is_programmable(token_standard: TokenStandard) -> bool {
+    matches!(
+        token_standard,
+        TokenStandard::ProgrammableNonFungible | TokenStandard::ProgrammableNonFungibleEdition
+    )
+}
+
+/// Length of an `EditionMarkerV2` account that includes the specified edition.
+fn edition_marker_v2_len(edition: u64) -> usize {
+    EDITION_MARKER_V2_BASE_LEN + (edition / 8) as usize + 1
+}
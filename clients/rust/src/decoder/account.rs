@@ -0,0 +1,189 @@
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    accounts::{
+        CollectionAuthorityRecord, DeprecatedMasterEditionV1, Edition, EditionMarker,
+        EditionMarkerV2, HolderDelegateRecord, MasterEdition, Metadata, MetadataAttributes,
+        MetadataDelegateRecord, PendingUpdateAuthority, TokenOwnedEscrow, TokenRecord,
+        UseAuthorityRecord,
+    },
+    errors::MplTokenMetadataError,
+    types::Key,
+    ID,
+};
+
+/// Size (in bytes) of a use authority record account (includes padding).
+const USE_AUTHORITY_RECORD_SIZE: usize = 18;
+
+/// Size (in bytes) of a collection authority record account.
+const COLLECTION_AUTHORITY_RECORD_SIZE: usize = 35;
+
+/// A `token-metadata` account decoded from its raw data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodedAccount {
+    Metadata(Metadata),
+    MasterEdition(MasterEdition),
+    DeprecatedMasterEditionV1(DeprecatedMasterEditionV1),
+    Edition(Edition),
+    EditionMarker(EditionMarker),
+    EditionMarkerV2(EditionMarkerV2),
+    TokenRecord(TokenRecord),
+    MetadataDelegateRecord(MetadataDelegateRecord),
+    HolderDelegateRecord(HolderDelegateRecord),
+    CollectionAuthorityRecord(CollectionAuthorityRecord),
+    UseAuthorityRecord(UseAuthorityRecord),
+    TokenOwnedEscrow(TokenOwnedEscrow),
+    PendingUpdateAuthority(PendingUpdateAuthority),
+    MetadataAttributes(MetadataAttributes),
+}
+
+impl DecodedAccount {
+    /// Returns the account discriminator of the decoded account.
+    pub fn key(&self) -> Key {
+        match self {
+            Self::Metadata(_) => Key::MetadataV1,
+            Self::MasterEdition(_) => Key::MasterEditionV2,
+            Self::DeprecatedMasterEditionV1(_) => Key::MasterEditionV1,
+            Self::Edition(_) => Key::EditionV1,
+            Self::EditionMarker(_) => Key::EditionMarker,
+            Self::EditionMarkerV2(_) => Key::EditionMarkerV2,
+            Self::TokenRecord(_) => Key::TokenRecord,
+            Self::MetadataDelegateRecord(_) => Key::MetadataDelegate,
+            Self::HolderDelegateRecord(_) => Key::HolderDelegate,
+            Self::CollectionAuthorityRecord(_) => Key::CollectionAuthorityRecord,
+            Self::UseAuthorityRecord(_) => Key::UseAuthorityRecord,
+            Self::TokenOwnedEscrow(_) => Key::TokenOwnedEscrow,
+            Self::PendingUpdateAuthority(_) => Key::PendingUpdateAuthority,
+            Self::MetadataAttributes(_) => Key::MetadataAttributes,
+        }
+    }
+}
+
+/// Decodes raw `token-metadata` accounts into their typed representation.
+///
+/// The decoder dispatches on the `Key` discriminator stored in the first byte of
+/// the account data and validates the account length for fixed-size accounts,
+/// mirroring the checks performed by the program when loading an account.
+pub struct AccountDecoder;
+
+impl AccountDecoder {
+    /// Decodes the data of a single account.
+    ///
+    /// The `owner` must be the `token-metadata` program; accounts owned by any other
+    /// program are rejected with `IncorrectOwner`.
+    pub fn decode(owner: &Pubkey, data: &[u8]) -> Result<DecodedAccount, MplTokenMetadataError> {
+        if *owner != ID {
+            return Err(MplTokenMetadataError::IncorrectOwner);
+        }
+
+        if data.is_empty() {
+            return Err(MplTokenMetadataError::DataIsEmptyOrZeroed);
+        }
+
+        let key = Key::from_u8(data[0]).ok_or(MplTokenMetadataError::DataTypeMismatch)?;
+
+        let account = match key {
+            Key::Uninitialized => return Err(MplTokenMetadataError::Uninitialized),
+            Key::MetadataV1 => DecodedAccount::Metadata(
+                Metadata::safe_deserialize(data).map_err(deserialization_error)?,
+            ),
+            Key::MasterEditionV2 => DecodedAccount::MasterEdition(
+                MasterEdition::safe_deserialize(data).map_err(deserialization_error)?,
+            ),
+            Key::MasterEditionV1 => DecodedAccount::DeprecatedMasterEditionV1(
+                DeprecatedMasterEditionV1::from_bytes(data).map_err(deserialization_error)?,
+            ),
+            Key::EditionV1 => {
+                if data.len() < Edition::LEN {
+                    return Err(MplTokenMetadataError::InvalidEditionAccountLength);
+                }
+                DecodedAccount::Edition(Edition::from_bytes(data).map_err(deserialization_error)?)
+            }
+            Key::EditionMarker => {
+                assert_length(data, EditionMarker::LEN)?;
+                DecodedAccount::EditionMarker(
+                    EditionMarker::from_bytes(data).map_err(deserialization_error)?,
+                )
+            }
+            Key::EditionMarkerV2 => DecodedAccount::EditionMarkerV2(
+                EditionMarkerV2::from_bytes(data).map_err(deserialization_error)?,
+            ),
+            // the token record can have different lengths, the length check is
+            // performed by `safe_deserialize`
+            Key::TokenRecord => DecodedAccount::TokenRecord(
+                TokenRecord::safe_deserialize(data).map_err(deserialization_error)?,
+            ),
+            // the metadata delegate record can have different lengths, the length
+            // check is performed by `safe_deserialize`
+            Key::MetadataDelegate => DecodedAccount::MetadataDelegateRecord(
+                MetadataDelegateRecord::safe_deserialize(data).map_err(deserialization_error)?,
+            ),
+            Key::HolderDelegate => {
+                assert_length(data, HolderDelegateRecord::LEN)?;
+                DecodedAccount::HolderDelegateRecord(
+                    HolderDelegateRecord::from_bytes(data).map_err(deserialization_error)?,
+                )
+            }
+            Key::CollectionAuthorityRecord => {
+                assert_length(data, COLLECTION_AUTHORITY_RECORD_SIZE)?;
+                DecodedAccount::CollectionAuthorityRecord(
+                    CollectionAuthorityRecord::safe_deserialize(data)
+                        .map_err(deserialization_error)?,
+                )
+            }
+            Key::UseAuthorityRecord => {
+                assert_length(data, USE_AUTHORITY_RECORD_SIZE)?;
+                DecodedAccount::UseAuthorityRecord(
+                    UseAuthorityRecord::from_bytes(data).map_err(deserialization_error)?,
+                )
+            }
+            Key::TokenOwnedEscrow => DecodedAccount::TokenOwnedEscrow(
+                TokenOwnedEscrow::from_bytes(data).map_err(deserialization_error)?,
+            ),
+            Key::PendingUpdateAuthority => {
+                assert_length(data, PendingUpdateAuthority::LEN)?;
+                DecodedAccount::PendingUpdateAuthority(
+                    PendingUpdateAuthority::from_bytes(data).map_err(deserialization_error)?,
+                )
+            }
+            // the attributes account is resized as attributes are added or removed
+            Key::MetadataAttributes => DecodedAccount::MetadataAttributes(
+                MetadataAttributes::from_bytes(data).map_err(deserialization_error)?,
+            ),
+            // reservation lists are deprecated and no longer supported
+            Key::ReservationListV1 | Key::ReservationListV2 => {
+                return Err(MplTokenMetadataError::ReservationListDeprecated)
+            }
+        };
+
+        Ok(account)
+    }
+
+    /// Decodes a list of `(address, owner, data)` tuples.
+    ///
+    /// The result of each account is returned together with its address; a failure
+    /// to decode one account does not prevent the remaining accounts from being decoded.
+    pub fn decode_all<'a, I>(
+        accounts: I,
+    ) -> Vec<(Pubkey, Result<DecodedAccount, MplTokenMetadataError>)>
+    where
+        I: IntoIterator<Item = (&'a Pubkey, &'a Pubkey, &'a [u8])>,
+    {
+        accounts
+            .into_iter()
+            .map(|(address, owner, data)| (*address, Self::decode(owner, data)))
+            .collect()
+    }
+}
+
+fn assert_length(data: &[u8], expected: usize) -> Result<(), MplTokenMetadataError> {
+    if data.len() != expected {
+        return Err(MplTokenMetadataError::DataTypeMismatch);
+    }
+    Ok(())
+}
+
+fn deserialization_error(_error: borsh::io::Error) -> MplTokenMetadataError {
+    MplTokenMetadataError::BorshDeserializationError
+}
@@ -0,0 +1,249 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    errors::MplTokenMetadataError,
+    types::{HolderDelegateRole, MetadataDelegateRole, TokenDelegateRole, TokenStandard},
+};
+
+/// Version of the event layout supported by the decoder.
+pub const EVENT_VERSION: u8 = 1;
+
+/// An event emitted by the `token-metadata` program.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum MetadataEvent {
+    /// Metadata accounts were created for a mint.
+    Created {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        update_authority: Pubkey,
+        token_standard: Option<TokenStandard>,
+    },
+    /// Tokens were minted to a token account.
+    Minted {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token: Pubkey,
+        amount: u64,
+    },
+    /// Tokens were transferred between token accounts.
+    Transferred {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        source: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// Fields of the metadata account were changed.
+    Updated {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        fields: Vec<UpdatedField>,
+    },
+    /// A delegate was approved.
+    Delegated {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        delegate: Pubkey,
+        role: DelegateScenario,
+    },
+    /// A delegate was revoked.
+    Revoked {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        delegate: Pubkey,
+        role: DelegateScenario,
+    },
+    /// A token account was locked.
+    Locked {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token: Pubkey,
+    },
+    /// A token account was unlocked.
+    Unlocked {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token: Pubkey,
+    },
+    /// A creator or collection was verified on the metadata of the mint.
+    Verified {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        target: VerificationTarget,
+    },
+    /// A creator or collection was unverified on the metadata of the mint.
+    Unverified {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        target: VerificationTarget,
+    },
+    /// Tokens (and the asset accounts, when applicable) were burned.
+    Burned {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        amount: u64,
+    },
+    /// An edition was printed from a master edition.
+    Printed {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        master_mint: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        edition_mint: Pubkey,
+        edition: u64,
+    },
+}
+
+/// Field of a metadata account reported by an `Updated` event.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpdatedField {
+    /// Name, symbol, uri, seller fee basis points or creators.
+    Data,
+    UpdateAuthority,
+    PrimarySaleHappened,
+    IsMutable,
+    TokenStandard,
+    Collection,
+    CollectionDetails,
+    Uses,
+    ProgrammableConfig,
+    /// On-chain attributes of the asset (stored in the attributes account).
+    Attributes,
+}
+
+/// Role of the delegate reported by `Delegated` and `Revoked` events.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum DelegateScenario {
+    Metadata(MetadataDelegateRole),
+    Holder(HolderDelegateRole),
+    Token(TokenDelegateRole),
+}
+
+/// Target of a `Verified` or `Unverified` event.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum VerificationTarget {
+    /// Address of the creator.
+    Creator(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        Pubkey,
+    ),
+    /// Mint of the collection.
+    Collection(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        Pubkey,
+    ),
+}
+
+/// Decodes events emitted by the `token-metadata` program.
+///
+/// Events are emitted through `sol_log_data` and appear in the transaction logs as
+/// `Program data: <base64>` lines, where the (base64-decoded) data is the event version
+/// byte followed by the Borsh serialization of the event.
+pub struct EventDecoder;
+
+impl EventDecoder {
+    /// Decodes the (base64-decoded) data of a single `Program data:` log entry.
+    ///
+    /// Data with an unsupported event version is rejected with `DataTypeMismatch`.
+    pub fn decode(data: &[u8]) -> Result<MetadataEvent, MplTokenMetadataError> {
+        match data.split_first() {
+            Some((&EVENT_VERSION, event)) => MetadataEvent::try_from_slice(event)
+                .map_err(|_| MplTokenMetadataError::BorshDeserializationError),
+            Some(_) => Err(MplTokenMetadataError::DataTypeMismatch),
+            None => Err(MplTokenMetadataError::DataIsEmptyOrZeroed),
+        }
+    }
+
+    /// Decodes the data of all `Program data:` log entries of the program, skipping
+    /// entries that are not events.
+    pub fn decode_all<'a, I>(entries: I) -> Vec<MetadataEvent>
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        entries
+            .into_iter()
+            .filter_map(|data| Self::decode(data).ok())
+            .collect()
+    }
+}
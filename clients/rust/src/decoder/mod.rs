@@ -0,0 +1,8 @@
+//! Decoders for raw account data fetched from the cluster and for the events
+//! emitted by the program.
+
+mod account;
+mod event;
+
+pub use account::*;
+pub use event::*;
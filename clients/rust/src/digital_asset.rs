@@ -0,0 +1,295 @@
+//! Resolution of all accounts associated with a mint.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    accounts::{
+        Edition, EditionMarker, EditionMarkerV2, HolderDelegateRecord, MasterEdition, Metadata,
+        MetadataDelegateRecord, TokenRecord,
+    },
+    decoder::{AccountDecoder, DecodedAccount},
+    errors::MplTokenMetadataError,
+    types::{HolderDelegateRole, MetadataDelegateRole, TokenStandard},
+    utils::find_associated_token_account,
+    EDITION_MARKER_BIT_SIZE, SPL_TOKEN_PROGRAM_ID,
+};
+
+/// Addresses of the accounts associated with a digital asset.
+///
+/// All addresses are derived using the same seeds as the program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DigitalAssetAccounts {
+    /// Mint of the asset.
+    pub mint: Pubkey,
+    /// Metadata account.
+    pub metadata: Pubkey,
+    /// Master edition or print edition account (both share the same derivation).
+    pub edition: Pubkey,
+    /// Edition marker of the parent master edition (print editions only).
+    pub edition_marker: Option<Pubkey>,
+    /// Token account of the owner.
+    pub token: Option<Pubkey>,
+    /// Token record of the token account (programmable assets only).
+    pub token_record: Option<Pubkey>,
+    /// Metadata delegate records.
+    pub metadata_delegate_records: Vec<Pubkey>,
+    /// Holder delegate records.
+    pub holder_delegate_records: Vec<Pubkey>,
+}
+
+impl DigitalAssetAccounts {
+    /// Derives the addresses for a mint without any token-specific information.
+    pub fn find(mint: &Pubkey) -> Self {
+        DigitalAssetAccountsBuilder::new().resolve(*mint)
+    }
+
+    /// Assembles a `DigitalAsset` from the supplied `(address, owner, data)` tuples.
+    ///
+    /// Accounts that do not belong to the asset are ignored. The metadata account is
+    /// required; any other account is optional and only decoded if present.
+    pub fn assemble<'a, I>(&self, accounts: I) -> Result<DigitalAsset, MplTokenMetadataError>
+    where
+        I: IntoIterator<Item = (&'a Pubkey, &'a Pubkey, &'a [u8])>,
+    {
+        let mut metadata = None;
+        let mut edition = None;
+        let mut token_record = None;
+        let mut metadata_delegate_records = Vec::new();
+        let mut holder_delegate_records = Vec::new();
+
+        for (address, owner, data) in accounts {
+            if *address == self.metadata {
+                match AccountDecoder::decode(owner, data)? {
+                    DecodedAccount::Metadata(account) => metadata = Some(account),
+                    _ => return Err(MplTokenMetadataError::DataTypeMismatch),
+                }
+            } else if *address == self.edition {
+                edition = match AccountDecoder::decode(owner, data)? {
+                    DecodedAccount::MasterEdition(account) => {
+                        Some(DigitalAssetEdition::Master(account))
+                    }
+                    DecodedAccount::Edition(account) => Some(DigitalAssetEdition::Print(account)),
+                    _ => return Err(MplTokenMetadataError::DataTypeMismatch),
+                };
+            } else if Some(*address) == self.token_record {
+                match AccountDecoder::decode(owner, data)? {
+                    DecodedAccount::TokenRecord(account) => token_record = Some(account),
+                    _ => return Err(MplTokenMetadataError::DataTypeMismatch),
+                }
+            } else if self.metadata_delegate_records.contains(address) {
+                match AccountDecoder::decode(owner, data)? {
+                    DecodedAccount::MetadataDelegateRecord(account) => {
+                        metadata_delegate_records.push(account)
+                    }
+                    _ => return Err(MplTokenMetadataError::DataTypeMismatch),
+                }
+            } else if self.holder_delegate_records.contains(address) {
+                match AccountDecoder::decode(owner, data)? {
+                    DecodedAccount::HolderDelegateRecord(account) => {
+                        holder_delegate_records.push(account)
+                    }
+                    _ => return Err(MplTokenMetadataError::DataTypeMismatch),
+                }
+            }
+        }
+
+        Ok(DigitalAsset {
+            accounts: self.clone(),
+            metadata: metadata.ok_or(MplTokenMetadataError::DataIsEmptyOrZeroed)?,
+            edition,
+            token_record,
+            metadata_delegate_records,
+            holder_delegate_records,
+        })
+    }
+}
+
+/// Edition account of a digital asset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DigitalAssetEdition {
+    Master(MasterEdition),
+    Print(Edition),
+}
+
+/// A digital asset assembled from its account data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DigitalAsset {
+    pub accounts: DigitalAssetAccounts,
+    pub metadata: Metadata,
+    pub edition: Option<DigitalAssetEdition>,
+    pub token_record: Option<TokenRecord>,
+    pub metadata_delegate_records: Vec<MetadataDelegateRecord>,
+    pub holder_delegate_records: Vec<HolderDelegateRecord>,
+}
+
+/// Builder for `DigitalAssetAccounts`.
+///
+/// ### Accounts:
+///
+///   0. `[]` mint
+///   1. `[optional]` token_owner
+///   2. `[optional]` token (default to the associated token account of `token_owner`)
+///   3. `[optional]` spl_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   4. `[optional]` parent_mint (mint of the master edition of a print edition)
+#[derive(Clone, Debug, Default)]
+pub struct DigitalAssetAccountsBuilder {
+    mint: Option<Pubkey>,
+    token_owner: Option<Pubkey>,
+    token: Option<Pubkey>,
+    spl_token_program: Option<Pubkey>,
+    parent_mint: Option<Pubkey>,
+    token_standard: Option<TokenStandard>,
+    edition_number: Option<u64>,
+    metadata_delegates: Vec<(MetadataDelegateRole, Pubkey, Pubkey)>,
+    holder_delegates: Vec<(HolderDelegateRole, Pubkey, Pubkey)>,
+}
+
+impl DigitalAssetAccountsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// `[optional account]`
+    /// Owner of the token account
+    #[inline(always)]
+    pub fn token_owner(&mut self, token_owner: Pubkey) -> &mut Self {
+        self.token_owner = Some(token_owner);
+        self
+    }
+    /// `[optional account]`
+    /// Token account (when not the associated token account of the owner)
+    #[inline(always)]
+    pub fn token(&mut self, token: Pubkey) -> &mut Self {
+        self.token = Some(token);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// SPL Token program
+    #[inline(always)]
+    pub fn spl_token_program(&mut self, spl_token_program: Pubkey) -> &mut Self {
+        self.spl_token_program = Some(spl_token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Mint of the master edition (print editions only)
+    #[inline(always)]
+    pub fn parent_mint(&mut self, parent_mint: Pubkey) -> &mut Self {
+        self.parent_mint = Some(parent_mint);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn token_standard(&mut self, token_standard: TokenStandard) -> &mut Self {
+        self.token_standard = Some(token_standard);
+        self
+    }
+    /// `[optional argument]`
+    /// Edition number (print editions only)
+    #[inline(always)]
+    pub fn edition_number(&mut self, edition_number: u64) -> &mut Self {
+        self.edition_number = Some(edition_number);
+        self
+    }
+    /// Adds a metadata delegate record to the list of resolved accounts.
+    #[inline(always)]
+    pub fn metadata_delegate(
+        &mut self,
+        role: MetadataDelegateRole,
+        update_authority: Pubkey,
+        delegate: Pubkey,
+    ) -> &mut Self {
+        self.metadata_delegates
+            .push((role, update_authority, delegate));
+        self
+    }
+    /// Adds a holder delegate record to the list of resolved accounts.
+    #[inline(always)]
+    pub fn holder_delegate(
+        &mut self,
+        role: HolderDelegateRole,
+        owner: Pubkey,
+        delegate: Pubkey,
+    ) -> &mut Self {
+        self.holder_delegates.push((role, owner, delegate));
+        self
+    }
+    /// Derives the addresses of the asset.
+    ///
+    /// Returns `MissingAccountInBuilder` when the mint is not set.
+    pub fn build(&self) -> Result<DigitalAssetAccounts, MplTokenMetadataError> {
+        let mint = self
+            .mint
+            .ok_or(MplTokenMetadataError::MissingAccountInBuilder)?;
+        Ok(self.resolve(mint))
+    }
+    fn resolve(&self, mint: Pubkey) -> DigitalAssetAccounts {
+        let spl_token_program = self.spl_token_program.unwrap_or(SPL_TOKEN_PROGRAM_ID);
+
+        let token = self.token.or_else(|| {
+            self.token_owner
+                .map(|owner| find_associated_token_account(&owner, &mint, &spl_token_program))
+        });
+
+        // token records are only used by programmable assets; when the standard is
+        // not known, the address is derived so it can be checked by the caller
+        let token_record = match (token, self.token_standard) {
+            (Some(token), None)
+            | (Some(token), Some(TokenStandard::ProgrammableNonFungible))
+            | (Some(token), Some(TokenStandard::ProgrammableNonFungibleEdition)) => {
+                Some(TokenRecord::find_pda(&mint, &token).0)
+            }
+            _ => None,
+        };
+
+        // edition markers are derived from the parent mint; programmable editions
+        // use the (single) edition marker v2 account
+        let edition_marker = match (self.parent_mint, self.edition_number) {
+            (Some(parent_mint), Some(edition_number)) => match self.token_standard {
+                Some(TokenStandard::ProgrammableNonFungibleEdition) => {
+                    Some(EditionMarkerV2::find_pda(&parent_mint).0)
+                }
+                _ => Some(
+                    EditionMarker::find_pda(
+                        &parent_mint,
+                        (edition_number / EDITION_MARKER_BIT_SIZE)
+                            .to_string()
+                            .into(),
+                    )
+                    .0,
+                ),
+            },
+            _ => None,
+        };
+
+        let metadata_delegate_records = self
+            .metadata_delegates
+            .iter()
+            .map(|(role, update_authority, delegate)| {
+                MetadataDelegateRecord::find_pda(&mint, role.clone(), update_authority, delegate).0
+            })
+            .collect();
+
+        let holder_delegate_records = self
+            .holder_delegates
+            .iter()
+            .map(|(role, owner, delegate)| {
+                HolderDelegateRecord::find_pda(&mint, role.clone(), owner, delegate).0
+            })
+            .collect();
+
+        DigitalAssetAccounts {
+            mint,
+            metadata: Metadata::find_pda(&mint).0,
+            edition: MasterEdition::find_pda(&mint).0,
+            edition_marker,
+            token,
+            token_record,
+            metadata_delegate_records,
+            holder_delegate_records,
+        }
+    }
+}
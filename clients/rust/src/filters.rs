@@ -0,0 +1,203 @@
+//! `getProgramAccounts` filters of common queries.
+//!
+//! The filters rely on the layout of the accounts:
+//!
+//! * `Metadata` - the `name`, `symbol` and `uri` strings are padded to their maximum
+//!   length by the program (`puff_out_data_fields`), so the fields up to the first
+//!   creator are at fixed offsets:
+//!
+//!   | Offset | Field                     | Length |
+//!   |--------|---------------------------|--------|
+//!   | 0      | key                       | 1      |
+//!   | 1      | update authority          | 32     |
+//!   | 33     | mint                      | 32     |
+//!   | 65     | name                      | 4 + 32 |
+//!   | 101    | symbol                    | 4 + 10 |
+//!   | 115    | uri                       | 4 + 200 |
+//!   | 319    | seller fee basis points   | 2      |
+//!   | 321    | creators (option)         | 1      |
+//!   | 322    | creators (length)         | 4      |
+//!   | 326    | first creator             | 34     |
+//!
+//!   The offset of the fields after the creators depends on the number of creators.
+//!
+//! * `TokenRecord` - the `key`, `bump` and `state` are at fixed offsets. The offset of
+//!   the `delegate` depends on whether the `rule_set_revision` is set, so delegate
+//!   queries are split in one query per layout.
+//!
+//! * `MasterEdition` - the token standard of programmable assets is stored in the last
+//!   byte of the account.
+//!
+//! The filters are independent of the RPC client types; each `AccountFilter` maps to a
+//! `memcmp` or `dataSize` filter.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    types::{Key, TokenStandard, TokenState},
+    utils::MAX_MASTER_EDITION_LEN,
+    MAX_CREATOR_LEN, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+
+/// Offset of the update authority of a metadata account.
+pub const METADATA_UPDATE_AUTHORITY_OFFSET: usize = 1;
+
+/// Offset of the mint of a metadata account.
+pub const METADATA_MINT_OFFSET: usize = METADATA_UPDATE_AUTHORITY_OFFSET + 32;
+
+/// Offset of the creators (option) of a metadata account.
+pub const METADATA_CREATORS_OFFSET: usize = METADATA_MINT_OFFSET
+    + 32
+    + 4
+    + MAX_NAME_LENGTH
+    + 4
+    + MAX_SYMBOL_LENGTH
+    + 4
+    + MAX_URI_LENGTH
+    + 2;
+
+/// Offset of the first creator of a metadata account.
+pub const METADATA_FIRST_CREATOR_OFFSET: usize = METADATA_CREATORS_OFFSET + 1 + 4;
+
+/// Offset of the state of a token record account.
+pub const TOKEN_RECORD_STATE_OFFSET: usize = 2;
+
+/// Offset of the rule set revision (option) of a token record account.
+pub const TOKEN_RECORD_RULE_SET_REVISION_OFFSET: usize = TOKEN_RECORD_STATE_OFFSET + 1;
+
+/// Filter of a `getProgramAccounts` request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountFilter {
+    /// Matches accounts whose data contains the `bytes` at the `offset`.
+    Memcmp { offset: usize, bytes: Vec<u8> },
+    /// Matches accounts whose data has the specified length.
+    DataSize(u64),
+}
+
+impl AccountFilter {
+    /// Returns whether the account data matches the filter.
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match self {
+            AccountFilter::Memcmp { offset, bytes } => data
+                .get(*offset..offset.saturating_add(bytes.len()))
+                .is_some_and(|slice| slice == bytes.as_slice()),
+            AccountFilter::DataSize(size) => data.len() as u64 == *size,
+        }
+    }
+}
+
+/// Matches accounts of the specified type.
+pub fn key(key: Key) -> AccountFilter {
+    memcmp(0, &[key as u8])
+}
+
+/// Metadata accounts with the specified update authority.
+pub fn metadata_by_update_authority(update_authority: &Pubkey) -> Vec<AccountFilter> {
+    vec![
+        key(Key::MetadataV1),
+        memcmp(METADATA_UPDATE_AUTHORITY_OFFSET, update_authority.as_ref()),
+    ]
+}
+
+/// Metadata account of the specified mint.
+pub fn metadata_by_mint(mint: &Pubkey) -> Vec<AccountFilter> {
+    vec![
+        key(Key::MetadataV1),
+        memcmp(METADATA_MINT_OFFSET, mint.as_ref()),
+    ]
+}
+
+/// Metadata accounts whose first creator is the specified (verified) creator.
+///
+/// This is commonly used to find the items of a candy machine, which is the first
+/// verified creator of the items it mints.
+pub fn metadata_by_first_verified_creator(creator: &Pubkey) -> Vec<AccountFilter> {
+    let mut bytes = creator.to_bytes().to_vec();
+    // verified flag
+    bytes.push(1);
+
+    vec![
+        key(Key::MetadataV1),
+        // the creators option is set
+        memcmp(METADATA_CREATORS_OFFSET, &[1]),
+        memcmp(METADATA_FIRST_CREATOR_OFFSET, &bytes),
+    ]
+}
+
+/// Metadata accounts with the specified token standard and number of creators.
+///
+/// The offset of the token standard depends on the number of creators, so accounts
+/// with a different number of creators are not matched. Assumes the edition nonce is
+/// set, which is the case for all metadata accounts created by the program.
+pub fn metadata_by_token_standard(
+    token_standard: TokenStandard,
+    creators: usize,
+) -> Vec<AccountFilter> {
+    let mut filters = vec![key(Key::MetadataV1)];
+
+    let creators_len = if creators == 0 {
+        filters.push(memcmp(METADATA_CREATORS_OFFSET, &[0]));
+        1
+    } else {
+        let mut bytes = vec![1];
+        bytes.extend_from_slice(&(creators as u32).to_le_bytes());
+        filters.push(memcmp(METADATA_CREATORS_OFFSET, &bytes));
+        1 + 4 + creators * MAX_CREATOR_LEN
+    };
+
+    // primary sale happened (1), is mutable (1) and edition nonce (2)
+    let offset = METADATA_CREATORS_OFFSET + creators_len + 1 + 1 + 2;
+    filters.push(memcmp(offset, &[1, token_standard as u8]));
+
+    filters
+}
+
+/// Token record accounts with the specified state.
+pub fn token_records_by_state(state: TokenState) -> Vec<AccountFilter> {
+    vec![
+        key(Key::TokenRecord),
+        memcmp(TOKEN_RECORD_STATE_OFFSET, &[state as u8]),
+    ]
+}
+
+/// Token record accounts with the specified delegate.
+///
+/// Returns two queries: the first matches token records without a rule set revision
+/// and the second matches token records with a rule set revision.
+pub fn token_records_by_delegate(delegate: &Pubkey) -> [Vec<AccountFilter>; 2] {
+    let mut bytes = vec![1];
+    bytes.extend_from_slice(delegate.as_ref());
+
+    let query = |revision_len: usize| {
+        vec![
+            key(Key::TokenRecord),
+            memcmp(
+                TOKEN_RECORD_RULE_SET_REVISION_OFFSET,
+                &[u8::from(revision_len > 1)],
+            ),
+            memcmp(TOKEN_RECORD_RULE_SET_REVISION_OFFSET + revision_len, &bytes),
+        ]
+    };
+
+    // rule set revision is either None (1 byte) or Some(u64) (9 bytes)
+    [query(1), query(9)]
+}
+
+/// Master edition accounts of assets with the specified token standard.
+///
+/// Only matches master edition accounts with the current length; accounts that
+/// were not resized keep the token standard at a different offset.
+pub fn master_editions_by_token_standard(token_standard: TokenStandard) -> Vec<AccountFilter> {
+    vec![
+        key(Key::MasterEditionV2),
+        AccountFilter::DataSize(MAX_MASTER_EDITION_LEN as u64),
+        memcmp(MAX_MASTER_EDITION_LEN - 1, &[token_standard as u8]),
+    ]
+}
+
+fn memcmp(offset: usize, bytes: &[u8]) -> AccountFilter {
+    AccountFilter::Memcmp {
+        offset,
+        bytes: bytes.to_vec(),
+    }
+}
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::DelegateExpiry;
 use crate::generated::types::Key;
 use crate::hooked::MetadataDelegateRoleSeed;
 #[cfg(feature = "anchor")]
@@ -35,10 +36,11 @@ pub struct MetadataDelegateRecord {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub update_authority: Pubkey,
+    pub expiry: Option<DelegateExpiry>,
 }
 
 impl MetadataDelegateRecord {
-    pub const LEN: usize = 98;
+    pub const LEN: usize = 108;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -13,7 +13,9 @@ pub(crate) mod r#edition_marker_v2;
 pub(crate) mod r#holder_delegate_record;
 pub(crate) mod r#master_edition;
 pub(crate) mod r#metadata;
+pub(crate) mod r#metadata_attributes;
 pub(crate) mod r#metadata_delegate_record;
+pub(crate) mod r#pending_update_authority;
 pub(crate) mod r#token_owned_escrow;
 pub(crate) mod r#token_record;
 pub(crate) mod r#use_authority_record;
@@ -26,7 +28,9 @@ pub use self::r#edition_marker_v2::*;
 pub use self::r#holder_delegate_record::*;
 pub use self::r#master_edition::*;
 pub use self::r#metadata::*;
+pub use self::r#metadata_attributes::*;
 pub use self::r#metadata_delegate_record::*;
+pub use self::r#pending_update_authority::*;
 pub use self::r#token_owned_escrow::*;
 pub use self::r#token_record::*;
 pub use self::r#use_authority_record::*;
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::DelegateExpiry;
 use crate::generated::types::Key;
 use crate::generated::types::TokenDelegateRole;
 use crate::generated::types::TokenState;
@@ -26,10 +27,12 @@ pub struct TokenRecord {
     pub delegate: Option<Pubkey>,
     pub delegate_role: Option<TokenDelegateRole>,
     pub locked_transfer: Option<Pubkey>,
+    pub delegate_expiry: Option<DelegateExpiry>,
+    pub unlock_after: Option<i64>,
 }
 
 impl TokenRecord {
-    pub const LEN: usize = 80;
+    pub const LEN: usize = 99;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -620,6 +620,50 @@ pub enum MplTokenMetadataError {
     /// 202 (0xCA) - Conditions for closing not met
     #[error("Conditions for closing not met")]
     ConditionsForClosingNotMet,
+    /// 203 (0xCB) - Account is required to be a signer
+    #[error("Account is required to be a signer")]
+    AccountNotSigner,
+    /// 204 (0xCC) - Account is required to be writable
+    #[error("Account is required to be writable")]
+    AccountNotWritable,
+    /// 205 (0xCD) - Authority does not match the pending update authority
+    #[error("Authority does not match the pending update authority")]
+    PendingUpdateAuthorityMismatch,
+    /// 206 (0xCE) - Update authority changed after the handoff was proposed
+    #[error("Update authority changed after the handoff was proposed")]
+    StalePendingUpdateAuthority,
+    /// 207 (0xCF) - Delegate expiry must be in the future
+    #[error("Delegate expiry must be in the future")]
+    InvalidDelegateExpiry,
+    /// 208 (0xD0) - Delegate has not expired
+    #[error("Delegate has not expired")]
+    DelegateNotExpired,
+    /// 209 (0xD1) - The account that owns the record still exists
+    #[error("The account that owns the record still exists")]
+    RecordNotOrphaned,
+    /// 210 (0xD2) - Rent destination must be the token owner or the authority that approved the delegate
+    #[error(
+        "Rent destination must be the token owner or the authority that approved the delegate"
+    )]
+    InvalidRentDestination,
+    /// 211 (0xD3) - Attribute key too long
+    #[error("Attribute key too long")]
+    AttributeKeyTooLong,
+    /// 212 (0xD4) - Attribute value too long
+    #[error("Attribute value too long")]
+    AttributeValueTooLong,
+    /// 213 (0xD5) - Too many attributes
+    #[error("Too many attributes")]
+    TooManyAttributes,
+    /// 214 (0xD6) - Token metadata interface instruction is not supported
+    #[error("Token metadata interface instruction is not supported")]
+    UnsupportedTokenMetadataInterfaceInstruction,
+    /// 215 (0xD7) - Unlock timestamp must be in the future
+    #[error("Unlock timestamp must be in the future")]
+    InvalidUnlockTimestamp,
+    /// 216 (0xD8) - Lock has not reached its unlock timestamp
+    #[error("Lock has not reached its unlock timestamp")]
+    LockNotExpired,
 }
 
 impl From<MplTokenMetadataError> for ProgramError {
@@ -835,6 +879,20 @@ impl TryFrom<u32> for MplTokenMetadataError {
             200 => Ok(MplTokenMetadataError::InvalidEditionAccountLength),
             201 => Ok(MplTokenMetadataError::AccountAlreadyResized),
             202 => Ok(MplTokenMetadataError::ConditionsForClosingNotMet),
+            203 => Ok(MplTokenMetadataError::AccountNotSigner),
+            204 => Ok(MplTokenMetadataError::AccountNotWritable),
+            205 => Ok(MplTokenMetadataError::PendingUpdateAuthorityMismatch),
+            206 => Ok(MplTokenMetadataError::StalePendingUpdateAuthority),
+            207 => Ok(MplTokenMetadataError::InvalidDelegateExpiry),
+            208 => Ok(MplTokenMetadataError::DelegateNotExpired),
+            209 => Ok(MplTokenMetadataError::RecordNotOrphaned),
+            210 => Ok(MplTokenMetadataError::InvalidRentDestination),
+            211 => Ok(MplTokenMetadataError::AttributeKeyTooLong),
+            212 => Ok(MplTokenMetadataError::AttributeValueTooLong),
+            213 => Ok(MplTokenMetadataError::TooManyAttributes),
+            214 => Ok(MplTokenMetadataError::UnsupportedTokenMetadataInterfaceInstruction),
+            215 => Ok(MplTokenMetadataError::InvalidUnlockTimestamp),
+            216 => Ok(MplTokenMetadataError::LockNotExpired),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -1046,6 +1104,20 @@ impl ToStr for MplTokenMetadataError {
                             MplTokenMetadataError::InvalidEditionAccountLength => "Edition account has an invalid length",
                             MplTokenMetadataError::AccountAlreadyResized => "Account has already been resized",
                             MplTokenMetadataError::ConditionsForClosingNotMet => "Conditions for closing not met",
+                            MplTokenMetadataError::AccountNotSigner => "Account is required to be a signer",
+                            MplTokenMetadataError::AccountNotWritable => "Account is required to be writable",
+                            MplTokenMetadataError::PendingUpdateAuthorityMismatch => "Authority does not match the pending update authority",
+                            MplTokenMetadataError::StalePendingUpdateAuthority => "Update authority changed after the handoff was proposed",
+                            MplTokenMetadataError::InvalidDelegateExpiry => "Delegate expiry must be in the future",
+                            MplTokenMetadataError::DelegateNotExpired => "Delegate has not expired",
+                            MplTokenMetadataError::RecordNotOrphaned => "The account that owns the record still exists",
+                            MplTokenMetadataError::InvalidRentDestination => "Rent destination must be the token owner or the authority that approved the delegate",
+                            MplTokenMetadataError::AttributeKeyTooLong => "Attribute key too long",
+                            MplTokenMetadataError::AttributeValueTooLong => "Attribute value too long",
+                            MplTokenMetadataError::TooManyAttributes => "Too many attributes",
+                            MplTokenMetadataError::UnsupportedTokenMetadataInterfaceInstruction => "Token metadata interface instruction is not supported",
+                            MplTokenMetadataError::InvalidUnlockTimestamp => "Unlock timestamp must be in the future",
+                            MplTokenMetadataError::LockNotExpired => "Lock has not reached its unlock timestamp",
                     }
     }
 }
@@ -0,0 +1,478 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::AcceptUpdateAuthorityArgs;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AcceptUpdateAuthority {
+    /// Proposed update authority
+    pub new_update_authority: solana_program::pubkey::Pubkey,
+    /// Metadata account
+    pub metadata: solana_program::pubkey::Pubkey,
+    /// Pending update authority account
+    pub pending_update_authority: solana_program::pubkey::Pubkey,
+    /// Update authority that proposed the handoff, which receives the rent
+    pub update_authority: solana_program::pubkey::Pubkey,
+}
+
+impl AcceptUpdateAuthority {
+    pub fn instruction(
+        &self,
+        args: AcceptUpdateAuthorityInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AcceptUpdateAuthorityInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.new_update_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.metadata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pending_update_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.update_authority,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(AcceptUpdateAuthorityInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AcceptUpdateAuthorityInstructionData {
+    discriminator: u8,
+}
+
+impl AcceptUpdateAuthorityInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 60 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptUpdateAuthorityInstructionArgs {
+    pub accept_update_authority_args: AcceptUpdateAuthorityArgs,
+}
+
+/// Instruction builder for `AcceptUpdateAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` new_update_authority
+///   1. `[writable]` metadata
+///   2. `[writable]` pending_update_authority
+///   3. `[writable]` update_authority
+#[derive(Default)]
+pub struct AcceptUpdateAuthorityBuilder {
+    new_update_authority: Option<solana_program::pubkey::Pubkey>,
+    metadata: Option<solana_program::pubkey::Pubkey>,
+    pending_update_authority: Option<solana_program::pubkey::Pubkey>,
+    update_authority: Option<solana_program::pubkey::Pubkey>,
+    accept_update_authority_args: Option<AcceptUpdateAuthorityArgs>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AcceptUpdateAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Proposed update authority
+    #[inline(always)]
+    pub fn new_update_authority(
+        &mut self,
+        new_update_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.new_update_authority = Some(new_update_authority);
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// Pending update authority account
+    #[inline(always)]
+    pub fn pending_update_authority(
+        &mut self,
+        pending_update_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.pending_update_authority = Some(pending_update_authority);
+        self
+    }
+    /// Update authority that proposed the handoff, which receives the rent
+    #[inline(always)]
+    pub fn update_authority(
+        &mut self,
+        update_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn accept_update_authority_args(
+        &mut self,
+        accept_update_authority_args: AcceptUpdateAuthorityArgs,
+    ) -> &mut Self {
+        self.accept_update_authority_args = Some(accept_update_authority_args);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AcceptUpdateAuthority {
+            new_update_authority: self
+                .new_update_authority
+                .expect("new_update_authority is not set"),
+            metadata: self.metadata.expect("metadata is not set"),
+            pending_update_authority: self
+                .pending_update_authority
+                .expect("pending_update_authority is not set"),
+            update_authority: self.update_authority.expect("update_authority is not set"),
+        };
+        let args = AcceptUpdateAuthorityInstructionArgs {
+            accept_update_authority_args: self
+                .accept_update_authority_args
+                .clone()
+                .expect("accept_update_authority_args is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `accept_update_authority` CPI accounts.
+pub struct AcceptUpdateAuthorityCpiAccounts<'a, 'b> {
+    /// Proposed update authority
+    pub new_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Metadata account
+    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pending update authority account
+    pub pending_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Update authority that proposed the handoff, which receives the rent
+    pub update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `accept_update_authority` CPI instruction.
+pub struct AcceptUpdateAuthorityCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Proposed update authority
+    pub new_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Metadata account
+    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pending update authority account
+    pub pending_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Update authority that proposed the handoff, which receives the rent
+    pub update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AcceptUpdateAuthorityInstructionArgs,
+}
+
+impl<'a, 'b> AcceptUpdateAuthorityCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AcceptUpdateAuthorityCpiAccounts<'a, 'b>,
+        args: AcceptUpdateAuthorityInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            new_update_authority: accounts.new_update_authority,
+            metadata: accounts.metadata,
+            pending_update_authority: accounts.pending_update_authority,
+            update_authority: accounts.update_authority,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.new_update_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.metadata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pending_update_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.update_authority.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(AcceptUpdateAuthorityInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.new_update_authority.clone());
+        account_infos.push(self.metadata.clone());
+        account_infos.push(self.pending_update_authority.clone());
+        account_infos.push(self.update_authority.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AcceptUpdateAuthority` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` new_update_authority
+///   1. `[writable]` metadata
+///   2. `[writable]` pending_update_authority
+///   3. `[writable]` update_authority
+pub struct AcceptUpdateAuthorityCpiBuilder<'a, 'b> {
+    instruction: Box<AcceptUpdateAuthorityCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AcceptUpdateAuthorityCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AcceptUpdateAuthorityCpiBuilderInstruction {
+            __program: program,
+            new_update_authority: None,
+            metadata: None,
+            pending_update_authority: None,
+            update_authority: None,
+            accept_update_authority_args: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Proposed update authority
+    #[inline(always)]
+    pub fn new_update_authority(
+        &mut self,
+        new_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_update_authority = Some(new_update_authority);
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(
+        &mut self,
+        metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.metadata = Some(metadata);
+        self
+    }
+    /// Pending update authority account
+    #[inline(always)]
+    pub fn pending_update_authority(
+        &mut self,
+        pending_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_update_authority = Some(pending_update_authority);
+        self
+    }
+    /// Update authority that proposed the handoff, which receives the rent
+    #[inline(always)]
+    pub fn update_authority(
+        &mut self,
+        update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.update_authority = Some(update_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn accept_update_authority_args(
+        &mut self,
+        accept_update_authority_args: AcceptUpdateAuthorityArgs,
+    ) -> &mut Self {
+        self.instruction.accept_update_authority_args = Some(accept_update_authority_args);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AcceptUpdateAuthorityInstructionArgs {
+            accept_update_authority_args: self
+                .instruction
+                .accept_update_authority_args
+                .clone()
+                .expect("accept_update_authority_args is not set"),
+        };
+        let instruction = AcceptUpdateAuthorityCpi {
+            __program: self.instruction.__program,
+
+            new_update_authority: self
+                .instruction
+                .new_update_authority
+                .expect("new_update_authority is not set"),
+
+            metadata: self.instruction.metadata.expect("metadata is not set"),
+
+            pending_update_authority: self
+                .instruction
+                .pending_update_authority
+                .expect("pending_update_authority is not set"),
+
+            update_authority: self
+                .instruction
+                .update_authority
+                .expect("update_authority is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AcceptUpdateAuthorityCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    new_update_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pending_update_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    update_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    accept_update_authority_args: Option<AcceptUpdateAuthorityArgs>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -40,6 +40,10 @@ pub struct BurnV1 {
     pub sysvar_instructions: solana_program::pubkey::Pubkey,
     /// SPL Token Program
     pub spl_token_program: solana_program::pubkey::Pubkey,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl BurnV1 {
@@ -55,7 +59,7 @@ impl BurnV1 {
         args: BurnV1InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -158,6 +162,28 @@ impl BurnV1 {
             self.spl_token_program,
             false,
         ));
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&(BurnV1InstructionData::new())).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -213,6 +239,8 @@ pub struct BurnV1InstructionArgs {
 ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   12. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
 ///   13. `[optional]` spl_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   14. `[optional]` authorization_rules_program
+///   15. `[optional]` authorization_rules
 #[derive(Default)]
 pub struct BurnV1Builder {
     authority: Option<solana_program::pubkey::Pubkey>,
@@ -229,6 +257,8 @@ pub struct BurnV1Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     sysvar_instructions: Option<solana_program::pubkey::Pubkey>,
     spl_token_program: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -355,6 +385,26 @@ impl BurnV1Builder {
         self.spl_token_program = Some(spl_token_program);
         self
     }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
     /// `[optional argument, defaults to '1']`
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
@@ -402,6 +452,8 @@ impl BurnV1Builder {
             spl_token_program: self.spl_token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
         };
         let args = BurnV1InstructionArgs {
             amount: self.amount.clone().unwrap_or(1),
@@ -441,6 +493,10 @@ pub struct BurnV1CpiAccounts<'a, 'b> {
     pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL Token Program
     pub spl_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `burn_v1` CPI instruction.
@@ -475,6 +531,10 @@ pub struct BurnV1Cpi<'a, 'b> {
     pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
     /// SPL Token Program
     pub spl_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: BurnV1InstructionArgs,
 }
@@ -501,6 +561,8 @@ impl<'a, 'b> BurnV1Cpi<'a, 'b> {
             system_program: accounts.system_program,
             sysvar_instructions: accounts.sysvar_instructions,
             spl_token_program: accounts.spl_token_program,
+            authorization_rules_program: accounts.authorization_rules_program,
+            authorization_rules: accounts.authorization_rules,
             __args: args,
         }
     }
@@ -537,7 +599,7 @@ impl<'a, 'b> BurnV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -643,6 +705,28 @@ impl<'a, 'b> BurnV1Cpi<'a, 'b> {
             *self.spl_token_program.key,
             false,
         ));
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -659,7 +743,7 @@ impl<'a, 'b> BurnV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(16 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         if let Some(collection_metadata) = self.collection_metadata {
@@ -689,6 +773,12 @@ impl<'a, 'b> BurnV1Cpi<'a, 'b> {
         account_infos.push(self.system_program.clone());
         account_infos.push(self.sysvar_instructions.clone());
         account_infos.push(self.spl_token_program.clone());
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            account_infos.push(authorization_rules_program.clone());
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            account_infos.push(authorization_rules.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -719,6 +809,8 @@ impl<'a, 'b> BurnV1Cpi<'a, 'b> {
 ///   11. `[]` system_program
 ///   12. `[]` sysvar_instructions
 ///   13. `[]` spl_token_program
+///   14. `[optional]` authorization_rules_program
+///   15. `[optional]` authorization_rules
 pub struct BurnV1CpiBuilder<'a, 'b> {
     instruction: Box<BurnV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -741,6 +833,8 @@ impl<'a, 'b> BurnV1CpiBuilder<'a, 'b> {
             system_program: None,
             sysvar_instructions: None,
             spl_token_program: None,
+            authorization_rules_program: None,
+            authorization_rules: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -873,6 +967,26 @@ impl<'a, 'b> BurnV1CpiBuilder<'a, 'b> {
         self.instruction.spl_token_program = Some(spl_token_program);
         self
     }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules = authorization_rules;
+        self
+    }
     /// `[optional argument, defaults to '1']`
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
@@ -962,6 +1076,10 @@ impl<'a, 'b> BurnV1CpiBuilder<'a, 'b> {
                 .instruction
                 .spl_token_program
                 .expect("spl_token_program is not set"),
+
+            authorization_rules_program: self.instruction.authorization_rules_program,
+
+            authorization_rules: self.instruction.authorization_rules,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -987,6 +1105,8 @@ struct BurnV1CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     sysvar_instructions: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     spl_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -0,0 +1,527 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::CloseOrphanedRecordArgs;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CloseOrphanedRecord {
+    /// Token record or delegate record account
+    pub record: solana_program::pubkey::Pubkey,
+    /// Mint of the asset
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Closed token account (required for token records)
+    pub token: Option<solana_program::pubkey::Pubkey>,
+    /// Closed metadata account (required for delegate records)
+    pub metadata: Option<solana_program::pubkey::Pubkey>,
+    /// Token owner or update authority that approved the delegate
+    pub destination: solana_program::pubkey::Pubkey,
+}
+
+impl CloseOrphanedRecord {
+    pub fn instruction(
+        &self,
+        args: CloseOrphanedRecordInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: CloseOrphanedRecordInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.record,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(metadata) = self.metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                metadata, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(CloseOrphanedRecordInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct CloseOrphanedRecordInstructionData {
+    discriminator: u8,
+}
+
+impl CloseOrphanedRecordInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 64 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CloseOrphanedRecordInstructionArgs {
+    pub close_orphaned_record_args: CloseOrphanedRecordArgs,
+}
+
+/// Instruction builder for `CloseOrphanedRecord`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` record
+///   1. `[]` mint
+///   2. `[optional]` token
+///   3. `[optional]` metadata
+///   4. `[writable]` destination
+#[derive(Default)]
+pub struct CloseOrphanedRecordBuilder {
+    record: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    token: Option<solana_program::pubkey::Pubkey>,
+    metadata: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    close_orphaned_record_args: Option<CloseOrphanedRecordArgs>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CloseOrphanedRecordBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Token record or delegate record account
+    #[inline(always)]
+    pub fn record(&mut self, record: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.record = Some(record);
+        self
+    }
+    /// Mint of the asset
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// `[optional account]`
+    /// Closed token account (required for token records)
+    #[inline(always)]
+    pub fn token(&mut self, token: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.token = token;
+        self
+    }
+    /// `[optional account]`
+    /// Closed metadata account (required for delegate records)
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.metadata = metadata;
+        self
+    }
+    /// Token owner or update authority that approved the delegate
+    #[inline(always)]
+    pub fn destination(&mut self, destination: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    #[inline(always)]
+    pub fn close_orphaned_record_args(
+        &mut self,
+        close_orphaned_record_args: CloseOrphanedRecordArgs,
+    ) -> &mut Self {
+        self.close_orphaned_record_args = Some(close_orphaned_record_args);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CloseOrphanedRecord {
+            record: self.record.expect("record is not set"),
+            mint: self.mint.expect("mint is not set"),
+            token: self.token,
+            metadata: self.metadata,
+            destination: self.destination.expect("destination is not set"),
+        };
+        let args = CloseOrphanedRecordInstructionArgs {
+            close_orphaned_record_args: self
+                .close_orphaned_record_args
+                .clone()
+                .expect("close_orphaned_record_args is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `close_orphaned_record` CPI accounts.
+pub struct CloseOrphanedRecordCpiAccounts<'a, 'b> {
+    /// Token record or delegate record account
+    pub record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Mint of the asset
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Closed token account (required for token records)
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Closed metadata account (required for delegate records)
+    pub metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token owner or update authority that approved the delegate
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `close_orphaned_record` CPI instruction.
+pub struct CloseOrphanedRecordCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token record or delegate record account
+    pub record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Mint of the asset
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Closed token account (required for token records)
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Closed metadata account (required for delegate records)
+    pub metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token owner or update authority that approved the delegate
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: CloseOrphanedRecordInstructionArgs,
+}
+
+impl<'a, 'b> CloseOrphanedRecordCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CloseOrphanedRecordCpiAccounts<'a, 'b>,
+        args: CloseOrphanedRecordInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            record: accounts.record,
+            mint: accounts.mint,
+            token: accounts.token,
+            metadata: accounts.metadata,
+            destination: accounts.destination,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.record.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(metadata) = self.metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *metadata.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(CloseOrphanedRecordInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.record.clone());
+        account_infos.push(self.mint.clone());
+        if let Some(token) = self.token {
+            account_infos.push(token.clone());
+        }
+        if let Some(metadata) = self.metadata {
+            account_infos.push(metadata.clone());
+        }
+        account_infos.push(self.destination.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CloseOrphanedRecord` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` record
+///   1. `[]` mint
+///   2. `[optional]` token
+///   3. `[optional]` metadata
+///   4. `[writable]` destination
+pub struct CloseOrphanedRecordCpiBuilder<'a, 'b> {
+    instruction: Box<CloseOrphanedRecordCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CloseOrphanedRecordCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CloseOrphanedRecordCpiBuilderInstruction {
+            __program: program,
+            record: None,
+            mint: None,
+            token: None,
+            metadata: None,
+            destination: None,
+            close_orphaned_record_args: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Token record or delegate record account
+    #[inline(always)]
+    pub fn record(
+        &mut self,
+        record: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record = Some(record);
+        self
+    }
+    /// Mint of the asset
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// `[optional account]`
+    /// Closed token account (required for token records)
+    #[inline(always)]
+    pub fn token(
+        &mut self,
+        token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token = token;
+        self
+    }
+    /// `[optional account]`
+    /// Closed metadata account (required for delegate records)
+    #[inline(always)]
+    pub fn metadata(
+        &mut self,
+        metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.metadata = metadata;
+        self
+    }
+    /// Token owner or update authority that approved the delegate
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination = Some(destination);
+        self
+    }
+    #[inline(always)]
+    pub fn close_orphaned_record_args(
+        &mut self,
+        close_orphaned_record_args: CloseOrphanedRecordArgs,
+    ) -> &mut Self {
+        self.instruction.close_orphaned_record_args = Some(close_orphaned_record_args);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = CloseOrphanedRecordInstructionArgs {
+            close_orphaned_record_args: self
+                .instruction
+                .close_orphaned_record_args
+                .clone()
+                .expect("close_orphaned_record_args is not set"),
+        };
+        let instruction = CloseOrphanedRecordCpi {
+            __program: self.instruction.__program,
+
+            record: self.instruction.record.expect("record is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            token: self.instruction.token,
+
+            metadata: self.instruction.metadata,
+
+            destination: self
+                .instruction
+                .destination
+                .expect("destination is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct CloseOrphanedRecordCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    close_orphaned_record_args: Option<CloseOrphanedRecordArgs>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,1005 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::AuthorizationData;
+use crate::generated::types::DelegateExpiry;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct DelegateCollectionItemV2 {
+    /// Delegate record account
+    pub delegate_record: Option<solana_program::pubkey::Pubkey>,
+    /// Owner of the delegated account
+    pub delegate: solana_program::pubkey::Pubkey,
+    /// Metadata account
+    pub metadata: solana_program::pubkey::Pubkey,
+    /// Master Edition account
+    pub master_edition: Option<solana_program::pubkey::Pubkey>,
+    /// Token record account
+    pub token_record: Option<solana_program::pubkey::Pubkey>,
+    /// Mint of metadata
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Token account of mint
+    pub token: Option<solana_program::pubkey::Pubkey>,
+    /// Update authority or token owner
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Payer
+    pub payer: solana_program::pubkey::Pubkey,
+    /// System Program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: solana_program::pubkey::Pubkey,
+    /// SPL Token Program
+    pub spl_token_program: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl DelegateCollectionItemV2 {
+    pub fn instruction(
+        &self,
+        args: DelegateCollectionItemV2InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: DelegateCollectionItemV2InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                delegate_record,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.delegate,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.metadata,
+            false,
+        ));
+        if let Some(master_edition) = self.master_edition {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                master_edition,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(token_record) = self.token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                token_record,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new(token, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        if let Some(spl_token_program) = self.spl_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                spl_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(DelegateCollectionItemV2InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct DelegateCollectionItemV2InstructionData {
+    discriminator: u8,
+    delegate_collection_item_v2_discriminator: u8,
+}
+
+impl DelegateCollectionItemV2InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 44,
+            delegate_collection_item_v2_discriminator: 23,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateCollectionItemV2InstructionArgs {
+    pub expiry: Option<DelegateExpiry>,
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+/// Instruction builder for `DelegateCollectionItemV2`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, optional]` delegate_record
+///   1. `[]` delegate
+///   2. `[writable]` metadata
+///   3. `[optional]` master_edition
+///   4. `[writable, optional]` token_record
+///   5. `[]` mint
+///   6. `[writable, optional]` token
+///   7. `[signer]` authority
+///   8. `[writable, signer]` payer
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+///   11. `[optional]` spl_token_program
+///   12. `[optional]` authorization_rules_program
+///   13. `[optional]` authorization_rules
+#[derive(Default)]
+pub struct DelegateCollectionItemV2Builder {
+    delegate_record: Option<solana_program::pubkey::Pubkey>,
+    delegate: Option<solana_program::pubkey::Pubkey>,
+    metadata: Option<solana_program::pubkey::Pubkey>,
+    master_edition: Option<solana_program::pubkey::Pubkey>,
+    token_record: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    token: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    sysvar_instructions: Option<solana_program::pubkey::Pubkey>,
+    spl_token_program: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    expiry: Option<DelegateExpiry>,
+    authorization_data: Option<AuthorizationData>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl DelegateCollectionItemV2Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// `[optional account]`
+    /// Delegate record account
+    #[inline(always)]
+    pub fn delegate_record(
+        &mut self,
+        delegate_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.delegate_record = delegate_record;
+        self
+    }
+    /// Owner of the delegated account
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.delegate = Some(delegate);
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Master Edition account
+    #[inline(always)]
+    pub fn master_edition(
+        &mut self,
+        master_edition: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.master_edition = master_edition;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account
+    #[inline(always)]
+    pub fn token_record(
+        &mut self,
+        token_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_record = token_record;
+        self
+    }
+    /// Mint of metadata
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// `[optional account]`
+    /// Token account of mint
+    #[inline(always)]
+    pub fn token(&mut self, token: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.token = token;
+        self
+    }
+    /// Update authority or token owner
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(
+        &mut self,
+        sysvar_instructions: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// `[optional account]`
+    /// SPL Token Program
+    #[inline(always)]
+    pub fn spl_token_program(
+        &mut self,
+        spl_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.spl_token_program = spl_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn expiry(&mut self, expiry: DelegateExpiry) -> &mut Self {
+        self.expiry = Some(expiry);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.authorization_data = Some(authorization_data);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = DelegateCollectionItemV2 {
+            delegate_record: self.delegate_record,
+            delegate: self.delegate.expect("delegate is not set"),
+            metadata: self.metadata.expect("metadata is not set"),
+            master_edition: self.master_edition,
+            token_record: self.token_record,
+            mint: self.mint.expect("mint is not set"),
+            token: self.token,
+            authority: self.authority.expect("authority is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(solana_program::pubkey!(
+                "Sysvar1nstructions1111111111111111111111111"
+            )),
+            spl_token_program: self.spl_token_program,
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
+        };
+        let args = DelegateCollectionItemV2InstructionArgs {
+            expiry: self.expiry.clone(),
+            authorization_data: self.authorization_data.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `delegate_collection_item_v2` CPI accounts.
+pub struct DelegateCollectionItemV2CpiAccounts<'a, 'b> {
+    /// Delegate record account
+    pub delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner of the delegated account
+    pub delegate: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Metadata account
+    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Master Edition account
+    pub master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account
+    pub token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Mint of metadata
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account of mint
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Update authority or token owner
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System Program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Instructions sysvar account
+    pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token Program
+    pub spl_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `delegate_collection_item_v2` CPI instruction.
+pub struct DelegateCollectionItemV2Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Delegate record account
+    pub delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Owner of the delegated account
+    pub delegate: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Metadata account
+    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Master Edition account
+    pub master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account
+    pub token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Mint of metadata
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account of mint
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Update authority or token owner
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System Program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Instructions sysvar account
+    pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token Program
+    pub spl_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: DelegateCollectionItemV2InstructionArgs,
+}
+
+impl<'a, 'b> DelegateCollectionItemV2Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: DelegateCollectionItemV2CpiAccounts<'a, 'b>,
+        args: DelegateCollectionItemV2InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            delegate_record: accounts.delegate_record,
+            delegate: accounts.delegate,
+            metadata: accounts.metadata,
+            master_edition: accounts.master_edition,
+            token_record: accounts.token_record,
+            mint: accounts.mint,
+            token: accounts.token,
+            authority: accounts.authority,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            sysvar_instructions: accounts.sysvar_instructions,
+            spl_token_program: accounts.spl_token_program,
+            authorization_rules_program: accounts.authorization_rules_program,
+            authorization_rules: accounts.authorization_rules,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *delegate_record.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.delegate.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.metadata.key,
+            false,
+        ));
+        if let Some(master_edition) = self.master_edition {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *master_edition.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(token_record) = self.token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *token_record.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *token.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.sysvar_instructions.key,
+            false,
+        ));
+        if let Some(spl_token_program) = self.spl_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *spl_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(DelegateCollectionItemV2InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        if let Some(delegate_record) = self.delegate_record {
+            account_infos.push(delegate_record.clone());
+        }
+        account_infos.push(self.delegate.clone());
+        account_infos.push(self.metadata.clone());
+        if let Some(master_edition) = self.master_edition {
+            account_infos.push(master_edition.clone());
+        }
+        if let Some(token_record) = self.token_record {
+            account_infos.push(token_record.clone());
+        }
+        account_infos.push(self.mint.clone());
+        if let Some(token) = self.token {
+            account_infos.push(token.clone());
+        }
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.sysvar_instructions.clone());
+        if let Some(spl_token_program) = self.spl_token_program {
+            account_infos.push(spl_token_program.clone());
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            account_infos.push(authorization_rules_program.clone());
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            account_infos.push(authorization_rules.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `DelegateCollectionItemV2` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, optional]` delegate_record
+///   1. `[]` delegate
+///   2. `[writable]` metadata
+///   3. `[optional]` master_edition
+///   4. `[writable, optional]` token_record
+///   5. `[]` mint
+///   6. `[writable, optional]` token
+///   7. `[signer]` authority
+///   8. `[writable, signer]` payer
+///   9. `[]` system_program
+///   10. `[]` sysvar_instructions
+///   11. `[optional]` spl_token_program
+///   12. `[optional]` authorization_rules_program
+///   13. `[optional]` authorization_rules
+pub struct DelegateCollectionItemV2CpiBuilder<'a, 'b> {
+    instruction: Box<DelegateCollectionItemV2CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> DelegateCollectionItemV2CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(DelegateCollectionItemV2CpiBuilderInstruction {
+            __program: program,
+            delegate_record: None,
+            delegate: None,
+            metadata: None,
+            master_edition: None,
+            token_record: None,
+            mint: None,
+            token: None,
+            authority: None,
+            payer: None,
+            system_program: None,
+            sysvar_instructions: None,
+            spl_token_program: None,
+            authorization_rules_program: None,
+            authorization_rules: None,
+            expiry: None,
+            authorization_data: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// `[optional account]`
+    /// Delegate record account
+    #[inline(always)]
+    pub fn delegate_record(
+        &mut self,
+        delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegate_record = delegate_record;
+        self
+    }
+    /// Owner of the delegated account
+    #[inline(always)]
+    pub fn delegate(
+        &mut self,
+        delegate: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.delegate = Some(delegate);
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(
+        &mut self,
+        metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Master Edition account
+    #[inline(always)]
+    pub fn master_edition(
+        &mut self,
+        master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.master_edition = master_edition;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account
+    #[inline(always)]
+    pub fn token_record(
+        &mut self,
+        token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_record = token_record;
+        self
+    }
+    /// Mint of metadata
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// `[optional account]`
+    /// Token account of mint
+    #[inline(always)]
+    pub fn token(
+        &mut self,
+        token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token = token;
+        self
+    }
+    /// Update authority or token owner
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// System Program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(
+        &mut self,
+        sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// `[optional account]`
+    /// SPL Token Program
+    #[inline(always)]
+    pub fn spl_token_program(
+        &mut self,
+        spl_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.spl_token_program = spl_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn expiry(&mut self, expiry: DelegateExpiry) -> &mut Self {
+        self.instruction.expiry = Some(expiry);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.instruction.authorization_data = Some(authorization_data);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = DelegateCollectionItemV2InstructionArgs {
+            expiry: self.instruction.expiry.clone(),
+            authorization_data: self.instruction.authorization_data.clone(),
+        };
+        let instruction = DelegateCollectionItemV2Cpi {
+            __program: self.instruction.__program,
+
+            delegate_record: self.instruction.delegate_record,
+
+            delegate: self.instruction.delegate.expect("delegate is not set"),
+
+            metadata: self.instruction.metadata.expect("metadata is not set"),
+
+            master_edition: self.instruction.master_edition,
+
+            token_record: self.instruction.token_record,
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            token: self.instruction.token,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            sysvar_instructions: self
+                .instruction
+                .sysvar_instructions
+                .expect("sysvar_instructions is not set"),
+
+            spl_token_program: self.instruction.spl_token_program,
+
+            authorization_rules_program: self.instruction.authorization_rules_program,
+
+            authorization_rules: self.instruction.authorization_rules,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct DelegateCollectionItemV2CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    sysvar_instructions: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    spl_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    expiry: Option<DelegateExpiry>,
+    authorization_data: Option<AuthorizationData>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
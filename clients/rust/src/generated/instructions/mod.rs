@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_update_authority;
 pub(crate) mod r#approve_collection_authority;
 pub(crate) mod r#approve_use_authority;
 pub(crate) mod r#bubblegum_set_collection_size;
@@ -12,8 +13,11 @@ pub(crate) mod r#burn;
 pub(crate) mod r#burn_edition_nft;
 pub(crate) mod r#burn_nft;
 pub(crate) mod r#burn_v1;
+pub(crate) mod r#burn_v2;
+pub(crate) mod r#cancel_update_authority;
 pub(crate) mod r#close_accounts;
 pub(crate) mod r#close_escrow_account;
+pub(crate) mod r#close_orphaned_record;
 pub(crate) mod r#collect;
 pub(crate) mod r#convert_master_edition_v1_to_v2;
 pub(crate) mod r#create;
@@ -23,19 +27,30 @@ pub(crate) mod r#create_metadata_account_v3;
 pub(crate) mod r#create_v1;
 pub(crate) mod r#delegate;
 pub(crate) mod r#delegate_authority_item_v1;
+pub(crate) mod r#delegate_authority_item_v2;
 pub(crate) mod r#delegate_collection_item_v1;
+pub(crate) mod r#delegate_collection_item_v2;
 pub(crate) mod r#delegate_collection_v1;
+pub(crate) mod r#delegate_collection_v2;
 pub(crate) mod r#delegate_data_item_v1;
+pub(crate) mod r#delegate_data_item_v2;
 pub(crate) mod r#delegate_data_v1;
+pub(crate) mod r#delegate_data_v2;
 pub(crate) mod r#delegate_locked_transfer_v1;
 pub(crate) mod r#delegate_print_delegate_v1;
 pub(crate) mod r#delegate_programmable_config_item_v1;
+pub(crate) mod r#delegate_programmable_config_item_v2;
 pub(crate) mod r#delegate_programmable_config_v1;
+pub(crate) mod r#delegate_programmable_config_v2;
 pub(crate) mod r#delegate_sale_v1;
+pub(crate) mod r#delegate_sale_v2;
 pub(crate) mod r#delegate_staking_v1;
+pub(crate) mod r#delegate_staking_v2;
 pub(crate) mod r#delegate_standard_v1;
 pub(crate) mod r#delegate_transfer_v1;
+pub(crate) mod r#delegate_transfer_v2;
 pub(crate) mod r#delegate_utility_v1;
+pub(crate) mod r#delegate_utility_v2;
 pub(crate) mod r#deprecated_mint_new_edition_from_master_edition_via_printing_token;
 pub(crate) mod r#freeze_delegated_account;
 pub(crate) mod r#lock;
@@ -48,6 +63,8 @@ pub(crate) mod r#mint_v1;
 pub(crate) mod r#print;
 pub(crate) mod r#print_v1;
 pub(crate) mod r#print_v2;
+pub(crate) mod r#print_v3;
+pub(crate) mod r#propose_update_authority;
 pub(crate) mod r#puff_metadata;
 pub(crate) mod r#remove_creator_verification;
 pub(crate) mod r#resize;
@@ -58,6 +75,7 @@ pub(crate) mod r#revoke_collection_item_v1;
 pub(crate) mod r#revoke_collection_v1;
 pub(crate) mod r#revoke_data_item_v1;
 pub(crate) mod r#revoke_data_v1;
+pub(crate) mod r#revoke_expired_delegate;
 pub(crate) mod r#revoke_locked_transfer_v1;
 pub(crate) mod r#revoke_migration_v1;
 pub(crate) mod r#revoke_print_delegate_v1;
@@ -94,6 +112,8 @@ pub(crate) mod r#update_as_data_item_delegate_v2;
 pub(crate) mod r#update_as_programmable_config_delegate_v2;
 pub(crate) mod r#update_as_programmable_config_item_delegate_v2;
 pub(crate) mod r#update_as_update_authority_v2;
+pub(crate) mod r#update_attributes;
+pub(crate) mod r#update_authority_batch;
 pub(crate) mod r#update_metadata_account_v2;
 pub(crate) mod r#update_primary_sale_happened_via_token;
 pub(crate) mod r#update_v1;
@@ -102,10 +122,12 @@ pub(crate) mod r#use_v1;
 pub(crate) mod r#utilize;
 pub(crate) mod r#verify;
 pub(crate) mod r#verify_collection;
+pub(crate) mod r#verify_collection_batch;
 pub(crate) mod r#verify_collection_v1;
 pub(crate) mod r#verify_creator_v1;
 pub(crate) mod r#verify_sized_collection_item;
 
+pub use self::r#accept_update_authority::*;
 pub use self::r#approve_collection_authority::*;
 pub use self::r#approve_use_authority::*;
 pub use self::r#bubblegum_set_collection_size::*;
@@ -113,8 +135,11 @@ pub use self::r#burn::*;
 pub use self::r#burn_edition_nft::*;
 pub use self::r#burn_nft::*;
 pub use self::r#burn_v1::*;
+pub use self::r#burn_v2::*;
+pub use self::r#cancel_update_authority::*;
 pub use self::r#close_accounts::*;
 pub use self::r#close_escrow_account::*;
+pub use self::r#close_orphaned_record::*;
 pub use self::r#collect::*;
 pub use self::r#convert_master_edition_v1_to_v2::*;
 pub use self::r#create::*;
@@ -124,19 +149,30 @@ pub use self::r#create_metadata_account_v3::*;
 pub use self::r#create_v1::*;
 pub use self::r#delegate::*;
 pub use self::r#delegate_authority_item_v1::*;
+pub use self::r#delegate_authority_item_v2::*;
 pub use self::r#delegate_collection_item_v1::*;
+pub use self::r#delegate_collection_item_v2::*;
 pub use self::r#delegate_collection_v1::*;
+pub use self::r#delegate_collection_v2::*;
 pub use self::r#delegate_data_item_v1::*;
+pub use self::r#delegate_data_item_v2::*;
 pub use self::r#delegate_data_v1::*;
+pub use self::r#delegate_data_v2::*;
 pub use self::r#delegate_locked_transfer_v1::*;
 pub use self::r#delegate_print_delegate_v1::*;
 pub use self::r#delegate_programmable_config_item_v1::*;
+pub use self::r#delegate_programmable_config_item_v2::*;
 pub use self::r#delegate_programmable_config_v1::*;
+pub use self::r#delegate_programmable_config_v2::*;
 pub use self::r#delegate_sale_v1::*;
+pub use self::r#delegate_sale_v2::*;
 pub use self::r#delegate_staking_v1::*;
+pub use self::r#delegate_staking_v2::*;
 pub use self::r#delegate_standard_v1::*;
 pub use self::r#delegate_transfer_v1::*;
+pub use self::r#delegate_transfer_v2::*;
 pub use self::r#delegate_utility_v1::*;
+pub use self::r#delegate_utility_v2::*;
 pub use self::r#deprecated_mint_new_edition_from_master_edition_via_printing_token::*;
 pub use self::r#freeze_delegated_account::*;
 pub use self::r#lock::*;
@@ -149,6 +185,8 @@ pub use self::r#mint_v1::*;
 pub use self::r#print::*;
 pub use self::r#print_v1::*;
 pub use self::r#print_v2::*;
+pub use self::r#print_v3::*;
+pub use self::r#propose_update_authority::*;
 pub use self::r#puff_metadata::*;
 pub use self::r#remove_creator_verification::*;
 pub use self::r#resize::*;
@@ -159,6 +197,7 @@ pub use self::r#revoke_collection_item_v1::*;
 pub use self::r#revoke_collection_v1::*;
 pub use self::r#revoke_data_item_v1::*;
 pub use self::r#revoke_data_v1::*;
+pub use self::r#revoke_expired_delegate::*;
 pub use self::r#revoke_locked_transfer_v1::*;
 pub use self::r#revoke_migration_v1::*;
 pub use self::r#revoke_print_delegate_v1::*;
@@ -195,6 +234,8 @@ pub use self::r#update_as_data_item_delegate_v2::*;
 pub use self::r#update_as_programmable_config_delegate_v2::*;
 pub use self::r#update_as_programmable_config_item_delegate_v2::*;
 pub use self::r#update_as_update_authority_v2::*;
+pub use self::r#update_attributes::*;
+pub use self::r#update_authority_batch::*;
 pub use self::r#update_metadata_account_v2::*;
 pub use self::r#update_primary_sale_happened_via_token::*;
 pub use self::r#update_v1::*;
@@ -203,6 +244,7 @@ pub use self::r#use_v1::*;
 pub use self::r#utilize::*;
 pub use self::r#verify::*;
 pub use self::r#verify_collection::*;
+pub use self::r#verify_collection_batch::*;
 pub use self::r#verify_collection_v1::*;
 pub use self::r#verify_creator_v1::*;
 pub use self::r#verify_sized_collection_item::*;
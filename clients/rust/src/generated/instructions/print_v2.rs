@@ -52,6 +52,14 @@ pub struct PrintV2 {
     pub holder_delegate_record: Option<solana_program::pubkey::Pubkey>,
     /// The authority printing the edition for a delegated print
     pub delegate: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    /// Mint of the master edition
+    pub master_edition_mint: Option<solana_program::pubkey::Pubkey>,
+    /// Token record account of the master edition token
+    pub master_token_record: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl PrintV2 {
@@ -67,7 +75,7 @@ impl PrintV2 {
         args: PrintV2InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(24 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.edition_metadata,
             false,
@@ -167,6 +175,50 @@ impl PrintV2 {
                 false,
             ));
         }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(master_edition_mint) = self.master_edition_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                master_edition_mint,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(master_token_record) = self.master_token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                master_token_record,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&(PrintV2InstructionData::new())).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -228,6 +280,10 @@ pub struct PrintV2InstructionArgs {
 ///   17. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   18. `[optional]` holder_delegate_record
 ///   19. `[signer, optional]` delegate
+///   20. `[optional]` authorization_rules_program
+///   21. `[optional]` authorization_rules
+///   22. `[optional]` master_edition_mint
+///   23. `[optional]` master_token_record
 #[derive(Default)]
 pub struct PrintV2Builder {
     edition_metadata: Option<solana_program::pubkey::Pubkey>,
@@ -250,6 +306,10 @@ pub struct PrintV2Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     holder_delegate_record: Option<solana_program::pubkey::Pubkey>,
     delegate: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    master_edition_mint: Option<solana_program::pubkey::Pubkey>,
+    master_token_record: Option<solana_program::pubkey::Pubkey>,
     edition_number: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -432,6 +492,46 @@ impl PrintV2Builder {
         self.delegate = delegate;
         self
     }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional account]`
+    /// Mint of the master edition
+    #[inline(always)]
+    pub fn master_edition_mint(
+        &mut self,
+        master_edition_mint: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.master_edition_mint = master_edition_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account of the master edition token
+    #[inline(always)]
+    pub fn master_token_record(
+        &mut self,
+        master_token_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.master_token_record = master_token_record;
+        self
+    }
     #[inline(always)]
     pub fn edition_number(&mut self, edition_number: u64) -> &mut Self {
         self.edition_number = Some(edition_number);
@@ -498,6 +598,10 @@ impl PrintV2Builder {
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
             holder_delegate_record: self.holder_delegate_record,
             delegate: self.delegate,
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
+            master_edition_mint: self.master_edition_mint,
+            master_token_record: self.master_token_record,
         };
         let args = PrintV2InstructionArgs {
             edition_number: self
@@ -552,6 +656,14 @@ pub struct PrintV2CpiAccounts<'a, 'b> {
     pub holder_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The authority printing the edition for a delegated print
     pub delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Mint of the master edition
+    pub master_edition_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account of the master edition token
+    pub master_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `print_v2` CPI instruction.
@@ -598,6 +710,14 @@ pub struct PrintV2Cpi<'a, 'b> {
     pub holder_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The authority printing the edition for a delegated print
     pub delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Mint of the master edition
+    pub master_edition_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account of the master edition token
+    pub master_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: PrintV2InstructionArgs,
 }
@@ -630,6 +750,10 @@ impl<'a, 'b> PrintV2Cpi<'a, 'b> {
             system_program: accounts.system_program,
             holder_delegate_record: accounts.holder_delegate_record,
             delegate: accounts.delegate,
+            authorization_rules_program: accounts.authorization_rules_program,
+            authorization_rules: accounts.authorization_rules,
+            master_edition_mint: accounts.master_edition_mint,
+            master_token_record: accounts.master_token_record,
             __args: args,
         }
     }
@@ -666,7 +790,7 @@ impl<'a, 'b> PrintV2Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(24 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.edition_metadata.key,
             false,
@@ -768,6 +892,50 @@ impl<'a, 'b> PrintV2Cpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(master_edition_mint) = self.master_edition_mint {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *master_edition_mint.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(master_token_record) = self.master_token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *master_token_record.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -784,7 +952,7 @@ impl<'a, 'b> PrintV2Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(20 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(24 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.edition_metadata.clone());
         account_infos.push(self.edition.clone());
@@ -812,6 +980,18 @@ impl<'a, 'b> PrintV2Cpi<'a, 'b> {
         if let Some(delegate) = self.delegate {
             account_infos.push(delegate.clone());
         }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            account_infos.push(authorization_rules_program.clone());
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            account_infos.push(authorization_rules.clone());
+        }
+        if let Some(master_edition_mint) = self.master_edition_mint {
+            account_infos.push(master_edition_mint.clone());
+        }
+        if let Some(master_token_record) = self.master_token_record {
+            account_infos.push(master_token_record.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -848,6 +1028,10 @@ impl<'a, 'b> PrintV2Cpi<'a, 'b> {
 ///   17. `[]` system_program
 ///   18. `[optional]` holder_delegate_record
 ///   19. `[signer, optional]` delegate
+///   20. `[optional]` authorization_rules_program
+///   21. `[optional]` authorization_rules
+///   22. `[optional]` master_edition_mint
+///   23. `[optional]` master_token_record
 pub struct PrintV2CpiBuilder<'a, 'b> {
     instruction: Box<PrintV2CpiBuilderInstruction<'a, 'b>>,
 }
@@ -876,6 +1060,10 @@ impl<'a, 'b> PrintV2CpiBuilder<'a, 'b> {
             system_program: None,
             holder_delegate_record: None,
             delegate: None,
+            authorization_rules_program: None,
+            authorization_rules: None,
+            master_edition_mint: None,
+            master_token_record: None,
             edition_number: None,
             __remaining_accounts: Vec::new(),
         });
@@ -1063,6 +1251,46 @@ impl<'a, 'b> PrintV2CpiBuilder<'a, 'b> {
         self.instruction.delegate = delegate;
         self
     }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional account]`
+    /// Mint of the master edition
+    #[inline(always)]
+    pub fn master_edition_mint(
+        &mut self,
+        master_edition_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.master_edition_mint = master_edition_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account of the master edition token
+    #[inline(always)]
+    pub fn master_token_record(
+        &mut self,
+        master_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.master_token_record = master_token_record;
+        self
+    }
     #[inline(always)]
     pub fn edition_number(&mut self, edition_number: u64) -> &mut Self {
         self.instruction.edition_number = Some(edition_number);
@@ -1203,6 +1431,14 @@ impl<'a, 'b> PrintV2CpiBuilder<'a, 'b> {
             holder_delegate_record: self.instruction.holder_delegate_record,
 
             delegate: self.instruction.delegate,
+
+            authorization_rules_program: self.instruction.authorization_rules_program,
+
+            authorization_rules: self.instruction.authorization_rules,
+
+            master_edition_mint: self.instruction.master_edition_mint,
+
+            master_token_record: self.instruction.master_token_record,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -1234,6 +1470,10 @@ struct PrintV2CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     holder_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     delegate: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    master_edition_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    master_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     edition_number: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
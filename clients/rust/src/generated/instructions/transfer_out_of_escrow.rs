@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AuthorizationData;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -38,6 +39,18 @@ pub struct TransferOutOfEscrow {
     pub sysvar_instructions: solana_program::pubkey::Pubkey,
     /// Authority/creator of the escrow account
     pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// Metadata account of the attribute (pNFT)
+    pub attribute_metadata: Option<solana_program::pubkey::Pubkey>,
+    /// Master edition account of the attribute (pNFT)
+    pub attribute_edition: Option<solana_program::pubkey::Pubkey>,
+    /// Token record account of the source token account (pNFT)
+    pub attribute_src_token_record: Option<solana_program::pubkey::Pubkey>,
+    /// Token record account of the destination token account (pNFT)
+    pub attribute_dst_token_record: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl TransferOutOfEscrow {
@@ -53,7 +66,7 @@ impl TransferOutOfEscrow {
         args: TransferOutOfEscrowInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.escrow,
             false,
@@ -106,6 +119,42 @@ impl TransferOutOfEscrow {
                 authority, true,
             ));
         }
+        if let Some(attribute_metadata) = self.attribute_metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                attribute_metadata,
+                false,
+            ));
+        }
+        if let Some(attribute_edition) = self.attribute_edition {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                attribute_edition,
+                false,
+            ));
+        }
+        if let Some(attribute_src_token_record) = self.attribute_src_token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                attribute_src_token_record,
+                false,
+            ));
+        }
+        if let Some(attribute_dst_token_record) = self.attribute_dst_token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                attribute_dst_token_record,
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&(TransferOutOfEscrowInstructionData::new())).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -137,6 +186,7 @@ impl TransferOutOfEscrowInstructionData {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransferOutOfEscrowInstructionArgs {
     pub amount: u64,
+    pub authorization_data: Option<AuthorizationData>,
 }
 
 /// Instruction builder for `TransferOutOfEscrow`.
@@ -156,6 +206,12 @@ pub struct TransferOutOfEscrowInstructionArgs {
 ///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   11. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
 ///   12. `[signer, optional]` authority
+///   13. `[optional]` attribute_metadata
+///   14. `[optional]` attribute_edition
+///   15. `[writable, optional]` attribute_src_token_record
+///   16. `[writable, optional]` attribute_dst_token_record
+///   17. `[optional]` authorization_rules_program
+///   18. `[optional]` authorization_rules
 #[derive(Default)]
 pub struct TransferOutOfEscrowBuilder {
     escrow: Option<solana_program::pubkey::Pubkey>,
@@ -171,7 +227,14 @@ pub struct TransferOutOfEscrowBuilder {
     token_program: Option<solana_program::pubkey::Pubkey>,
     sysvar_instructions: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
+    attribute_metadata: Option<solana_program::pubkey::Pubkey>,
+    attribute_edition: Option<solana_program::pubkey::Pubkey>,
+    attribute_src_token_record: Option<solana_program::pubkey::Pubkey>,
+    attribute_dst_token_record: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
+    authorization_data: Option<AuthorizationData>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -265,12 +328,78 @@ impl TransferOutOfEscrowBuilder {
         self.authority = authority;
         self
     }
+    /// `[optional account]`
+    /// Metadata account of the attribute (pNFT)
+    #[inline(always)]
+    pub fn attribute_metadata(
+        &mut self,
+        attribute_metadata: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.attribute_metadata = attribute_metadata;
+        self
+    }
+    /// `[optional account]`
+    /// Master edition account of the attribute (pNFT)
+    #[inline(always)]
+    pub fn attribute_edition(
+        &mut self,
+        attribute_edition: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.attribute_edition = attribute_edition;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account of the source token account (pNFT)
+    #[inline(always)]
+    pub fn attribute_src_token_record(
+        &mut self,
+        attribute_src_token_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.attribute_src_token_record = attribute_src_token_record;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account of the destination token account (pNFT)
+    #[inline(always)]
+    pub fn attribute_dst_token_record(
+        &mut self,
+        attribute_dst_token_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.attribute_dst_token_record = attribute_dst_token_record;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
     /// `[optional argument, defaults to '1']`
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.authorization_data = Some(authorization_data);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -313,9 +442,16 @@ impl TransferOutOfEscrowBuilder {
                 "Sysvar1nstructions1111111111111111111111111"
             )),
             authority: self.authority,
+            attribute_metadata: self.attribute_metadata,
+            attribute_edition: self.attribute_edition,
+            attribute_src_token_record: self.attribute_src_token_record,
+            attribute_dst_token_record: self.attribute_dst_token_record,
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
         };
         let args = TransferOutOfEscrowInstructionArgs {
             amount: self.amount.clone().unwrap_or(1),
+            authorization_data: self.authorization_data.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -350,6 +486,18 @@ pub struct TransferOutOfEscrowCpiAccounts<'a, 'b> {
     pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
     /// Authority/creator of the escrow account
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Metadata account of the attribute (pNFT)
+    pub attribute_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Master edition account of the attribute (pNFT)
+    pub attribute_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account of the source token account (pNFT)
+    pub attribute_src_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account of the destination token account (pNFT)
+    pub attribute_dst_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `transfer_out_of_escrow` CPI instruction.
@@ -382,6 +530,18 @@ pub struct TransferOutOfEscrowCpi<'a, 'b> {
     pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
     /// Authority/creator of the escrow account
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Metadata account of the attribute (pNFT)
+    pub attribute_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Master edition account of the attribute (pNFT)
+    pub attribute_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account of the source token account (pNFT)
+    pub attribute_src_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account of the destination token account (pNFT)
+    pub attribute_dst_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: TransferOutOfEscrowInstructionArgs,
 }
@@ -407,6 +567,12 @@ impl<'a, 'b> TransferOutOfEscrowCpi<'a, 'b> {
             token_program: accounts.token_program,
             sysvar_instructions: accounts.sysvar_instructions,
             authority: accounts.authority,
+            attribute_metadata: accounts.attribute_metadata,
+            attribute_edition: accounts.attribute_edition,
+            attribute_src_token_record: accounts.attribute_src_token_record,
+            attribute_dst_token_record: accounts.attribute_dst_token_record,
+            authorization_rules_program: accounts.authorization_rules_program,
+            authorization_rules: accounts.authorization_rules,
             __args: args,
         }
     }
@@ -443,7 +609,7 @@ impl<'a, 'b> TransferOutOfEscrowCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.escrow.key,
             false,
@@ -498,6 +664,42 @@ impl<'a, 'b> TransferOutOfEscrowCpi<'a, 'b> {
                 true,
             ));
         }
+        if let Some(attribute_metadata) = self.attribute_metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *attribute_metadata.key,
+                false,
+            ));
+        }
+        if let Some(attribute_edition) = self.attribute_edition {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *attribute_edition.key,
+                false,
+            ));
+        }
+        if let Some(attribute_src_token_record) = self.attribute_src_token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *attribute_src_token_record.key,
+                false,
+            ));
+        }
+        if let Some(attribute_dst_token_record) = self.attribute_dst_token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *attribute_dst_token_record.key,
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules_program.key,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -514,7 +716,7 @@ impl<'a, 'b> TransferOutOfEscrowCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(19 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.escrow.clone());
         account_infos.push(self.metadata.clone());
@@ -531,6 +733,24 @@ impl<'a, 'b> TransferOutOfEscrowCpi<'a, 'b> {
         if let Some(authority) = self.authority {
             account_infos.push(authority.clone());
         }
+        if let Some(attribute_metadata) = self.attribute_metadata {
+            account_infos.push(attribute_metadata.clone());
+        }
+        if let Some(attribute_edition) = self.attribute_edition {
+            account_infos.push(attribute_edition.clone());
+        }
+        if let Some(attribute_src_token_record) = self.attribute_src_token_record {
+            account_infos.push(attribute_src_token_record.clone());
+        }
+        if let Some(attribute_dst_token_record) = self.attribute_dst_token_record {
+            account_infos.push(attribute_dst_token_record.clone());
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            account_infos.push(authorization_rules_program.clone());
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            account_infos.push(authorization_rules.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -560,6 +780,12 @@ impl<'a, 'b> TransferOutOfEscrowCpi<'a, 'b> {
 ///   10. `[]` token_program
 ///   11. `[]` sysvar_instructions
 ///   12. `[signer, optional]` authority
+///   13. `[optional]` attribute_metadata
+///   14. `[optional]` attribute_edition
+///   15. `[writable, optional]` attribute_src_token_record
+///   16. `[writable, optional]` attribute_dst_token_record
+///   17. `[optional]` authorization_rules_program
+///   18. `[optional]` authorization_rules
 pub struct TransferOutOfEscrowCpiBuilder<'a, 'b> {
     instruction: Box<TransferOutOfEscrowCpiBuilderInstruction<'a, 'b>>,
 }
@@ -581,7 +807,14 @@ impl<'a, 'b> TransferOutOfEscrowCpiBuilder<'a, 'b> {
             token_program: None,
             sysvar_instructions: None,
             authority: None,
+            attribute_metadata: None,
+            attribute_edition: None,
+            attribute_src_token_record: None,
+            attribute_dst_token_record: None,
+            authorization_rules_program: None,
+            authorization_rules: None,
             amount: None,
+            authorization_data: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -701,12 +934,78 @@ impl<'a, 'b> TransferOutOfEscrowCpiBuilder<'a, 'b> {
         self.instruction.authority = authority;
         self
     }
+    /// `[optional account]`
+    /// Metadata account of the attribute (pNFT)
+    #[inline(always)]
+    pub fn attribute_metadata(
+        &mut self,
+        attribute_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.attribute_metadata = attribute_metadata;
+        self
+    }
+    /// `[optional account]`
+    /// Master edition account of the attribute (pNFT)
+    #[inline(always)]
+    pub fn attribute_edition(
+        &mut self,
+        attribute_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.attribute_edition = attribute_edition;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account of the source token account (pNFT)
+    #[inline(always)]
+    pub fn attribute_src_token_record(
+        &mut self,
+        attribute_src_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.attribute_src_token_record = attribute_src_token_record;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account of the destination token account (pNFT)
+    #[inline(always)]
+    pub fn attribute_dst_token_record(
+        &mut self,
+        attribute_dst_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.attribute_dst_token_record = attribute_dst_token_record;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules = authorization_rules;
+        self
+    }
     /// `[optional argument, defaults to '1']`
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.instruction.authorization_data = Some(authorization_data);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -750,6 +1049,7 @@ impl<'a, 'b> TransferOutOfEscrowCpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = TransferOutOfEscrowInstructionArgs {
             amount: self.instruction.amount.clone().unwrap_or(1),
+            authorization_data: self.instruction.authorization_data.clone(),
         };
         let instruction = TransferOutOfEscrowCpi {
             __program: self.instruction.__program,
@@ -806,6 +1106,18 @@ impl<'a, 'b> TransferOutOfEscrowCpiBuilder<'a, 'b> {
                 .expect("sysvar_instructions is not set"),
 
             authority: self.instruction.authority,
+
+            attribute_metadata: self.instruction.attribute_metadata,
+
+            attribute_edition: self.instruction.attribute_edition,
+
+            attribute_src_token_record: self.instruction.attribute_src_token_record,
+
+            attribute_dst_token_record: self.instruction.attribute_dst_token_record,
+
+            authorization_rules_program: self.instruction.authorization_rules_program,
+
+            authorization_rules: self.instruction.authorization_rules,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -830,7 +1142,14 @@ struct TransferOutOfEscrowCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     sysvar_instructions: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attribute_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attribute_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attribute_src_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attribute_dst_token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
+    authorization_data: Option<AuthorizationData>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -13,7 +13,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 
 /// Accounts.
 pub struct Unlock {
-    /// Delegate or freeze authority
+    /// Delegate, freeze authority or token owner (expired lock)
     pub authority: solana_program::pubkey::Pubkey,
     /// Token owner account
     pub token_owner: Option<solana_program::pubkey::Pubkey>,
@@ -218,7 +218,7 @@ impl UnlockBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    /// Delegate or freeze authority
+    /// Delegate, freeze authority or token owner (expired lock)
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -376,7 +376,7 @@ impl UnlockBuilder {
 
 /// `unlock` CPI accounts.
 pub struct UnlockCpiAccounts<'a, 'b> {
-    /// Delegate or freeze authority
+    /// Delegate, freeze authority or token owner (expired lock)
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token owner account
     pub token_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -408,7 +408,7 @@ pub struct UnlockCpiAccounts<'a, 'b> {
 pub struct UnlockCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Delegate or freeze authority
+    /// Delegate, freeze authority or token owner (expired lock)
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token owner account
     pub token_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -688,7 +688,7 @@ impl<'a, 'b> UnlockCpiBuilder<'a, 'b> {
         });
         Self { instruction }
     }
-    /// Delegate or freeze authority
+    /// Delegate, freeze authority or token owner (expired lock)
     #[inline(always)]
     pub fn authority(
         &mut self,
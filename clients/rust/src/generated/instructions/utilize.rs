@@ -34,6 +34,12 @@ pub struct Utilize {
     pub use_authority_record: Option<solana_program::pubkey::Pubkey>,
     /// Program As Signer (Burner)
     pub burner: Option<solana_program::pubkey::Pubkey>,
+    /// Token record account (programmable assets)
+    pub token_record: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl Utilize {
@@ -49,7 +55,7 @@ impl Utilize {
         args: UtilizeInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.metadata,
             false,
@@ -94,6 +100,24 @@ impl Utilize {
                 burner, false,
             ));
         }
+        if let Some(token_record) = self.token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_record,
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&(UtilizeInstructionData::new())).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -142,6 +166,9 @@ pub struct UtilizeInstructionArgs {
 ///   8. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
 ///   9. `[writable, optional]` use_authority_record
 ///   10. `[optional]` burner
+///   11. `[optional]` token_record
+///   12. `[optional]` authorization_rules_program
+///   13. `[optional]` authorization_rules
 #[derive(Default)]
 pub struct UtilizeBuilder {
     metadata: Option<solana_program::pubkey::Pubkey>,
@@ -155,6 +182,9 @@ pub struct UtilizeBuilder {
     rent: Option<solana_program::pubkey::Pubkey>,
     use_authority_record: Option<solana_program::pubkey::Pubkey>,
     burner: Option<solana_program::pubkey::Pubkey>,
+    token_record: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    authorization_rules: Option<solana_program::pubkey::Pubkey>,
     number_of_uses: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -238,6 +268,36 @@ impl UtilizeBuilder {
         self.burner = burner;
         self
     }
+    /// `[optional account]`
+    /// Token record account (programmable assets)
+    #[inline(always)]
+    pub fn token_record(
+        &mut self,
+        token_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_record = token_record;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
     #[inline(always)]
     pub fn number_of_uses(&mut self, number_of_uses: u64) -> &mut Self {
         self.number_of_uses = Some(number_of_uses);
@@ -283,6 +343,9 @@ impl UtilizeBuilder {
             )),
             use_authority_record: self.use_authority_record,
             burner: self.burner,
+            token_record: self.token_record,
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
         };
         let args = UtilizeInstructionArgs {
             number_of_uses: self
@@ -319,6 +382,12 @@ pub struct UtilizeCpiAccounts<'a, 'b> {
     pub use_authority_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Program As Signer (Burner)
     pub burner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account (programmable assets)
+    pub token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `utilize` CPI instruction.
@@ -347,6 +416,12 @@ pub struct UtilizeCpi<'a, 'b> {
     pub use_authority_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Program As Signer (Burner)
     pub burner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token record account (programmable assets)
+    pub token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UtilizeInstructionArgs,
 }
@@ -370,6 +445,9 @@ impl<'a, 'b> UtilizeCpi<'a, 'b> {
             rent: accounts.rent,
             use_authority_record: accounts.use_authority_record,
             burner: accounts.burner,
+            token_record: accounts.token_record,
+            authorization_rules_program: accounts.authorization_rules_program,
+            authorization_rules: accounts.authorization_rules,
             __args: args,
         }
     }
@@ -406,7 +484,7 @@ impl<'a, 'b> UtilizeCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.metadata.key,
             false,
@@ -455,6 +533,24 @@ impl<'a, 'b> UtilizeCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(token_record) = self.token_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_record.key,
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules_program.key,
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authorization_rules.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -471,7 +567,7 @@ impl<'a, 'b> UtilizeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.metadata.clone());
         account_infos.push(self.token_account.clone());
@@ -488,6 +584,15 @@ impl<'a, 'b> UtilizeCpi<'a, 'b> {
         if let Some(burner) = self.burner {
             account_infos.push(burner.clone());
         }
+        if let Some(token_record) = self.token_record {
+            account_infos.push(token_record.clone());
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            account_infos.push(authorization_rules_program.clone());
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            account_infos.push(authorization_rules.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -515,6 +620,9 @@ impl<'a, 'b> UtilizeCpi<'a, 'b> {
 ///   8. `[]` rent
 ///   9. `[writable, optional]` use_authority_record
 ///   10. `[optional]` burner
+///   11. `[optional]` token_record
+///   12. `[optional]` authorization_rules_program
+///   13. `[optional]` authorization_rules
 pub struct UtilizeCpiBuilder<'a, 'b> {
     instruction: Box<UtilizeCpiBuilderInstruction<'a, 'b>>,
 }
@@ -534,6 +642,9 @@ impl<'a, 'b> UtilizeCpiBuilder<'a, 'b> {
             rent: None,
             use_authority_record: None,
             burner: None,
+            token_record: None,
+            authorization_rules_program: None,
+            authorization_rules: None,
             number_of_uses: None,
             __remaining_accounts: Vec::new(),
         });
@@ -631,6 +742,36 @@ impl<'a, 'b> UtilizeCpiBuilder<'a, 'b> {
         self.instruction.burner = burner;
         self
     }
+    /// `[optional account]`
+    /// Token record account (programmable assets)
+    #[inline(always)]
+    pub fn token_record(
+        &mut self,
+        token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_record = token_record;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(
+        &mut self,
+        authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authorization_rules = authorization_rules;
+        self
+    }
     #[inline(always)]
     pub fn number_of_uses(&mut self, number_of_uses: u64) -> &mut Self {
         self.instruction.number_of_uses = Some(number_of_uses);
@@ -723,6 +864,12 @@ impl<'a, 'b> UtilizeCpiBuilder<'a, 'b> {
             use_authority_record: self.instruction.use_authority_record,
 
             burner: self.instruction.burner,
+
+            token_record: self.instruction.token_record,
+
+            authorization_rules_program: self.instruction.authorization_rules_program,
+
+            authorization_rules: self.instruction.authorization_rules,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -745,6 +892,9 @@ struct UtilizeCpiBuilderInstruction<'a, 'b> {
     rent: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     use_authority_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     burner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authorization_rules: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     number_of_uses: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
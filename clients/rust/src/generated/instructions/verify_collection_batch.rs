@@ -0,0 +1,644 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::VerifyCollectionBatchArgs;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct VerifyCollectionBatch {
+    /// Collection update authority or delegate
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Delegate record PDA
+    pub delegate_record: Option<solana_program::pubkey::Pubkey>,
+    /// Mint of the Collection
+    pub collection_mint: solana_program::pubkey::Pubkey,
+    /// Metadata Account of the Collection
+    pub collection_metadata: solana_program::pubkey::Pubkey,
+    /// Master Edition Account of the Collection Token
+    pub collection_master_edition: Option<solana_program::pubkey::Pubkey>,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: solana_program::pubkey::Pubkey,
+}
+
+impl VerifyCollectionBatch {
+    pub fn instruction(
+        &self,
+        args: VerifyCollectionBatchInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: VerifyCollectionBatchInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                delegate_record,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.collection_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.collection_metadata,
+            false,
+        ));
+        if let Some(collection_master_edition) = self.collection_master_edition {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                collection_master_edition,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(VerifyCollectionBatchInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct VerifyCollectionBatchInstructionData {
+    discriminator: u8,
+}
+
+impl VerifyCollectionBatchInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 58 }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifyCollectionBatchInstructionArgs {
+    pub verify_collection_batch_args: VerifyCollectionBatchArgs,
+}
+
+/// Instruction builder for `VerifyCollectionBatch`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` delegate_record
+///   2. `[]` collection_mint
+///   3. `[writable]` collection_metadata
+///   4. `[optional]` collection_master_edition
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+#[derive(Default)]
+pub struct VerifyCollectionBatchBuilder {
+    authority: Option<solana_program::pubkey::Pubkey>,
+    delegate_record: Option<solana_program::pubkey::Pubkey>,
+    collection_mint: Option<solana_program::pubkey::Pubkey>,
+    collection_metadata: Option<solana_program::pubkey::Pubkey>,
+    collection_master_edition: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    sysvar_instructions: Option<solana_program::pubkey::Pubkey>,
+    verify_collection_batch_args: Option<VerifyCollectionBatchArgs>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl VerifyCollectionBatchBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Collection update authority or delegate
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Delegate record PDA
+    #[inline(always)]
+    pub fn delegate_record(
+        &mut self,
+        delegate_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.delegate_record = delegate_record;
+        self
+    }
+    /// Mint of the Collection
+    #[inline(always)]
+    pub fn collection_mint(
+        &mut self,
+        collection_mint: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.collection_mint = Some(collection_mint);
+        self
+    }
+    /// Metadata Account of the Collection
+    #[inline(always)]
+    pub fn collection_metadata(
+        &mut self,
+        collection_metadata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.collection_metadata = Some(collection_metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Master Edition Account of the Collection Token
+    #[inline(always)]
+    pub fn collection_master_edition(
+        &mut self,
+        collection_master_edition: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.collection_master_edition = collection_master_edition;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(
+        &mut self,
+        sysvar_instructions: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    #[inline(always)]
+    pub fn verify_collection_batch_args(
+        &mut self,
+        verify_collection_batch_args: VerifyCollectionBatchArgs,
+    ) -> &mut Self {
+        self.verify_collection_batch_args = Some(verify_collection_batch_args);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = VerifyCollectionBatch {
+            authority: self.authority.expect("authority is not set"),
+            delegate_record: self.delegate_record,
+            collection_mint: self.collection_mint.expect("collection_mint is not set"),
+            collection_metadata: self
+                .collection_metadata
+                .expect("collection_metadata is not set"),
+            collection_master_edition: self.collection_master_edition,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(solana_program::pubkey!(
+                "Sysvar1nstructions1111111111111111111111111"
+            )),
+        };
+        let args = VerifyCollectionBatchInstructionArgs {
+            verify_collection_batch_args: self
+                .verify_collection_batch_args
+                .clone()
+                .expect("verify_collection_batch_args is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `verify_collection_batch` CPI accounts.
+pub struct VerifyCollectionBatchCpiAccounts<'a, 'b> {
+    /// Collection update authority or delegate
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Delegate record PDA
+    pub delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Mint of the Collection
+    pub collection_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Metadata Account of the Collection
+    pub collection_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Master Edition Account of the Collection Token
+    pub collection_master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Instructions sysvar account
+    pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `verify_collection_batch` CPI instruction.
+pub struct VerifyCollectionBatchCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Collection update authority or delegate
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Delegate record PDA
+    pub delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Mint of the Collection
+    pub collection_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Metadata Account of the Collection
+    pub collection_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Master Edition Account of the Collection Token
+    pub collection_master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Instructions sysvar account
+    pub sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: VerifyCollectionBatchInstructionArgs,
+}
+
+impl<'a, 'b> VerifyCollectionBatchCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: VerifyCollectionBatchCpiAccounts<'a, 'b>,
+        args: VerifyCollectionBatchInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            delegate_record: accounts.delegate_record,
+            collection_mint: accounts.collection_mint,
+            collection_metadata: accounts.collection_metadata,
+            collection_master_edition: accounts.collection_master_edition,
+            system_program: accounts.system_program,
+            sysvar_instructions: accounts.sysvar_instructions,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *delegate_record.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.collection_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.collection_metadata.key,
+            false,
+        ));
+        if let Some(collection_master_edition) = self.collection_master_edition {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *collection_master_edition.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_TOKEN_METADATA_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.sysvar_instructions.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(VerifyCollectionBatchInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_TOKEN_METADATA_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        if let Some(delegate_record) = self.delegate_record {
+            account_infos.push(delegate_record.clone());
+        }
+        account_infos.push(self.collection_mint.clone());
+        account_infos.push(self.collection_metadata.clone());
+        if let Some(collection_master_edition) = self.collection_master_edition {
+            account_infos.push(collection_master_edition.clone());
+        }
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.sysvar_instructions.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `VerifyCollectionBatch` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` delegate_record
+///   2. `[]` collection_mint
+///   3. `[writable]` collection_metadata
+///   4. `[optional]` collection_master_edition
+///   5. `[]` system_program
+///   6. `[]` sysvar_instructions
+pub struct VerifyCollectionBatchCpiBuilder<'a, 'b> {
+    instruction: Box<VerifyCollectionBatchCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> VerifyCollectionBatchCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(VerifyCollectionBatchCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            delegate_record: None,
+            collection_mint: None,
+            collection_metadata: None,
+            collection_master_edition: None,
+            system_program: None,
+            sysvar_instructions: None,
+            verify_collection_batch_args: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Collection update authority or delegate
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Delegate record PDA
+    #[inline(always)]
+    pub fn delegate_record(
+        &mut self,
+        delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegate_record = delegate_record;
+        self
+    }
+    /// Mint of the Collection
+    #[inline(always)]
+    pub fn collection_mint(
+        &mut self,
+        collection_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.collection_mint = Some(collection_mint);
+        self
+    }
+    /// Metadata Account of the Collection
+    #[inline(always)]
+    pub fn collection_metadata(
+        &mut self,
+        collection_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.collection_metadata = Some(collection_metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Master Edition Account of the Collection Token
+    #[inline(always)]
+    pub fn collection_master_edition(
+        &mut self,
+        collection_master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.collection_master_edition = collection_master_edition;
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(
+        &mut self,
+        sysvar_instructions: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    #[inline(always)]
+    pub fn verify_collection_batch_args(
+        &mut self,
+        verify_collection_batch_args: VerifyCollectionBatchArgs,
+    ) -> &mut Self {
+        self.instruction.verify_collection_batch_args = Some(verify_collection_batch_args);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = VerifyCollectionBatchInstructionArgs {
+            verify_collection_batch_args: self
+                .instruction
+                .verify_collection_batch_args
+                .clone()
+                .expect("verify_collection_batch_args is not set"),
+        };
+        let instruction = VerifyCollectionBatchCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            delegate_record: self.instruction.delegate_record,
+
+            collection_mint: self
+                .instruction
+                .collection_mint
+                .expect("collection_mint is not set"),
+
+            collection_metadata: self
+                .instruction
+                .collection_metadata
+                .expect("collection_metadata is not set"),
+
+            collection_master_edition: self.instruction.collection_master_edition,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            sysvar_instructions: self
+                .instruction
+                .sysvar_instructions
+                .expect("sysvar_instructions is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct VerifyCollectionBatchCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection_master_edition: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    sysvar_instructions: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    verify_collection_batch_args: Option<VerifyCollectionBatchArgs>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::AuthorizationData;
+use crate::generated::types::DelegateExpiry;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -69,4 +70,52 @@ pub enum DelegateArgs {
     PrintDelegateV1 {
         authorization_data: Option<AuthorizationData>,
     },
+    CollectionV2 {
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    SaleV2 {
+        amount: u64,
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    TransferV2 {
+        amount: u64,
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    DataV2 {
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    UtilityV2 {
+        amount: u64,
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    StakingV2 {
+        amount: u64,
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    ProgrammableConfigV2 {
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    AuthorityItemV2 {
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    DataItemV2 {
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    CollectionItemV2 {
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
+    ProgrammableConfigItemV2 {
+        expiry: Option<DelegateExpiry>,
+        authorization_data: Option<AuthorizationData>,
+    },
 }
@@ -31,4 +31,6 @@ pub enum Key {
     MetadataDelegate,
     EditionMarkerV2,
     HolderDelegate,
+    PendingUpdateAuthority,
+    MetadataAttributes,
 }
@@ -19,4 +19,8 @@ pub enum LockArgs {
     V1 {
         authorization_data: Option<AuthorizationData>,
     },
+    V2 {
+        unlock_after: Option<i64>,
+        authorization_data: Option<AuthorizationData>,
+    },
 }
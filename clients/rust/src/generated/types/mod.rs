@@ -5,9 +5,13 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_update_authority_args;
+pub(crate) mod r#attribute;
 pub(crate) mod r#authority_type;
 pub(crate) mod r#authorization_data;
 pub(crate) mod r#burn_args;
+pub(crate) mod r#cancel_update_authority_args;
+pub(crate) mod r#close_orphaned_record_args;
 pub(crate) mod r#collection;
 pub(crate) mod r#collection_details;
 pub(crate) mod r#collection_details_toggle;
@@ -17,6 +21,7 @@ pub(crate) mod r#creator;
 pub(crate) mod r#data;
 pub(crate) mod r#data_v2;
 pub(crate) mod r#delegate_args;
+pub(crate) mod r#delegate_expiry;
 pub(crate) mod r#escrow_authority;
 pub(crate) mod r#holder_delegate_role;
 pub(crate) mod r#key;
@@ -32,9 +37,11 @@ pub(crate) mod r#print_args;
 pub(crate) mod r#print_supply;
 pub(crate) mod r#programmable_config;
 pub(crate) mod r#proof_info;
+pub(crate) mod r#propose_update_authority_args;
 pub(crate) mod r#reservation;
 pub(crate) mod r#reservation_v1;
 pub(crate) mod r#revoke_args;
+pub(crate) mod r#revoke_expired_delegate_args;
 pub(crate) mod r#rule_set_toggle;
 pub(crate) mod r#seeds_vec;
 pub(crate) mod r#set_collection_size_args;
@@ -44,15 +51,22 @@ pub(crate) mod r#token_state;
 pub(crate) mod r#transfer_args;
 pub(crate) mod r#unlock_args;
 pub(crate) mod r#update_args;
+pub(crate) mod r#update_attributes_args;
+pub(crate) mod r#update_authority_batch_args;
 pub(crate) mod r#use_args;
 pub(crate) mod r#use_method;
 pub(crate) mod r#uses;
 pub(crate) mod r#uses_toggle;
 pub(crate) mod r#verification_args;
+pub(crate) mod r#verify_collection_batch_args;
 
+pub use self::r#accept_update_authority_args::*;
+pub use self::r#attribute::*;
 pub use self::r#authority_type::*;
 pub use self::r#authorization_data::*;
 pub use self::r#burn_args::*;
+pub use self::r#cancel_update_authority_args::*;
+pub use self::r#close_orphaned_record_args::*;
 pub use self::r#collection::*;
 pub use self::r#collection_details::*;
 pub use self::r#collection_details_toggle::*;
@@ -62,6 +76,7 @@ pub use self::r#creator::*;
 pub use self::r#data::*;
 pub use self::r#data_v2::*;
 pub use self::r#delegate_args::*;
+pub use self::r#delegate_expiry::*;
 pub use self::r#escrow_authority::*;
 pub use self::r#holder_delegate_role::*;
 pub use self::r#key::*;
@@ -77,9 +92,11 @@ pub use self::r#print_args::*;
 pub use self::r#print_supply::*;
 pub use self::r#programmable_config::*;
 pub use self::r#proof_info::*;
+pub use self::r#propose_update_authority_args::*;
 pub use self::r#reservation::*;
 pub use self::r#reservation_v1::*;
 pub use self::r#revoke_args::*;
+pub use self::r#revoke_expired_delegate_args::*;
 pub use self::r#rule_set_toggle::*;
 pub use self::r#seeds_vec::*;
 pub use self::r#set_collection_size_args::*;
@@ -89,8 +106,11 @@ pub use self::r#token_state::*;
 pub use self::r#transfer_args::*;
 pub use self::r#unlock_args::*;
 pub use self::r#update_args::*;
+pub use self::r#update_attributes_args::*;
+pub use self::r#update_authority_batch_args::*;
 pub use self::r#use_args::*;
 pub use self::r#use_method::*;
 pub use self::r#uses::*;
 pub use self::r#uses_toggle::*;
 pub use self::r#verification_args::*;
+pub use self::r#verify_collection_batch_args::*;
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AuthorizationData;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -15,6 +16,14 @@ use borsh::{BorshDeserialize, BorshSerialize};
 #[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PrintArgs {
-    V1 { edition: u64 },
-    V2 { edition: u64 },
+    V1 {
+        edition: u64,
+    },
+    V2 {
+        edition: u64,
+    },
+    V3 {
+        edition: u64,
+        authorization_data: Option<AuthorizationData>,
+    },
 }
@@ -1,11 +1,31 @@
+pub mod authority;
+pub mod authorization;
+pub mod cost;
+pub mod decoder;
+pub mod digital_asset;
+pub mod filters;
 mod generated;
 pub mod hooked;
+pub mod operations;
 mod traits;
 pub mod utils;
+pub mod validation;
 
 pub use generated::programs::MPL_TOKEN_METADATA_ID as ID;
 pub use generated::*;
 
+/// SPL Token program ID.
+pub const SPL_TOKEN_PROGRAM_ID: solana_program::pubkey::Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// SPL Token 2022 program ID.
+pub const SPL_TOKEN_2022_PROGRAM_ID: solana_program::pubkey::Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// SPL Associated Token Account program ID.
+pub const SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID: solana_program::pubkey::Pubkey =
+    solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
 /// Maximum number of characters in a metadata name.
 pub const MAX_NAME_LENGTH: usize = 32;
 
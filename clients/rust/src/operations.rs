@@ -0,0 +1,703 @@
+//! High-level planners that assemble the instructions of common asset operations.
+//!
+//! The accounts required by `Create`, `Mint`, `Transfer`, `Burn`, `Delegate` and `Revoke`
+//! depend on the token standard of the asset. The planners in this module mirror the
+//! branching of the program processors so that callers only need to provide the
+//! accounts that cannot be derived.
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::{
+    accounts::{
+        EditionMarker, EditionMarkerV2, HolderDelegateRecord, MasterEdition, Metadata,
+        MetadataDelegateRecord, TokenRecord,
+    },
+    errors::MplTokenMetadataError,
+    instructions::{
+        BurnV1Builder, CreateV1, CreateV1InstructionArgs, DelegateBuilder, MintV1Builder,
+        RevokeBuilder, TransferV1Builder,
+    },
+    types::{
+        AuthorizationData, DelegateArgs, HolderDelegateRole, MetadataDelegateRole,
+        ProgrammableConfig, RevokeArgs, TokenDelegateRole, TokenStandard,
+    },
+    utils::find_associated_token_account,
+    EDITION_MARKER_BIT_SIZE, SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID,
+};
+
+/// Compute Budget program ID.
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// Token Auth Rules program ID.
+pub const MPL_TOKEN_AUTH_RULES_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
+
+/// Compute units requested for operations on non-programmable assets.
+pub const DEFAULT_COMPUTE_UNITS: u32 = 200_000;
+
+/// Compute units requested for operations on programmable assets, which
+/// perform additional thaw/freeze CPIs and rule set validation.
+pub const PROGRAMMABLE_COMPUTE_UNITS: u32 = 400_000;
+
+/// System program ID.
+const SYSTEM_PROGRAM_ID: Pubkey = solana_program::pubkey!("11111111111111111111111111111111");
+
+/// Instructions sysvar ID.
+const SYSVAR_INSTRUCTIONS_ID: Pubkey =
+    solana_program::pubkey!("Sysvar1nstructions1111111111111111111111111");
+
+/// Discriminator of the compute budget `SetComputeUnitLimit` instruction.
+const SET_COMPUTE_UNIT_LIMIT_DISCRIMINATOR: u8 = 2;
+
+/// Instructions required to perform an operation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationPlan {
+    /// Instructions in the order they must be executed.
+    pub instructions: Vec<Instruction>,
+    /// Estimated compute units required by the instructions.
+    pub compute_units: u32,
+}
+
+impl OperationPlan {
+    /// Returns the plan instructions preceded by a `SetComputeUnitLimit` instruction.
+    pub fn with_compute_budget(&self) -> Vec<Instruction> {
+        let mut instructions = Vec::with_capacity(self.instructions.len() + 1);
+        instructions.push(set_compute_unit_limit(self.compute_units));
+        instructions.extend(self.instructions.iter().cloned());
+        instructions
+    }
+}
+
+/// Creates a compute budget `SetComputeUnitLimit` instruction.
+pub fn set_compute_unit_limit(units: u32) -> Instruction {
+    let mut data = Vec::with_capacity(5);
+    data.push(SET_COMPUTE_UNIT_LIMIT_DISCRIMINATOR);
+    data.extend_from_slice(&units.to_le_bytes());
+
+    Instruction {
+        program_id: COMPUTE_BUDGET_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Parameters of a create operation.
+#[derive(Clone, Debug)]
+pub struct CreateParams {
+    /// Mint of the asset.
+    pub mint: Pubkey,
+    /// Indicates whether the mint account needs to be created, in which case it must sign.
+    pub create_mint: bool,
+    /// Mint authority.
+    pub authority: Pubkey,
+    /// Payer of the transaction.
+    pub payer: Pubkey,
+    /// Update authority of the asset.
+    pub update_authority: Pubkey,
+    /// Indicates whether the update authority signs the transaction.
+    pub update_authority_signer: bool,
+    /// SPL Token program (defaults to the legacy SPL Token program).
+    pub spl_token_program: Option<Pubkey>,
+    /// Arguments of the `CreateV1` instruction.
+    pub args: CreateV1InstructionArgs,
+    /// Optional owner and amount of tokens to mint after the asset is created.
+    pub mint_to: Option<(Pubkey, u64)>,
+}
+
+/// Parameters of a mint operation.
+#[derive(Clone, Debug)]
+pub struct MintParams {
+    /// Owner of the token account.
+    pub token_owner: Pubkey,
+    /// Token account (defaults to the associated token account of `token_owner`).
+    pub token: Option<Pubkey>,
+    /// Mint authority (or update authority for assets with an edition).
+    pub authority: Pubkey,
+    /// Payer of the transaction.
+    pub payer: Pubkey,
+    /// Metadata delegate record when the authority is a delegate.
+    pub delegate_record: Option<Pubkey>,
+    /// Number of tokens to mint.
+    pub amount: u64,
+    /// Authorization data for the rule set.
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+/// Parameters of a transfer operation.
+#[derive(Clone, Debug)]
+pub struct TransferParams {
+    /// Owner of the source token account.
+    pub token_owner: Pubkey,
+    /// Source token account (defaults to the associated token account of `token_owner`).
+    pub token: Option<Pubkey>,
+    /// Owner of the destination token account.
+    pub destination_owner: Pubkey,
+    /// Destination token account (defaults to the associated token account of
+    /// `destination_owner`, which is created by the program if needed).
+    pub destination_token: Option<Pubkey>,
+    /// Transfer authority (defaults to `token_owner`).
+    pub authority: Option<Pubkey>,
+    /// Payer of the transaction.
+    pub payer: Pubkey,
+    /// Number of tokens to transfer.
+    pub amount: u64,
+    /// Authorization data for the rule set.
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+/// Accounts of the master edition of a print edition.
+#[derive(Clone, Debug)]
+pub struct PrintEditionParent {
+    /// Mint of the master edition.
+    pub mint: Pubkey,
+    /// Token account holding the master edition.
+    pub token: Pubkey,
+    /// Edition number of the print edition.
+    pub edition_number: u64,
+}
+
+/// Parameters of a burn operation.
+#[derive(Clone, Debug)]
+pub struct BurnParams {
+    /// Asset owner or utility delegate.
+    pub authority: Pubkey,
+    /// Owner of the token account.
+    pub token_owner: Pubkey,
+    /// Token account (defaults to the associated token account of `token_owner`).
+    pub token: Option<Pubkey>,
+    /// Number of tokens to burn.
+    pub amount: u64,
+    /// Master edition accounts (print editions only).
+    pub parent: Option<PrintEditionParent>,
+}
+
+/// Parameters of a delegate operation.
+#[derive(Clone, Debug)]
+pub struct DelegateParams {
+    /// Account receiving the delegation.
+    pub delegate: Pubkey,
+    /// Update authority (metadata delegates) or token owner (token and holder delegates).
+    pub authority: Pubkey,
+    /// Payer of the transaction.
+    pub payer: Pubkey,
+    /// Token account (defaults to the associated token account of `authority`).
+    pub token: Option<Pubkey>,
+    /// Arguments of the delegate instruction.
+    pub args: DelegateArgs,
+}
+
+/// Parameters of a revoke operation.
+#[derive(Clone, Debug)]
+pub struct RevokeParams {
+    /// Account being revoked.
+    pub delegate: Pubkey,
+    /// Update authority, token owner or the delegate itself (self-revoke).
+    pub authority: Pubkey,
+    /// Payer of the transaction.
+    pub payer: Pubkey,
+    /// Owner of the token account (token and holder delegates).
+    pub token_owner: Option<Pubkey>,
+    /// Token account (defaults to the associated token account of `token_owner`).
+    pub token: Option<Pubkey>,
+    /// Arguments of the revoke instruction.
+    pub args: RevokeArgs,
+}
+
+/// Delegation scenario of a delegate or revoke instruction.
+enum DelegateKind {
+    Metadata(MetadataDelegateRole),
+    Holder(HolderDelegateRole),
+    Token(TokenDelegateRole),
+}
+
+/// Plans the instructions of operations over an existing asset.
+#[derive(Clone, Debug)]
+pub struct AssetOperations {
+    mint: Pubkey,
+    update_authority: Pubkey,
+    token_standard: TokenStandard,
+    rule_set: Option<Pubkey>,
+    collection: Option<Pubkey>,
+    spl_token_program: Pubkey,
+}
+
+impl AssetOperations {
+    /// Creates a planner for the asset described by `metadata`.
+    ///
+    /// Fails with `CouldNotDetermineTokenStandard` when the metadata does not
+    /// specify a token standard; use `with_token_standard` in this case.
+    pub fn new(metadata: &Metadata) -> Result<Self, MplTokenMetadataError> {
+        let token_standard = metadata
+            .token_standard
+            .ok_or(MplTokenMetadataError::CouldNotDetermineTokenStandard)?;
+
+        Ok(Self::with_token_standard(metadata, token_standard))
+    }
+
+    /// Creates a planner for the asset described by `metadata` using the specified
+    /// token standard.
+    pub fn with_token_standard(metadata: &Metadata, token_standard: TokenStandard) -> Self {
+        let rule_set = match &metadata.programmable_config {
+            Some(ProgrammableConfig::V1 { rule_set }) => *rule_set,
+            None => None,
+        };
+
+        let collection = metadata
+            .collection
+            .as_ref()
+            .filter(|collection| collection.verified)
+            .map(|collection| collection.key);
+
+        Self {
+            mint: metadata.mint,
+            update_authority: metadata.update_authority,
+            token_standard,
+            rule_set,
+            collection,
+            spl_token_program: SPL_TOKEN_PROGRAM_ID,
+        }
+    }
+
+    /// Sets the SPL Token program of the asset (defaults to the legacy SPL Token program).
+    pub fn spl_token_program(mut self, spl_token_program: Pubkey) -> Self {
+        self.spl_token_program = spl_token_program;
+        self
+    }
+
+    /// Plans the creation of a new asset, optionally minting tokens to an owner.
+    pub fn create(params: CreateParams) -> OperationPlan {
+        let spl_token_program = params.spl_token_program.unwrap_or(SPL_TOKEN_PROGRAM_ID);
+
+        let operations = Self {
+            mint: params.mint,
+            update_authority: params.update_authority,
+            token_standard: params.args.token_standard,
+            rule_set: params.args.rule_set,
+            collection: None,
+            spl_token_program,
+        };
+
+        let create_ix = CreateV1 {
+            metadata: Metadata::find_pda(&params.mint).0,
+            master_edition: operations.edition(),
+            mint: (params.mint, params.create_mint),
+            authority: params.authority,
+            payer: params.payer,
+            update_authority: (params.update_authority, params.update_authority_signer),
+            system_program: SYSTEM_PROGRAM_ID,
+            sysvar_instructions: SYSVAR_INSTRUCTIONS_ID,
+            spl_token_program: Some(spl_token_program),
+        }
+        .instruction(params.args);
+
+        let mut plan = OperationPlan {
+            instructions: vec![create_ix],
+            compute_units: operations.compute_units(),
+        };
+
+        if let Some((token_owner, amount)) = params.mint_to {
+            // fungibles are minted by the mint authority, while assets with an edition
+            // are minted by the update authority (the edition is the mint authority)
+            let authority = match operations.token_standard {
+                TokenStandard::Fungible | TokenStandard::FungibleAsset => params.authority,
+                _ => params.update_authority,
+            };
+
+            let mint_plan = operations.mint(MintParams {
+                token_owner,
+                token: None,
+                authority,
+                payer: params.payer,
+                delegate_record: None,
+                amount,
+                authorization_data: None,
+            });
+            plan.instructions.extend(mint_plan.instructions);
+            plan.compute_units += mint_plan.compute_units;
+        }
+
+        plan
+    }
+
+    /// Plans minting tokens of the asset.
+    ///
+    /// The associated token account is created by the program when it does not exist.
+    pub fn mint(&self, params: MintParams) -> OperationPlan {
+        let token = params.token.unwrap_or_else(|| {
+            find_associated_token_account(&params.token_owner, &self.mint, &self.spl_token_program)
+        });
+
+        let mut builder = MintV1Builder::new();
+        builder
+            .token(token)
+            .token_owner(Some(params.token_owner))
+            .metadata(Metadata::find_pda(&self.mint).0)
+            .master_edition(self.edition())
+            .token_record(self.token_record(&token))
+            .mint(self.mint)
+            .authority(params.authority)
+            .delegate_record(params.delegate_record)
+            .payer(params.payer)
+            .spl_token_program(self.spl_token_program)
+            .spl_ata_program(SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID)
+            .authorization_rules_program(self.authorization_rules_program())
+            .authorization_rules(self.authorization_rules())
+            .amount(params.amount);
+
+        if let Some(authorization_data) = params.authorization_data {
+            builder.authorization_data(authorization_data);
+        }
+
+        self.plan(builder.instruction())
+    }
+
+    /// Plans a transfer of the asset.
+    ///
+    /// The destination associated token account is created by the program when it
+    /// does not exist.
+    pub fn transfer(&self, params: TransferParams) -> OperationPlan {
+        let token = params.token.unwrap_or_else(|| {
+            find_associated_token_account(&params.token_owner, &self.mint, &self.spl_token_program)
+        });
+        let destination_token = params.destination_token.unwrap_or_else(|| {
+            find_associated_token_account(
+                &params.destination_owner,
+                &self.mint,
+                &self.spl_token_program,
+            )
+        });
+
+        let mut builder = TransferV1Builder::new();
+        builder
+            .token(token)
+            .token_owner(params.token_owner)
+            .destination_token(destination_token)
+            .destination_owner(params.destination_owner)
+            .mint(self.mint)
+            .metadata(Metadata::find_pda(&self.mint).0)
+            .edition(self.edition())
+            .token_record(self.token_record(&token))
+            .destination_token_record(self.token_record(&destination_token))
+            .authority(params.authority.unwrap_or(params.token_owner))
+            .payer(params.payer)
+            .spl_token_program(self.spl_token_program)
+            .spl_ata_program(SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID)
+            .authorization_rules_program(self.authorization_rules_program())
+            .authorization_rules(self.authorization_rules())
+            .amount(params.amount);
+
+        if let Some(authorization_data) = params.authorization_data {
+            builder.authorization_data(authorization_data);
+        }
+
+        self.plan(builder.instruction())
+    }
+
+    /// Plans burning the asset.
+    ///
+    /// Burning a print edition requires the accounts of its master edition; burning an
+    /// asset that is a verified member of a collection includes the collection metadata
+    /// so the collection size can be decremented.
+    pub fn burn(&self, params: BurnParams) -> Result<OperationPlan, MplTokenMetadataError> {
+        let token = params.token.unwrap_or_else(|| {
+            find_associated_token_account(&params.token_owner, &self.mint, &self.spl_token_program)
+        });
+
+        let mut builder = BurnV1Builder::new();
+        builder
+            .authority(params.authority)
+            .collection_metadata(
+                self.collection
+                    .map(|collection| Metadata::find_pda(&collection).0),
+            )
+            .metadata(Metadata::find_pda(&self.mint).0)
+            .edition(self.edition())
+            .mint(self.mint)
+            .token(token)
+            .token_record(self.token_record(&token))
+            .spl_token_program(self.spl_token_program)
+            .authorization_rules_program(self.authorization_rules_program())
+            .authorization_rules(self.authorization_rules())
+            .amount(params.amount);
+
+        if matches!(
+            self.token_standard,
+            TokenStandard::NonFungibleEdition | TokenStandard::ProgrammableNonFungibleEdition
+        ) {
+            let parent = params
+                .parent
+                .ok_or(MplTokenMetadataError::MissingMasterEditionAccount)?;
+
+            let edition_marker = if self.is_programmable() {
+                EditionMarkerV2::find_pda(&parent.mint).0
+            } else {
+                EditionMarker::find_pda(
+                    &parent.mint,
+                    (parent.edition_number / EDITION_MARKER_BIT_SIZE)
+                        .to_string()
+                        .into(),
+                )
+                .0
+            };
+
+            builder
+                .master_edition(Some(MasterEdition::find_pda(&parent.mint).0))
+                .master_edition_mint(Some(parent.mint))
+                .master_edition_token(Some(parent.token))
+                .edition_marker(Some(edition_marker));
+        }
+
+        Ok(self.plan(builder.instruction()))
+    }
+
+    /// Plans the creation of a delegate.
+    pub fn delegate(&self, params: DelegateParams) -> Result<OperationPlan, MplTokenMetadataError> {
+        let kind = match &params.args {
+            DelegateArgs::CollectionV1 { .. } | DelegateArgs::CollectionV2 { .. } => {
+                DelegateKind::Metadata(MetadataDelegateRole::Collection)
+            }
+            DelegateArgs::DataV1 { .. } | DelegateArgs::DataV2 { .. } => {
+                DelegateKind::Metadata(MetadataDelegateRole::Data)
+            }
+            DelegateArgs::ProgrammableConfigV1 { .. }
+            | DelegateArgs::ProgrammableConfigV2 { .. } => {
+                DelegateKind::Metadata(MetadataDelegateRole::ProgrammableConfig)
+            }
+            DelegateArgs::AuthorityItemV1 { .. } | DelegateArgs::AuthorityItemV2 { .. } => {
+                DelegateKind::Metadata(MetadataDelegateRole::AuthorityItem)
+            }
+            DelegateArgs::DataItemV1 { .. } | DelegateArgs::DataItemV2 { .. } => {
+                DelegateKind::Metadata(MetadataDelegateRole::DataItem)
+            }
+            DelegateArgs::CollectionItemV1 { .. } | DelegateArgs::CollectionItemV2 { .. } => {
+                DelegateKind::Metadata(MetadataDelegateRole::CollectionItem)
+            }
+            DelegateArgs::ProgrammableConfigItemV1 { .. }
+            | DelegateArgs::ProgrammableConfigItemV2 { .. } => {
+                DelegateKind::Metadata(MetadataDelegateRole::ProgrammableConfigItem)
+            }
+            DelegateArgs::PrintDelegateV1 { .. } => {
+                DelegateKind::Holder(HolderDelegateRole::PrintDelegate)
+            }
+            DelegateArgs::SaleV1 { .. } | DelegateArgs::SaleV2 { .. } => {
+                DelegateKind::Token(TokenDelegateRole::Sale)
+            }
+            DelegateArgs::TransferV1 { .. } | DelegateArgs::TransferV2 { .. } => {
+                DelegateKind::Token(TokenDelegateRole::Transfer)
+            }
+            DelegateArgs::UtilityV1 { .. } | DelegateArgs::UtilityV2 { .. } => {
+                DelegateKind::Token(TokenDelegateRole::Utility)
+            }
+            DelegateArgs::StakingV1 { .. } | DelegateArgs::StakingV2 { .. } => {
+                DelegateKind::Token(TokenDelegateRole::Staking)
+            }
+            DelegateArgs::StandardV1 { .. } => DelegateKind::Token(TokenDelegateRole::Standard),
+            DelegateArgs::LockedTransferV1 { .. } => {
+                DelegateKind::Token(TokenDelegateRole::LockedTransfer)
+            }
+        };
+
+        let accounts =
+            self.delegate_accounts(&kind, &params.delegate, &params.authority, params.token)?;
+
+        let instruction = DelegateBuilder::new()
+            .delegate_record(accounts.delegate_record)
+            .delegate(params.delegate)
+            .metadata(Metadata::find_pda(&self.mint).0)
+            .master_edition(accounts.master_edition)
+            .token_record(accounts.token_record)
+            .mint(self.mint)
+            .token(accounts.token)
+            .authority(params.authority)
+            .payer(params.payer)
+            .spl_token_program(accounts.spl_token_program)
+            .authorization_rules_program(accounts.authorization_rules_program)
+            .authorization_rules(accounts.authorization_rules)
+            .delegate_args(params.args)
+            .instruction();
+
+        Ok(self.plan(instruction))
+    }
+
+    /// Plans the revocation of a delegate.
+    pub fn revoke(&self, params: RevokeParams) -> Result<OperationPlan, MplTokenMetadataError> {
+        let kind = match &params.args {
+            RevokeArgs::CollectionV1 => DelegateKind::Metadata(MetadataDelegateRole::Collection),
+            RevokeArgs::DataV1 => DelegateKind::Metadata(MetadataDelegateRole::Data),
+            RevokeArgs::ProgrammableConfigV1 => {
+                DelegateKind::Metadata(MetadataDelegateRole::ProgrammableConfig)
+            }
+            RevokeArgs::AuthorityItemV1 => {
+                DelegateKind::Metadata(MetadataDelegateRole::AuthorityItem)
+            }
+            RevokeArgs::DataItemV1 => DelegateKind::Metadata(MetadataDelegateRole::DataItem),
+            RevokeArgs::CollectionItemV1 => {
+                DelegateKind::Metadata(MetadataDelegateRole::CollectionItem)
+            }
+            RevokeArgs::ProgrammableConfigItemV1 => {
+                DelegateKind::Metadata(MetadataDelegateRole::ProgrammableConfigItem)
+            }
+            RevokeArgs::PrintDelegateV1 => DelegateKind::Holder(HolderDelegateRole::PrintDelegate),
+            RevokeArgs::SaleV1 => DelegateKind::Token(TokenDelegateRole::Sale),
+            RevokeArgs::TransferV1 => DelegateKind::Token(TokenDelegateRole::Transfer),
+            RevokeArgs::UtilityV1 => DelegateKind::Token(TokenDelegateRole::Utility),
+            RevokeArgs::StakingV1 => DelegateKind::Token(TokenDelegateRole::Staking),
+            RevokeArgs::StandardV1 => DelegateKind::Token(TokenDelegateRole::Standard),
+            RevokeArgs::LockedTransferV1 => DelegateKind::Token(TokenDelegateRole::LockedTransfer),
+            RevokeArgs::MigrationV1 => DelegateKind::Token(TokenDelegateRole::Migration),
+        };
+
+        // holder and token delegates are derived from the token owner, which is the
+        // authority unless the delegate is revoking itself
+        let token_owner = params.token_owner.unwrap_or(params.authority);
+
+        let accounts =
+            self.delegate_accounts(&kind, &params.delegate, &token_owner, params.token)?;
+
+        let instruction = RevokeBuilder::new()
+            .delegate_record(accounts.delegate_record)
+            .delegate(params.delegate)
+            .metadata(Metadata::find_pda(&self.mint).0)
+            .master_edition(accounts.master_edition)
+            .token_record(accounts.token_record)
+            .mint(self.mint)
+            .token(accounts.token)
+            .authority(params.authority)
+            .payer(params.payer)
+            .spl_token_program(accounts.spl_token_program)
+            .authorization_rules_program(accounts.authorization_rules_program)
+            .authorization_rules(accounts.authorization_rules)
+            .revoke_args(params.args)
+            .instruction();
+
+        Ok(self.plan(instruction))
+    }
+
+    /// Resolves the optional accounts of a delegate or revoke instruction.
+    fn delegate_accounts(
+        &self,
+        kind: &DelegateKind,
+        delegate: &Pubkey,
+        owner: &Pubkey,
+        token: Option<Pubkey>,
+    ) -> Result<DelegateAccounts, MplTokenMetadataError> {
+        let token = token.unwrap_or_else(|| {
+            find_associated_token_account(owner, &self.mint, &self.spl_token_program)
+        });
+
+        let accounts = match kind {
+            DelegateKind::Metadata(role) => DelegateAccounts {
+                delegate_record: Some(
+                    MetadataDelegateRecord::find_pda(
+                        &self.mint,
+                        role.clone(),
+                        &self.update_authority,
+                        delegate,
+                    )
+                    .0,
+                ),
+                ..Default::default()
+            },
+            DelegateKind::Holder(role) => DelegateAccounts {
+                delegate_record: Some(
+                    HolderDelegateRecord::find_pda(&self.mint, role.clone(), owner, delegate).0,
+                ),
+                token: Some(token),
+                ..Default::default()
+            },
+            DelegateKind::Token(role) => {
+                // programmable assets can have any token delegate apart from `Standard`,
+                // while non-programmable assets only support `Standard` delegates
+                if self.is_programmable() == matches!(role, TokenDelegateRole::Standard) {
+                    return Err(MplTokenMetadataError::InvalidDelegateRole);
+                }
+
+                // the master edition is needed to thaw/freeze programmable assets and
+                // to set the close authority of utility delegates
+                let master_edition =
+                    if self.is_programmable() || matches!(role, TokenDelegateRole::Utility) {
+                        self.edition()
+                    } else {
+                        None
+                    };
+
+                DelegateAccounts {
+                    master_edition,
+                    token_record: self.token_record(&token),
+                    token: Some(token),
+                    spl_token_program: Some(self.spl_token_program),
+                    authorization_rules_program: self.authorization_rules_program(),
+                    authorization_rules: self.authorization_rules(),
+                    ..Default::default()
+                }
+            }
+        };
+
+        Ok(accounts)
+    }
+
+    fn is_programmable(&self) -> bool {
+        matches!(
+            self.token_standard,
+            TokenStandard::ProgrammableNonFungible | TokenStandard::ProgrammableNonFungibleEdition
+        )
+    }
+
+    /// Master edition or print edition account; fungible assets do not have one.
+    fn edition(&self) -> Option<Pubkey> {
+        match self.token_standard {
+            TokenStandard::Fungible | TokenStandard::FungibleAsset => None,
+            _ => Some(MasterEdition::find_pda(&self.mint).0),
+        }
+    }
+
+    /// Token record account of a token account (programmable assets only).
+    fn token_record(&self, token: &Pubkey) -> Option<Pubkey> {
+        if self.is_programmable() {
+            Some(TokenRecord::find_pda(&self.mint, token).0)
+        } else {
+            None
+        }
+    }
+
+    fn authorization_rules(&self) -> Option<Pubkey> {
+        if self.is_programmable() {
+            self.rule_set
+        } else {
+            None
+        }
+    }
+
+    fn authorization_rules_program(&self) -> Option<Pubkey> {
+        self.authorization_rules()
+            .map(|_| MPL_TOKEN_AUTH_RULES_PROGRAM_ID)
+    }
+
+    fn compute_units(&self) -> u32 {
+        if self.is_programmable() {
+            PROGRAMMABLE_COMPUTE_UNITS
+        } else {
+            DEFAULT_COMPUTE_UNITS
+        }
+    }
+
+    fn plan(&self, instruction: Instruction) -> OperationPlan {
+        OperationPlan {
+            instructions: vec![instruction],
+            compute_units: self.compute_units(),
+        }
+    }
+}
+
+/// Optional accounts of delegate and revoke instructions.
+#[derive(Default)]
+struct DelegateAccounts {
+    delegate_record: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    spl_token_program: Option<Pubkey>,
+    authorization_rules_program: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+}
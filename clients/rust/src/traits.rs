@@ -7,7 +7,8 @@ use solana_program::pubkey::Pubkey;
 
 use crate::{
     accounts::{
-        CollectionAuthorityRecord, MasterEdition, Metadata, MetadataDelegateRecord, TokenRecord,
+        CollectionAuthorityRecord, MasterEdition, Metadata, MetadataAttributes,
+        MetadataDelegateRecord, TokenRecord,
     },
     errors::MplTokenMetadataError,
     generated::{
@@ -15,8 +16,8 @@ use crate::{
         {instructions::UpdateV1InstructionArgs, types::CollectionDetailsToggle},
     },
     types::{
-        Collection, CollectionDetails, Data, Key, ProgrammableConfig, TokenDelegateRole,
-        TokenStandard, TokenState, UpdateArgs, Uses,
+        Collection, CollectionDetails, Data, DelegateExpiry, Key, ProgrammableConfig,
+        TokenDelegateRole, TokenStandard, TokenState, UpdateArgs, Uses,
     },
 };
 
@@ -48,8 +49,7 @@ macro_rules! safe_deserialize {
 
 safe_deserialize!(
     (CollectionAuthorityRecord, CollectionAuthorityRecord),
-    (MasterEdition, MasterEditionV2),
-    (MetadataDelegateRecord, MetadataDelegate)
+    (MasterEdition, MasterEditionV2)
 );
 
 // UpdateV1InstructionArgs
@@ -160,15 +160,30 @@ impl Metadata {
 
 const LOCKED_TRANSFER_SIZE: usize = 33;
 
+const DELEGATE_EXPIRY_SIZE: usize = 10;
+
+const UNLOCK_AFTER_SIZE: usize = 9;
+
 impl TokenRecord {
     pub fn safe_deserialize(data: &[u8]) -> Result<TokenRecord, Error> {
         // we perform a manual deserialization since we are potentially dealing
-        // with accounts of different sizes
+        // with accounts of different sizes: records created before the unlock
+        // timestamp, the delegate expiry (and the locked transfer) were supported
+        // do not store them
         let length = TokenRecord::LEN as i64 - data.len() as i64;
+        let without_unlock_after = UNLOCK_AFTER_SIZE as i64;
+        let without_delegate_expiry = without_unlock_after + DELEGATE_EXPIRY_SIZE as i64;
+        let without_locked_transfer = without_delegate_expiry + LOCKED_TRANSFER_SIZE as i64;
 
         // we use the account length in the 'is_correct_account_type' since we are
         // manually checking that the account length is valid
-        if !(length == 0 || length == LOCKED_TRANSFER_SIZE as i64)
+        if ![
+            0,
+            without_unlock_after,
+            without_delegate_expiry,
+            without_locked_transfer,
+        ]
+        .contains(&length)
             || data[0] != Key::TokenRecord as u8
         {
             return Err(Error::new(
@@ -186,7 +201,19 @@ impl TokenRecord {
         let delegate: Option<Pubkey> = BorshDeserialize::deserialize(&mut data)?;
         let delegate_role: Option<TokenDelegateRole> = BorshDeserialize::deserialize(&mut data)?;
 
-        let locked_transfer: Option<Pubkey> = if length == 0 {
+        let locked_transfer: Option<Pubkey> = if length < without_locked_transfer {
+            BorshDeserialize::deserialize(&mut data)?
+        } else {
+            None
+        };
+
+        let delegate_expiry: Option<DelegateExpiry> = if length < without_delegate_expiry {
+            BorshDeserialize::deserialize(&mut data)?
+        } else {
+            None
+        };
+
+        let unlock_after: Option<i64> = if length == 0 {
             BorshDeserialize::deserialize(&mut data)?
         } else {
             None
@@ -200,10 +227,65 @@ impl TokenRecord {
             delegate,
             delegate_role,
             locked_transfer,
+            delegate_expiry,
+            unlock_after,
+        })
+    }
+}
+
+// MetadataDelegateRecord
+
+impl MetadataDelegateRecord {
+    pub fn safe_deserialize(data: &[u8]) -> Result<MetadataDelegateRecord, Error> {
+        // records created before the delegate expiry was supported do not store it
+        let length = MetadataDelegateRecord::LEN as i64 - data.len() as i64;
+
+        if !(length == 0 || length == DELEGATE_EXPIRY_SIZE as i64)
+            || data[0] != Key::MetadataDelegate as u8
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                MplTokenMetadataError::DataTypeMismatch,
+            ));
+        }
+        // mutable "pointer" to the account data
+        let mut data = data;
+
+        let key: Key = BorshDeserialize::deserialize(&mut data)?;
+        let bump: u8 = BorshDeserialize::deserialize(&mut data)?;
+        let mint: Pubkey = BorshDeserialize::deserialize(&mut data)?;
+        let delegate: Pubkey = BorshDeserialize::deserialize(&mut data)?;
+        let update_authority: Pubkey = BorshDeserialize::deserialize(&mut data)?;
+
+        let expiry: Option<DelegateExpiry> = if length == 0 {
+            BorshDeserialize::deserialize(&mut data)?
+        } else {
+            None
+        };
+
+        Ok(MetadataDelegateRecord {
+            key,
+            bump,
+            mint,
+            delegate,
+            update_authority,
+            expiry,
         })
     }
 }
 
+// MetadataAttributes
+
+impl MetadataAttributes {
+    /// Returns the value of the attribute with the specified key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    }
+}
+
 // UpdateArgs
 
 impl Default for UpdateArgs {
@@ -1,19 +1,43 @@
 use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
 
 use crate::{
     errors::MplTokenMetadataError,
     types::{Key, TokenStandard},
+    SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
 };
 
 /// The offset of the token standard byte in the master edition
 /// from the end of the account data.
 const TOKEN_STANDARD_OFFSET: usize = 1;
 
+/// Number of bytes allocated for a metadata account.
+pub const MAX_METADATA_LEN: usize = 607;
+
+/// Number of bytes allocated for a master edition account.
+pub const MAX_MASTER_EDITION_LEN: usize = 20;
+
+/// Number of bytes allocated for a print edition account.
+pub const MAX_EDITION_LEN: usize = 42;
+
 /// Removes all null bytes from a string.
 pub fn clean(value: String) -> String {
     value.replace('\0', "")
 }
 
+/// Derives the associated token account address of a wallet for a given mint.
+pub fn find_associated_token_account(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    spl_token_program: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), spl_token_program.as_ref(), mint.as_ref()],
+        &SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+    )
+    .0
+}
+
 /// Checks that the `master_edition` is Programmable NFT master edition.
 pub fn assert_edition_is_programmable(edition_data: &[u8]) -> Result<(), MplTokenMetadataError> {
     if edition_data.len() > TOKEN_STANDARD_OFFSET {
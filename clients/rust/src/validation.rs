@@ -0,0 +1,467 @@
+//! Off-chain validation of the data of `Create` and `Update` instructions.
+//!
+//! The `validate` methods reproduce the checks performed by the program on the
+//! metadata data (`assert_data_valid`), uses and collection so that invalid values
+//! are reported before a transaction is submitted. Unlike the program, which fails
+//! on the first violation, all violations are returned.
+//!
+//! The `authority` of the validation is the signer of the instruction. As with the
+//! program, a creator matching the authority can set or clear its own `verified` flag.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    accounts::Metadata,
+    errors::MplTokenMetadataError,
+    types::{
+        Collection, CollectionDetailsToggle, CollectionToggle, CreateArgs, Creator, Data, DataV2,
+        RuleSetToggle, TokenStandard, UpdateArgs, UseMethod, Uses, UsesToggle,
+    },
+    MAX_CREATOR_LIMIT, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+
+/// Maximum value of the seller fee basis points.
+const MAX_SELLER_FEE_BASIS_POINTS: u16 = 10000;
+
+/// Violations found during a validation.
+#[derive(Default)]
+struct Violations(Vec<MplTokenMetadataError>);
+
+impl Violations {
+    fn push(&mut self, error: MplTokenMetadataError) {
+        // the same rule can be violated by more than one creator
+        if !self.0.contains(&error) {
+            self.0.push(error);
+        }
+    }
+
+    fn into_result(self) -> Result<(), Vec<MplTokenMetadataError>> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(self.0)
+        }
+    }
+}
+
+impl Data {
+    /// Validates the data against the `existing` metadata, which is `None` when the
+    /// metadata is being created.
+    pub fn validate(
+        &self,
+        authority: &Pubkey,
+        existing: Option<&Metadata>,
+    ) -> Result<(), Vec<MplTokenMetadataError>> {
+        let mut violations = Violations::default();
+
+        validate_data(
+            &self.name,
+            &self.symbol,
+            &self.uri,
+            self.seller_fee_basis_points,
+            &self.creators,
+            authority,
+            existing.and_then(|metadata| metadata.creators.as_ref()),
+            &mut violations,
+        );
+
+        violations.into_result()
+    }
+}
+
+impl DataV2 {
+    /// Validates the data, uses and collection against the `existing` metadata, which
+    /// is `None` when the metadata is being created.
+    pub fn validate(
+        &self,
+        authority: &Pubkey,
+        existing: Option<&Metadata>,
+    ) -> Result<(), Vec<MplTokenMetadataError>> {
+        let mut violations = Violations::default();
+
+        validate_data(
+            &self.name,
+            &self.symbol,
+            &self.uri,
+            self.seller_fee_basis_points,
+            &self.creators,
+            authority,
+            existing.and_then(|metadata| metadata.creators.as_ref()),
+            &mut violations,
+        );
+
+        let existing_collection = existing.and_then(|metadata| metadata.collection.as_ref());
+
+        match (&self.collection, existing_collection) {
+            (None, Some(collection)) if collection.verified => {
+                violations.push(MplTokenMetadataError::CannotUpdateVerifiedCollection)
+            }
+            (None, _) => (),
+            (Some(collection), existing_collection) => {
+                validate_collection(Some(collection), existing_collection, &mut violations)
+            }
+        }
+
+        validate_uses(
+            self.uses.as_ref(),
+            existing.and_then(|metadata| metadata.uses.as_ref()),
+            &mut violations,
+        );
+
+        violations.into_result()
+    }
+}
+
+impl CreateArgs {
+    /// Validates the asset data of a `Create` instruction.
+    pub fn validate(&self, authority: &Pubkey) -> Result<(), Vec<MplTokenMetadataError>> {
+        let mut violations = Violations::default();
+
+        let CreateArgs::V1 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points,
+            creators,
+            token_standard,
+            collection,
+            uses,
+            ..
+        } = self;
+
+        // editions are only created by printing from a master edition
+        if matches!(
+            token_standard,
+            TokenStandard::NonFungibleEdition | TokenStandard::ProgrammableNonFungibleEdition
+        ) {
+            violations.push(MplTokenMetadataError::InvalidTokenStandard);
+        }
+
+        validate_data(
+            name,
+            symbol,
+            uri,
+            *seller_fee_basis_points,
+            creators,
+            authority,
+            None,
+            &mut violations,
+        );
+        validate_collection(collection.as_ref(), None, &mut violations);
+        validate_uses(uses.as_ref(), None, &mut violations);
+
+        violations.into_result()
+    }
+}
+
+impl UpdateArgs {
+    /// Validates the arguments of an `Update` instruction against the `existing` metadata.
+    pub fn validate(
+        &self,
+        authority: &Pubkey,
+        existing: &Metadata,
+    ) -> Result<(), Vec<MplTokenMetadataError>> {
+        let mut violations = Violations::default();
+
+        match self {
+            UpdateArgs::V1 {
+                uses,
+                collection_details,
+                ..
+            }
+            | UpdateArgs::AsUpdateAuthorityV2 {
+                uses,
+                collection_details,
+                ..
+            } => {
+                if let UsesToggle::Set(uses) = uses {
+                    validate_uses(Some(uses), existing.uses.as_ref(), &mut violations);
+                }
+
+                if matches!(collection_details, CollectionDetailsToggle::Set(_))
+                    && existing.collection_details.is_some()
+                {
+                    violations.push(MplTokenMetadataError::SizedCollection);
+                }
+            }
+            _ => (),
+        }
+
+        match self {
+            UpdateArgs::V1 {
+                data: Some(data), ..
+            }
+            | UpdateArgs::AsUpdateAuthorityV2 {
+                data: Some(data), ..
+            }
+            | UpdateArgs::AsDataDelegateV2 {
+                data: Some(data), ..
+            }
+            | UpdateArgs::AsDataItemDelegateV2 {
+                data: Some(data), ..
+            } => {
+                if existing.is_mutable {
+                    validate_data(
+                        &data.name,
+                        &data.symbol,
+                        &data.uri,
+                        data.seller_fee_basis_points,
+                        &data.creators,
+                        authority,
+                        existing.creators.as_ref(),
+                        &mut violations,
+                    );
+                } else {
+                    violations.push(MplTokenMetadataError::DataIsImmutable);
+                }
+            }
+            _ => (),
+        }
+
+        match self {
+            UpdateArgs::V1 {
+                primary_sale_happened,
+                is_mutable,
+                ..
+            }
+            | UpdateArgs::AsUpdateAuthorityV2 {
+                primary_sale_happened,
+                is_mutable,
+                ..
+            }
+            | UpdateArgs::AsAuthorityItemDelegateV2 {
+                primary_sale_happened,
+                is_mutable,
+                ..
+            } => {
+                if *primary_sale_happened == Some(false) && existing.primary_sale_happened {
+                    violations.push(MplTokenMetadataError::PrimarySaleCanOnlyBeFlippedToTrue);
+                }
+
+                if *is_mutable == Some(true) && !existing.is_mutable {
+                    violations.push(MplTokenMetadataError::IsMutableCanOnlyBeFlippedToFalse);
+                }
+            }
+            _ => (),
+        }
+
+        if let UpdateArgs::AsAuthorityItemDelegateV2 {
+            new_update_authority: Some(_),
+            ..
+        } = self
+        {
+            violations.push(MplTokenMetadataError::CannotChangeUpdateAuthorityWithDelegate);
+        }
+
+        match self {
+            UpdateArgs::V1 { collection, .. }
+            | UpdateArgs::AsUpdateAuthorityV2 { collection, .. }
+            | UpdateArgs::AsCollectionDelegateV2 { collection, .. }
+            | UpdateArgs::AsCollectionItemDelegateV2 { collection, .. } => match collection {
+                CollectionToggle::Set(collection) => {
+                    validate_collection(
+                        Some(collection),
+                        existing.collection.as_ref(),
+                        &mut violations,
+                    );
+                }
+                CollectionToggle::Clear => {
+                    if matches!(&existing.collection, Some(collection) if collection.verified) {
+                        violations.push(MplTokenMetadataError::CannotUpdateVerifiedCollection);
+                    }
+                }
+                CollectionToggle::None => (),
+            },
+            _ => (),
+        }
+
+        match self {
+            UpdateArgs::V1 { rule_set, .. }
+            | UpdateArgs::AsUpdateAuthorityV2 { rule_set, .. }
+            | UpdateArgs::AsProgrammableConfigDelegateV2 { rule_set, .. }
+            | UpdateArgs::AsProgrammableConfigItemDelegateV2 { rule_set, .. } => {
+                // the token standard can be changed by the same instruction
+                let token_standard = match self {
+                    UpdateArgs::AsUpdateAuthorityV2 {
+                        token_standard: Some(token_standard),
+                        ..
+                    } => Some(*token_standard),
+                    _ => existing.token_standard,
+                };
+
+                if !matches!(rule_set, RuleSetToggle::None)
+                    && !matches!(
+                        token_standard,
+                        Some(TokenStandard::ProgrammableNonFungible)
+                            | Some(TokenStandard::ProgrammableNonFungibleEdition)
+                    )
+                {
+                    violations.push(MplTokenMetadataError::InvalidTokenStandard);
+                }
+            }
+            _ => (),
+        }
+
+        violations.into_result()
+    }
+}
+
+/// Reproduces the checks of `assert_data_valid`.
+#[allow(clippy::too_many_arguments)]
+fn validate_data(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    seller_fee_basis_points: u16,
+    creators: &Option<Vec<Creator>>,
+    authority: &Pubkey,
+    existing_creators: Option<&Vec<Creator>>,
+    violations: &mut Violations,
+) {
+    if name.len() > MAX_NAME_LENGTH {
+        violations.push(MplTokenMetadataError::NameTooLong);
+    }
+
+    if symbol.len() > MAX_SYMBOL_LENGTH {
+        violations.push(MplTokenMetadataError::SymbolTooLong);
+    }
+
+    if uri.len() > MAX_URI_LENGTH {
+        violations.push(MplTokenMetadataError::UriTooLong);
+    }
+
+    if seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+        violations.push(MplTokenMetadataError::InvalidBasisPoints);
+    }
+
+    let creators = match creators {
+        Some(creators) => creators,
+        None => {
+            // creators can only be removed if none of them is verified
+            if existing_creators.is_some_and(|existing| existing.iter().any(|c| c.verified)) {
+                violations.push(MplTokenMetadataError::CannotRemoveVerifiedCreator);
+            }
+            return;
+        }
+    };
+
+    if creators.len() > MAX_CREATOR_LIMIT {
+        violations.push(MplTokenMetadataError::CreatorsTooLong);
+    }
+
+    if creators.is_empty() {
+        violations.push(MplTokenMetadataError::CreatorsMustBeAtleastOne);
+    }
+
+    let mut unique_creators: Vec<&Creator> = Vec::with_capacity(creators.len());
+
+    for creator in creators {
+        if unique_creators
+            .iter()
+            .any(|unique| unique.address == creator.address)
+        {
+            violations.push(MplTokenMetadataError::DuplicateCreatorAddress);
+        } else {
+            unique_creators.push(creator);
+        }
+    }
+
+    let find_existing = |address: &Pubkey| {
+        existing_creators.and_then(|existing| existing.iter().find(|c| c.address == *address))
+    };
+
+    let mut share_total = Some(0u8);
+
+    for creator in &unique_creators {
+        share_total = share_total.and_then(|total| total.checked_add(creator.share));
+
+        // the authority can set or clear its own verified flag
+        if creator.address == *authority {
+            continue;
+        }
+
+        match find_existing(&creator.address) {
+            Some(existing) if creator.verified && !existing.verified => {
+                violations.push(MplTokenMetadataError::CannotVerifyAnotherCreator)
+            }
+            Some(existing) if !creator.verified && existing.verified => {
+                violations.push(MplTokenMetadataError::CannotUnverifyAnotherCreator)
+            }
+            None if creator.verified => {
+                violations.push(MplTokenMetadataError::CannotVerifyAnotherCreator)
+            }
+            _ => (),
+        }
+    }
+
+    match share_total {
+        Some(100) => (),
+        Some(_) => violations.push(MplTokenMetadataError::ShareTotalMustBe100),
+        None => violations.push(MplTokenMetadataError::NumericalOverflowError),
+    }
+
+    // verified creators cannot be removed by another authority
+    if let Some(existing_creators) = existing_creators {
+        for existing in existing_creators {
+            if existing.verified
+                && existing.address != *authority
+                && !unique_creators
+                    .iter()
+                    .any(|c| c.address == existing.address)
+            {
+                violations.push(MplTokenMetadataError::CannotUnverifyAnotherCreator);
+            }
+        }
+    }
+}
+
+/// Reproduces the checks of `assert_collection_update_is_valid`.
+fn validate_collection(
+    incoming: Option<&Collection>,
+    existing: Option<&Collection>,
+    violations: &mut Violations,
+) {
+    let is_existing_verified = existing.is_some_and(|collection| collection.verified);
+
+    let valid = match incoming {
+        // verified: can only update if the details match
+        Some(incoming) if incoming.verified => {
+            existing.is_some_and(|existing| existing.verified && existing.key == incoming.key)
+        }
+        // unverified: can only update if existing is unverified
+        _ => !is_existing_verified,
+    };
+
+    if !valid {
+        violations.push(MplTokenMetadataError::CollectionCannotBeVerifiedInThisInstruction);
+    }
+}
+
+/// Reproduces the checks of `assert_valid_use`.
+fn validate_uses(incoming: Option<&Uses>, current: Option<&Uses>, violations: &mut Violations) {
+    let Some(incoming) = incoming else {
+        return;
+    };
+
+    let invalid_method = match incoming.use_method {
+        UseMethod::Single => incoming.total != 1 || incoming.remaining != 1,
+        UseMethod::Multiple => incoming.total < 2 || incoming.total < incoming.remaining,
+        _ => false,
+    };
+
+    if invalid_method {
+        violations.push(MplTokenMetadataError::InvalidUseMethod);
+    }
+
+    // uses can only change before the first use
+    if let Some(current) = current {
+        if current.total != current.remaining {
+            if incoming.use_method != current.use_method {
+                violations.push(MplTokenMetadataError::CannotChangeUseMethodAfterFirstUse);
+            }
+            if incoming.total != current.total || incoming.remaining != current.remaining {
+                violations.push(MplTokenMetadataError::CannotChangeUsesAfterFirstUse);
+            }
+        }
+    }
+}
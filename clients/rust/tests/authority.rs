@@ -0,0 +1,376 @@
+pub mod setup;
+pub use setup::*;
+
+use mpl_token_metadata::{
+    accounts::{Metadata, MetadataDelegateRecord, TokenRecord},
+    authority::{
+        explain_authority, AuthorityOperation, TokenAccount, TransferScenario, UpdateScenario,
+    },
+    errors::MplTokenMetadataError,
+    types::{
+        AuthorityType, DelegateExpiry, Key, MetadataDelegateRole, TokenDelegateRole, TokenStandard,
+        TokenState, UpdateArgs,
+    },
+};
+use solana_program::{clock::Clock, program_pack::Pack};
+use solana_pubkey::Pubkey;
+
+fn token(metadata: &Metadata, owner: Pubkey, delegate: Option<Pubkey>) -> TokenAccount {
+    TokenAccount {
+        address: Pubkey::new_unique(),
+        mint: metadata.mint,
+        owner,
+        amount: 1,
+        delegate,
+        delegated_amount: delegate.map(|_| 1).unwrap_or_default(),
+    }
+}
+
+fn token_record(delegate: Option<Pubkey>, delegate_role: Option<TokenDelegateRole>) -> TokenRecord {
+    TokenRecord {
+        key: Key::TokenRecord,
+        bump: 255,
+        state: TokenState::Unlocked,
+        rule_set_revision: None,
+        delegate,
+        delegate_role,
+        locked_transfer: None,
+        delegate_expiry: None,
+        unlock_after: None,
+    }
+}
+
+mod authority {
+    use super::*;
+
+    #[test]
+    fn update_as_update_authority() {
+        let update_authority = Pubkey::new_unique();
+        let metadata = Metadata {
+            update_authority,
+            ..metadata(TokenStandard::ProgrammableNonFungible)
+        };
+
+        let explanation = explain_authority(
+            &metadata,
+            None,
+            None,
+            &[],
+            &update_authority,
+            &AuthorityOperation::Update(UpdateArgs::default_as_update_authority()),
+            &Clock::default(),
+        );
+
+        assert!(explanation.is_authorized());
+        assert_eq!(explanation.authority_type, AuthorityType::Metadata);
+        assert_eq!(
+            explanation.update_scenario,
+            Some(UpdateScenario::MetadataAuth)
+        );
+
+        let rejected: Vec<AuthorityType> = explanation
+            .rejected
+            .iter()
+            .map(|rejected| rejected.authority_type.clone())
+            .collect();
+        assert_eq!(
+            rejected,
+            vec![AuthorityType::MetadataDelegate, AuthorityType::Holder]
+        );
+    }
+
+    #[test]
+    fn update_as_metadata_delegate() {
+        let update_authority = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let metadata = Metadata {
+            update_authority,
+            ..metadata(TokenStandard::ProgrammableNonFungible)
+        };
+
+        let (address, bump) = MetadataDelegateRecord::find_pda(
+            &metadata.mint,
+            MetadataDelegateRole::Collection,
+            &update_authority,
+            &delegate,
+        );
+        let record = MetadataDelegateRecord {
+            key: Key::MetadataDelegate,
+            bump,
+            mint: metadata.mint,
+            delegate,
+            update_authority,
+            expiry: None,
+        };
+
+        let explanation = explain_authority(
+            &metadata,
+            None,
+            None,
+            &[(address, record.clone())],
+            &delegate,
+            &AuthorityOperation::Update(UpdateArgs::default_as_collection_delegate()),
+            &Clock::default(),
+        );
+
+        assert!(explanation.is_authorized());
+        assert_eq!(explanation.authority_type, AuthorityType::MetadataDelegate);
+        assert_eq!(
+            explanation.metadata_delegate_role,
+            Some(MetadataDelegateRole::Collection)
+        );
+        assert_eq!(explanation.update_scenario, Some(UpdateScenario::Delegate));
+        assert_eq!(
+            explanation.rejected[0].authority_type,
+            AuthorityType::Metadata
+        );
+
+        // a collection delegate cannot update the data
+        let explanation = explain_authority(
+            &metadata,
+            None,
+            None,
+            &[(address, record)],
+            &delegate,
+            &AuthorityOperation::Update(UpdateArgs::default_as_data_delegate()),
+            &Clock::default(),
+        );
+
+        assert_eq!(explanation.authority_type, AuthorityType::MetadataDelegate);
+        assert_eq!(
+            explanation.error,
+            Some(MplTokenMetadataError::InvalidUpdateArgs)
+        );
+    }
+
+    #[test]
+    fn transfer_as_sale_delegate() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let metadata = metadata(TokenStandard::ProgrammableNonFungible);
+        let token = token(&metadata, owner, Some(delegate));
+        let token_record = token_record(Some(delegate), Some(TokenDelegateRole::Sale));
+
+        let explanation = explain_authority(
+            &metadata,
+            Some(&token),
+            Some(&token_record),
+            &[],
+            &delegate,
+            &AuthorityOperation::Transfer { amount: 1 },
+            &Clock::default(),
+        );
+
+        assert!(explanation.is_authorized());
+        assert_eq!(explanation.authority_type, AuthorityType::TokenDelegate);
+        assert_eq!(
+            explanation.token_delegate_role,
+            Some(TokenDelegateRole::Sale)
+        );
+        assert_eq!(
+            explanation.transfer_scenario,
+            Some(TransferScenario::SaleDelegate)
+        );
+
+        // the owner cannot transfer while a sale delegate is set
+        let explanation = explain_authority(
+            &metadata,
+            Some(&token),
+            Some(&token_record),
+            &[],
+            &owner,
+            &AuthorityOperation::Transfer { amount: 1 },
+            &Clock::default(),
+        );
+
+        assert_eq!(explanation.authority_type, AuthorityType::Holder);
+        assert_eq!(
+            explanation.error,
+            Some(MplTokenMetadataError::OnlySaleDelegateCanTransfer)
+        );
+    }
+
+    #[test]
+    fn fail_to_update_as_expired_metadata_delegate() {
+        let update_authority = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let metadata = Metadata {
+            update_authority,
+            ..metadata(TokenStandard::ProgrammableNonFungible)
+        };
+
+        let (address, bump) = MetadataDelegateRecord::find_pda(
+            &metadata.mint,
+            MetadataDelegateRole::Data,
+            &update_authority,
+            &delegate,
+        );
+        let record = MetadataDelegateRecord {
+            key: Key::MetadataDelegate,
+            bump,
+            mint: metadata.mint,
+            delegate,
+            update_authority,
+            expiry: Some(DelegateExpiry::Slot(100)),
+        };
+        let operation = AuthorityOperation::Update(UpdateArgs::default_as_data_delegate());
+
+        let clock = Clock {
+            slot: 99,
+            ..Default::default()
+        };
+        let explanation = explain_authority(
+            &metadata,
+            None,
+            None,
+            &[(address, record.clone())],
+            &delegate,
+            &operation,
+            &clock,
+        );
+
+        assert!(explanation.is_authorized());
+        assert_eq!(explanation.authority_type, AuthorityType::MetadataDelegate);
+
+        // the delegate expires at the expiry slot
+        let clock = Clock {
+            slot: 100,
+            ..Default::default()
+        };
+        let explanation = explain_authority(
+            &metadata,
+            None,
+            None,
+            &[(address, record)],
+            &delegate,
+            &operation,
+            &clock,
+        );
+
+        assert_eq!(explanation.authority_type, AuthorityType::None);
+        assert_eq!(
+            explanation.error,
+            Some(MplTokenMetadataError::InvalidAuthorityType)
+        );
+        assert_eq!(
+            explanation.rejected[1].reason,
+            "metadata delegate record of the signer has expired"
+        );
+    }
+
+    #[test]
+    fn transfer_with_expired_sale_delegate() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let metadata = metadata(TokenStandard::ProgrammableNonFungible);
+        let token = token(&metadata, owner, Some(delegate));
+        let mut token_record = token_record(Some(delegate), Some(TokenDelegateRole::Sale));
+        token_record.delegate_expiry = Some(DelegateExpiry::UnixTimestamp(1_000));
+
+        let clock = Clock {
+            unix_timestamp: 1_000,
+            ..Default::default()
+        };
+        let explanation = explain_authority(
+            &metadata,
+            Some(&token),
+            Some(&token_record),
+            &[],
+            &delegate,
+            &AuthorityOperation::Transfer { amount: 1 },
+            &clock,
+        );
+
+        assert_eq!(explanation.authority_type, AuthorityType::None);
+        assert_eq!(
+            explanation.rejected[0].authority_type,
+            AuthorityType::TokenDelegate
+        );
+        assert_eq!(explanation.rejected[0].reason, "token delegate has expired");
+
+        // the expired sale delegate no longer restricts the owner
+        let explanation = explain_authority(
+            &metadata,
+            Some(&token),
+            Some(&token_record),
+            &[],
+            &owner,
+            &AuthorityOperation::Transfer { amount: 1 },
+            &clock,
+        );
+
+        assert!(explanation.is_authorized());
+        assert_eq!(explanation.authority_type, AuthorityType::Holder);
+        assert_eq!(
+            explanation.transfer_scenario,
+            Some(TransferScenario::Holder)
+        );
+    }
+
+    #[test]
+    fn fail_to_transfer_as_update_authority() {
+        let update_authority = Pubkey::new_unique();
+        let metadata = Metadata {
+            update_authority,
+            ..metadata(TokenStandard::ProgrammableNonFungible)
+        };
+        let token = token(&metadata, Pubkey::new_unique(), None);
+        let token_record = token_record(None, None);
+
+        let explanation = explain_authority(
+            &metadata,
+            Some(&token),
+            Some(&token_record),
+            &[],
+            &update_authority,
+            &AuthorityOperation::Transfer { amount: 1 },
+            &Clock::default(),
+        );
+
+        // the update authority is identified but it cannot transfer a programmable asset
+        assert_eq!(explanation.authority_type, AuthorityType::Metadata);
+        assert_eq!(
+            explanation.error,
+            Some(MplTokenMetadataError::InvalidAuthorityType)
+        );
+        assert_eq!(explanation.rejected.len(), 3);
+    }
+
+    #[test]
+    fn read_token_account() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            amount: 10,
+            delegate: Some(delegate).into(),
+            state: spl_token::state::AccountState::Initialized,
+            delegated_amount: 5,
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account::pack(account, &mut data).unwrap();
+
+        let address = Pubkey::new_unique();
+        let token = TokenAccount::from_bytes(address, &data).unwrap();
+
+        assert_eq!(
+            token,
+            TokenAccount {
+                address,
+                mint,
+                owner,
+                amount: 10,
+                delegate: Some(delegate),
+                delegated_amount: 5,
+            }
+        );
+        assert_eq!(
+            TokenAccount::from_bytes(address, &data[..64]),
+            Err(MplTokenMetadataError::DataTypeMismatch)
+        );
+    }
+}
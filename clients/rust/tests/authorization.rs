@@ -0,0 +1,111 @@
+use mpl_token_metadata::{
+    authorization::AuthorizationDataBuilder,
+    errors::MplTokenMetadataError,
+    types::{PayloadKey, PayloadType, ProofInfo, SeedsVec},
+};
+use solana_pubkey::Pubkey;
+
+mod authorization {
+
+    use super::*;
+
+    #[test]
+    fn build_transfer_payload() {
+        let authority = Pubkey::new_unique();
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let data = AuthorizationDataBuilder::transfer(1, authority, source, destination).build();
+        let map = &data.payload.map;
+
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.get("Amount"), Some(&PayloadType::Number(1)));
+        assert_eq!(map.get("Authority"), Some(&PayloadType::Pubkey(authority)));
+        assert_eq!(map.get("Source"), Some(&PayloadType::Pubkey(source)));
+        assert_eq!(
+            map.get("Destination"),
+            Some(&PayloadType::Pubkey(destination))
+        );
+    }
+
+    #[test]
+    fn build_delegate_payload() {
+        let delegate = Pubkey::new_unique();
+
+        let data = AuthorizationDataBuilder::delegate(1, delegate).build();
+        let map = &data.payload.map;
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("Amount"), Some(&PayloadType::Number(1)));
+        assert_eq!(map.get("Delegate"), Some(&PayloadType::Pubkey(delegate)));
+    }
+
+    #[test]
+    fn build_program_owned_destination_payload() {
+        let program_id = Pubkey::new_unique();
+        let seeds = vec![b"vault".to_vec(), Pubkey::new_unique().to_bytes().to_vec()];
+        let path: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        let (destination, _) = Pubkey::find_program_address(&path, &program_id);
+
+        let data = AuthorizationDataBuilder::transfer(
+            1,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            destination,
+        )
+        .program_owned(
+            PayloadKey::Destination,
+            &destination,
+            &program_id,
+            seeds.clone(),
+        )
+        .unwrap()
+        .build();
+
+        assert_eq!(
+            data.payload.map.get("DestinationSeeds"),
+            Some(&PayloadType::Seeds(SeedsVec { seeds }))
+        );
+    }
+
+    #[test]
+    fn fail_program_owned_with_invalid_seeds() {
+        let program_id = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let error = AuthorizationDataBuilder::new()
+            .program_owned(
+                PayloadKey::Destination,
+                &destination,
+                &program_id,
+                vec![b"vault".to_vec()],
+            )
+            .unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::DerivedKeyInvalid);
+
+        // only account keys have seeds
+        let error = AuthorizationDataBuilder::new()
+            .program_owned(
+                PayloadKey::Amount,
+                &destination,
+                &program_id,
+                vec![b"vault".to_vec()],
+            )
+            .unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::InvalidOperation);
+    }
+
+    #[test]
+    fn build_merkle_proof_payload() {
+        let proof = vec![[1; 32], [2; 32]];
+
+        let data = AuthorizationDataBuilder::delegate(1, Pubkey::new_unique())
+            .merkle_proof("DelegateProof", proof.clone())
+            .build();
+
+        assert_eq!(
+            data.payload.map.get("DelegateProof"),
+            Some(&PayloadType::MerkleProof(ProofInfo { proof }))
+        );
+    }
+}
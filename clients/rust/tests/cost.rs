@@ -0,0 +1,157 @@
+use mpl_token_metadata::{
+    cost::{CostEstimator, CostItem, CostKind},
+    types::{Key, TokenStandard},
+    SPL_TOKEN_2022_PROGRAM_ID,
+};
+use solana_program::rent::Rent;
+
+/// Rent of an account with the specified data length using the default rent.
+fn rent(len: u64) -> u64 {
+    (128 + len) * 3480 * 2
+}
+
+mod cost {
+
+    use super::*;
+
+    #[test]
+    fn create_fee() {
+        let estimator = CostEstimator::new(Rent::default());
+        assert_eq!(estimator.create_fee(), 10_000_000);
+    }
+
+    #[test]
+    fn estimate_create_and_mint() {
+        let estimate = CostEstimator::new(Rent::default())
+            .create(TokenStandard::ProgrammableNonFungible, 2)
+            .mint(TokenStandard::ProgrammableNonFungible, 2)
+            .estimate();
+
+        assert_eq!(
+            estimate.charges,
+            vec![
+                CostItem {
+                    kind: CostKind::CreateFee,
+                    count: 2,
+                    lamports: 20_000_000,
+                },
+                CostItem {
+                    kind: CostKind::Metadata,
+                    count: 2,
+                    lamports: 2 * rent(607),
+                },
+                CostItem {
+                    kind: CostKind::Mint,
+                    count: 2,
+                    lamports: 2 * rent(82),
+                },
+                CostItem {
+                    kind: CostKind::TokenAccount,
+                    count: 2,
+                    lamports: 2 * rent(165),
+                },
+                CostItem {
+                    kind: CostKind::MasterEdition,
+                    count: 2,
+                    lamports: 2 * rent(20),
+                },
+                CostItem {
+                    kind: CostKind::TokenRecord,
+                    count: 2,
+                    lamports: 2 * rent(99),
+                },
+            ]
+        );
+        assert_eq!(
+            estimate.total_charges(),
+            20_000_000 + 2 * (rent(607) + rent(82) + rent(165) + rent(20) + rent(99))
+        );
+        assert!(estimate.refunds.is_empty());
+    }
+
+    #[test]
+    fn estimate_fungible_with_token_2022() {
+        let estimate = CostEstimator::new(Rent::default())
+            .spl_token_program(SPL_TOKEN_2022_PROGRAM_ID)
+            .create(TokenStandard::Fungible, 1)
+            .mint(TokenStandard::Fungible, 1)
+            .estimate();
+
+        assert_eq!(estimate.charge(CostKind::Mint), rent(270));
+        assert_eq!(estimate.charge(CostKind::TokenAccount), rent(170));
+        assert_eq!(estimate.charge(CostKind::MasterEdition), 0);
+        assert_eq!(estimate.charge(CostKind::TokenRecord), 0);
+    }
+
+    #[test]
+    fn estimate_print_edition_markers() {
+        // editions 1 to 248 span the first two markers
+        let estimate = CostEstimator::new(Rent::default())
+            .print(TokenStandard::NonFungible, 1, 248)
+            .estimate();
+
+        assert_eq!(estimate.charge(CostKind::Edition), 248 * rent(42));
+        assert_eq!(estimate.charge(CostKind::EditionMarker), 2 * rent(32));
+
+        // the marker of edition 2 was created when printing edition 1
+        let estimate = CostEstimator::new(Rent::default())
+            .print(TokenStandard::NonFungible, 2, 10)
+            .estimate();
+
+        assert_eq!(estimate.charge(CostKind::EditionMarker), 0);
+        assert_eq!(estimate.charge(CostKind::CreateFee), 10 * 10_000_000);
+    }
+
+    #[test]
+    fn estimate_print_programmable_edition_marker() {
+        // the ledger of editions 1 to 16 has 3 bytes
+        let estimate = CostEstimator::new(Rent::default())
+            .print(TokenStandard::ProgrammableNonFungible, 1, 16)
+            .estimate();
+
+        assert_eq!(estimate.charge(CostKind::EditionMarker), rent(1 + 4 + 3));
+        assert_eq!(estimate.charge(CostKind::TokenRecord), 16 * rent(99));
+
+        // the ledger grows by one byte
+        let estimate = CostEstimator::new(Rent::default())
+            .print(TokenStandard::ProgrammableNonFungible, 17, 8)
+            .estimate();
+
+        assert_eq!(
+            estimate.charge(CostKind::EditionMarker),
+            rent(1 + 4 + 4) - rent(1 + 4 + 3)
+        );
+    }
+
+    #[test]
+    fn estimate_resize_refunds() {
+        let estimate = CostEstimator::new(Rent::default())
+            .resize(679, Some((Key::MasterEditionV2, 282)))
+            .resize(679, Some((Key::EditionV1, 241)))
+            // already resized
+            .resize(607, None)
+            .estimate();
+
+        assert!(estimate.charges.is_empty());
+        assert_eq!(
+            estimate.refunds,
+            vec![
+                CostItem {
+                    kind: CostKind::Metadata,
+                    count: 2,
+                    lamports: 2 * (rent(679) - rent(607)),
+                },
+                CostItem {
+                    kind: CostKind::MasterEdition,
+                    count: 1,
+                    lamports: rent(282) - rent(20),
+                },
+                CostItem {
+                    kind: CostKind::Edition,
+                    count: 1,
+                    lamports: rent(241) - rent(42),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,262 @@
+pub mod setup;
+pub use setup::*;
+
+use mpl_token_metadata::{
+    accounts::{
+        EditionMarker, MasterEdition, MetadataAttributes, MetadataDelegateRecord, TokenRecord,
+    },
+    decoder::{
+        AccountDecoder, DecodedAccount, EventDecoder, MetadataEvent, UpdatedField,
+        VerificationTarget, EVENT_VERSION,
+    },
+    errors::MplTokenMetadataError,
+    types::{Attribute, Key, TokenStandard, TokenState},
+    ID,
+};
+use solana_pubkey::Pubkey;
+
+fn event_data(version: u8, event: &MetadataEvent) -> Vec<u8> {
+    let mut data = vec![version];
+    data.extend(borsh::to_vec(event).unwrap());
+    data
+}
+
+mod decoder {
+
+    use super::*;
+
+    #[test]
+    fn decode_metadata() {
+        let metadata = metadata(TokenStandard::ProgrammableNonFungible);
+        // metadata accounts are padded to their maximum length
+        let data = serialize(&metadata, 679);
+
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+
+        assert_eq!(decoded.key(), Key::MetadataV1);
+        assert_eq!(decoded, DecodedAccount::Metadata(metadata));
+    }
+
+    #[test]
+    fn decode_master_edition() {
+        let master_edition = MasterEdition {
+            key: Key::MasterEditionV2,
+            supply: 1,
+            max_supply: Some(10),
+        };
+        let data = serialize(&master_edition, 282);
+
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+
+        assert_eq!(decoded, DecodedAccount::MasterEdition(master_edition));
+    }
+
+    #[test]
+    fn decode_token_record() {
+        let token_record = TokenRecord {
+            key: Key::TokenRecord,
+            bump: 254,
+            state: TokenState::Locked,
+            rule_set_revision: None,
+            delegate: Some(Pubkey::new_unique()),
+            delegate_role: None,
+            locked_transfer: None,
+            delegate_expiry: None,
+            unlock_after: None,
+        };
+
+        // current token record size
+        let data = serialize(&token_record, TokenRecord::LEN);
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+        assert_eq!(decoded, DecodedAccount::TokenRecord(token_record.clone()));
+
+        // token record created before the unlock timestamp field was added
+        let data = serialize(&token_record, TokenRecord::LEN - 9);
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+        assert_eq!(decoded, DecodedAccount::TokenRecord(token_record.clone()));
+
+        // token record created before the delegate expiry field was added
+        let data = serialize(&token_record, TokenRecord::LEN - 9 - 10);
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+        assert_eq!(decoded, DecodedAccount::TokenRecord(token_record.clone()));
+
+        // token record created before the locked transfer field was added
+        let data = serialize(&token_record, TokenRecord::LEN - 9 - 10 - 33);
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+        assert_eq!(decoded, DecodedAccount::TokenRecord(token_record));
+    }
+
+    #[test]
+    fn decode_metadata_delegate_record() {
+        let delegate_record = MetadataDelegateRecord {
+            key: Key::MetadataDelegate,
+            bump: 255,
+            mint: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+            update_authority: Pubkey::new_unique(),
+            expiry: None,
+        };
+        let data = serialize(&delegate_record, MetadataDelegateRecord::LEN);
+
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+
+        assert_eq!(
+            decoded,
+            DecodedAccount::MetadataDelegateRecord(delegate_record.clone())
+        );
+
+        // delegate record created before the expiry field was added
+        let data = serialize(&delegate_record, MetadataDelegateRecord::LEN - 10);
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+        assert_eq!(
+            decoded,
+            DecodedAccount::MetadataDelegateRecord(delegate_record)
+        );
+    }
+
+    #[test]
+    fn decode_metadata_attributes() {
+        let mint = Pubkey::new_unique();
+        let (_, bump) = MetadataAttributes::find_pda(&mint);
+
+        let attributes = MetadataAttributes {
+            key: Key::MetadataAttributes,
+            bump,
+            mint,
+            attributes: vec![
+                Attribute {
+                    key: "level".to_string(),
+                    value: "7".to_string(),
+                },
+                Attribute {
+                    key: "class".to_string(),
+                    value: "mage".to_string(),
+                },
+            ],
+        };
+        // the account is sized to the serialized attributes
+        let data = borsh::to_vec(&attributes).unwrap();
+
+        let decoded = AccountDecoder::decode(&ID, &data).unwrap();
+
+        match decoded {
+            DecodedAccount::MetadataAttributes(decoded) => {
+                assert_eq!(decoded.get("level"), Some("7"));
+                assert_eq!(decoded.get("class"), Some("mage"));
+                assert_eq!(decoded.get("rarity"), None);
+                assert_eq!(decoded, attributes);
+            }
+            _ => panic!("Unexpected account type"),
+        }
+    }
+
+    #[test]
+    fn fail_to_decode_with_wrong_owner() {
+        let data = serialize(&metadata(TokenStandard::ProgrammableNonFungible), 679);
+
+        let error = AccountDecoder::decode(&Pubkey::new_unique(), &data).unwrap_err();
+
+        assert_eq!(error, MplTokenMetadataError::IncorrectOwner);
+    }
+
+    #[test]
+    fn fail_to_decode_with_invalid_length() {
+        let edition_marker = EditionMarker {
+            key: Key::EditionMarker,
+            ledger: [0; 31],
+        };
+        let data = serialize(&edition_marker, EditionMarker::LEN + 1);
+
+        let error = AccountDecoder::decode(&ID, &data).unwrap_err();
+
+        assert_eq!(error, MplTokenMetadataError::DataTypeMismatch);
+    }
+
+    #[test]
+    fn fail_to_decode_uninitialized() {
+        let error = AccountDecoder::decode(&ID, &[0; 32]).unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::Uninitialized);
+
+        let error = AccountDecoder::decode(&ID, &[]).unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::DataIsEmptyOrZeroed);
+    }
+
+    #[test]
+    fn decode_all_accounts() {
+        let metadata = metadata(TokenStandard::ProgrammableNonFungible);
+        let metadata_address = Pubkey::new_unique();
+        let metadata_data = serialize(&metadata, 679);
+
+        let other_address = Pubkey::new_unique();
+        let other_owner = Pubkey::new_unique();
+
+        let decoded = AccountDecoder::decode_all([
+            (&metadata_address, &ID, metadata_data.as_slice()),
+            (&other_address, &other_owner, metadata_data.as_slice()),
+        ]);
+
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(
+            decoded[0],
+            (metadata_address, Ok(DecodedAccount::Metadata(metadata)))
+        );
+        assert_eq!(
+            decoded[1],
+            (other_address, Err(MplTokenMetadataError::IncorrectOwner))
+        );
+    }
+
+    #[test]
+    fn decode_events() {
+        let mint = Pubkey::new_unique();
+
+        let created = MetadataEvent::Created {
+            mint,
+            update_authority: Pubkey::new_unique(),
+            token_standard: Some(TokenStandard::ProgrammableNonFungible),
+        };
+        let updated = MetadataEvent::Updated {
+            mint,
+            fields: vec![UpdatedField::Data, UpdatedField::IsMutable],
+        };
+        let verified = MetadataEvent::Verified {
+            mint,
+            target: VerificationTarget::Collection(Pubkey::new_unique()),
+        };
+
+        for event in [created, updated, verified] {
+            let data = event_data(EVENT_VERSION, &event);
+            assert_eq!(EventDecoder::decode(&data).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn fail_to_decode_unsupported_event_version() {
+        let event = MetadataEvent::Burned {
+            mint: Pubkey::new_unique(),
+            amount: 1,
+        };
+        let data = event_data(EVENT_VERSION + 1, &event);
+
+        let error = EventDecoder::decode(&data).unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::DataTypeMismatch);
+
+        let error = EventDecoder::decode(&[]).unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::DataIsEmptyOrZeroed);
+
+        let error = EventDecoder::decode(&[EVENT_VERSION, 255]).unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::BorshDeserializationError);
+    }
+
+    #[test]
+    fn decode_all_events() {
+        let event = MetadataEvent::Locked {
+            mint: Pubkey::new_unique(),
+            token: Pubkey::new_unique(),
+        };
+        let data = event_data(EVENT_VERSION, &event);
+
+        let decoded = EventDecoder::decode_all([data.as_slice(), &[0, 1, 2]]);
+        assert_eq!(decoded, vec![event]);
+    }
+}
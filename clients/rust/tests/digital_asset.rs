@@ -0,0 +1,201 @@
+pub mod setup;
+pub use setup::*;
+
+use mpl_token_metadata::{
+    accounts::{
+        EditionMarker, EditionMarkerV2, MasterEdition, Metadata, MetadataDelegateRecord,
+        TokenRecord,
+    },
+    digital_asset::{DigitalAssetAccounts, DigitalAssetAccountsBuilder, DigitalAssetEdition},
+    errors::MplTokenMetadataError,
+    types::{Key, MetadataDelegateRole, TokenStandard, TokenState},
+    ID,
+};
+use solana_pubkey::Pubkey;
+
+mod digital_asset {
+
+    use super::*;
+
+    #[test]
+    fn resolve_programmable_accounts() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let accounts = DigitalAssetAccountsBuilder::new()
+            .mint(mint)
+            .token_owner(owner)
+            .token_standard(TokenStandard::ProgrammableNonFungible)
+            .build()
+            .unwrap();
+
+        let token = spl_associated_token_account::get_associated_token_address(&owner, &mint);
+
+        assert_eq!(accounts.metadata, Metadata::find_pda(&mint).0);
+        assert_eq!(accounts.edition, MasterEdition::find_pda(&mint).0);
+        assert_eq!(accounts.token, Some(token));
+        assert_eq!(
+            accounts.token_record,
+            Some(TokenRecord::find_pda(&mint, &token).0)
+        );
+        assert_eq!(accounts.edition_marker, None);
+    }
+
+    #[test]
+    fn resolve_non_programmable_accounts() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let accounts = DigitalAssetAccountsBuilder::new()
+            .mint(mint)
+            .token_owner(owner)
+            .spl_token_program(spl_token_2022_interface::ID)
+            .token_standard(TokenStandard::NonFungible)
+            .build()
+            .unwrap();
+
+        let token = spl_associated_token_account::get_associated_token_address_with_program_id(
+            &owner,
+            &mint,
+            &spl_token_2022_interface::ID,
+        );
+
+        assert_eq!(accounts.token, Some(token));
+        assert_eq!(accounts.token_record, None);
+    }
+
+    #[test]
+    fn resolve_print_edition_markers() {
+        let mint = Pubkey::new_unique();
+        let parent_mint = Pubkey::new_unique();
+
+        let accounts = DigitalAssetAccountsBuilder::new()
+            .mint(mint)
+            .parent_mint(parent_mint)
+            .edition_number(500)
+            .token_standard(TokenStandard::NonFungibleEdition)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            accounts.edition_marker,
+            Some(EditionMarker::find_pda(&parent_mint, "2".into()).0)
+        );
+
+        let accounts = DigitalAssetAccountsBuilder::new()
+            .mint(mint)
+            .parent_mint(parent_mint)
+            .edition_number(500)
+            .token_standard(TokenStandard::ProgrammableNonFungibleEdition)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            accounts.edition_marker,
+            Some(EditionMarkerV2::find_pda(&parent_mint).0)
+        );
+    }
+
+    #[test]
+    fn resolve_delegate_records() {
+        let mint = Pubkey::new_unique();
+        let update_authority = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let accounts = DigitalAssetAccountsBuilder::new()
+            .mint(mint)
+            .metadata_delegate(MetadataDelegateRole::Data, update_authority, delegate)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            accounts.metadata_delegate_records,
+            vec![
+                MetadataDelegateRecord::find_pda(
+                    &mint,
+                    MetadataDelegateRole::Data,
+                    &update_authority,
+                    &delegate
+                )
+                .0
+            ]
+        );
+    }
+
+    #[test]
+    fn assemble_digital_asset() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let accounts = DigitalAssetAccountsBuilder::new()
+            .mint(mint)
+            .token_owner(owner)
+            .build()
+            .unwrap();
+
+        let metadata = Metadata {
+            mint,
+            ..metadata(TokenStandard::ProgrammableNonFungible)
+        };
+        let metadata_data = borsh::to_vec(&metadata).unwrap();
+
+        let master_edition = MasterEdition {
+            key: Key::MasterEditionV2,
+            supply: 0,
+            max_supply: Some(0),
+        };
+        let master_edition_data = borsh::to_vec(&master_edition).unwrap();
+
+        let token_record = TokenRecord {
+            key: Key::TokenRecord,
+            bump: 255,
+            state: TokenState::Unlocked,
+            rule_set_revision: None,
+            delegate: None,
+            delegate_role: None,
+            locked_transfer: None,
+            delegate_expiry: None,
+            unlock_after: None,
+        };
+        let mut token_record_data = borsh::to_vec(&token_record).unwrap();
+        token_record_data.resize(TokenRecord::LEN, 0);
+
+        let token_record_address = accounts.token_record.unwrap();
+        let unrelated = Pubkey::new_unique();
+
+        let asset = accounts
+            .assemble([
+                (&accounts.metadata, &ID, metadata_data.as_slice()),
+                (&accounts.edition, &ID, master_edition_data.as_slice()),
+                (&token_record_address, &ID, token_record_data.as_slice()),
+                (&unrelated, &ID, metadata_data.as_slice()),
+            ])
+            .unwrap();
+
+        assert_eq!(asset.metadata, metadata);
+        assert_eq!(
+            asset.edition,
+            Some(DigitalAssetEdition::Master(master_edition))
+        );
+        assert_eq!(asset.token_record, Some(token_record));
+    }
+
+    #[test]
+    fn fail_to_assemble_without_metadata() {
+        let accounts = DigitalAssetAccounts::find(&Pubkey::new_unique());
+
+        let error = accounts.assemble([]).unwrap_err();
+
+        assert_eq!(error, MplTokenMetadataError::DataIsEmptyOrZeroed);
+    }
+
+    #[test]
+    fn fail_to_resolve_without_mint() {
+        let error = DigitalAssetAccountsBuilder::new()
+            .token_owner(Pubkey::new_unique())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(error, MplTokenMetadataError::MissingAccountInBuilder);
+    }
+}
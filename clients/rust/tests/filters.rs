@@ -0,0 +1,203 @@
+pub mod setup;
+pub use setup::*;
+
+use mpl_token_metadata::{
+    accounts::{Metadata, TokenRecord},
+    filters::{
+        key, master_editions_by_token_standard, metadata_by_first_verified_creator,
+        metadata_by_mint, metadata_by_token_standard, metadata_by_update_authority,
+        token_records_by_delegate, token_records_by_state, AccountFilter,
+    },
+    types::{Creator, Key, TokenDelegateRole, TokenStandard, TokenState},
+    MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+use solana_pubkey::Pubkey;
+
+/// Pads the string to the specified length, as the program does.
+fn puffed(value: &str, length: usize) -> String {
+    format!("{value:\0<length$}")
+}
+
+/// Metadata fixture with the padded strings stored by the program.
+fn puffed_metadata(creators: Option<Vec<Creator>>) -> Metadata {
+    Metadata {
+        name: puffed("Digital Asset", MAX_NAME_LENGTH),
+        symbol: puffed("DA", MAX_SYMBOL_LENGTH),
+        uri: puffed("https://digital.asset.org", MAX_URI_LENGTH),
+        creators,
+        ..metadata(TokenStandard::ProgrammableNonFungible)
+    }
+}
+
+fn token_record(rule_set_revision: Option<u64>, delegate: Option<Pubkey>) -> TokenRecord {
+    TokenRecord {
+        key: Key::TokenRecord,
+        bump: 255,
+        state: TokenState::Locked,
+        rule_set_revision,
+        delegate,
+        delegate_role: Some(TokenDelegateRole::Utility),
+        locked_transfer: None,
+        delegate_expiry: None,
+        unlock_after: None,
+    }
+}
+
+fn matches(filters: &[AccountFilter], data: &[u8]) -> bool {
+    filters.iter().all(|filter| filter.matches(data))
+}
+
+mod filters {
+
+    use super::*;
+
+    #[test]
+    fn filter_metadata_by_update_authority_and_mint() {
+        let metadata = puffed_metadata(None);
+        let data = serialize(&metadata, 607);
+
+        assert!(matches(
+            &metadata_by_update_authority(&metadata.update_authority),
+            &data
+        ));
+        assert!(!matches(
+            &metadata_by_update_authority(&Pubkey::new_unique()),
+            &data
+        ));
+
+        assert!(matches(&metadata_by_mint(&metadata.mint), &data));
+        assert!(!matches(
+            &metadata_by_mint(&metadata.update_authority),
+            &data
+        ));
+    }
+
+    #[test]
+    fn filter_metadata_by_first_verified_creator() {
+        let creator = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        let verified = puffed_metadata(Some(vec![
+            Creator {
+                address: creator,
+                verified: true,
+                share: 50,
+            },
+            Creator {
+                address: other,
+                verified: true,
+                share: 50,
+            },
+        ]));
+        let data = serialize(&verified, 607);
+
+        assert!(matches(
+            &metadata_by_first_verified_creator(&creator),
+            &data
+        ));
+        // only the first creator is matched
+        assert!(!matches(&metadata_by_first_verified_creator(&other), &data));
+
+        let unverified = puffed_metadata(Some(vec![Creator {
+            address: creator,
+            verified: false,
+            share: 100,
+        }]));
+        let data = serialize(&unverified, 607);
+
+        assert!(!matches(
+            &metadata_by_first_verified_creator(&creator),
+            &data
+        ));
+    }
+
+    #[test]
+    fn filter_metadata_by_token_standard() {
+        let without_creators = serialize(&puffed_metadata(None), 607);
+
+        assert!(matches(
+            &metadata_by_token_standard(TokenStandard::ProgrammableNonFungible, 0),
+            &without_creators
+        ));
+        assert!(!matches(
+            &metadata_by_token_standard(TokenStandard::NonFungible, 0),
+            &without_creators
+        ));
+
+        let creators = (0..3)
+            .map(|_| Creator {
+                address: Pubkey::new_unique(),
+                verified: false,
+                share: 33,
+            })
+            .collect();
+        let with_creators = serialize(&puffed_metadata(Some(creators)), 607);
+
+        assert!(matches(
+            &metadata_by_token_standard(TokenStandard::ProgrammableNonFungible, 3),
+            &with_creators
+        ));
+        // the number of creators determines the offset
+        assert!(!matches(
+            &metadata_by_token_standard(TokenStandard::ProgrammableNonFungible, 2),
+            &with_creators
+        ));
+        assert!(!matches(
+            &metadata_by_token_standard(TokenStandard::ProgrammableNonFungible, 0),
+            &with_creators
+        ));
+    }
+
+    #[test]
+    fn filter_token_records() {
+        let delegate = Pubkey::new_unique();
+        let [without_revision, with_revision] = token_records_by_delegate(&delegate);
+
+        let data = serialize(&token_record(None, Some(delegate)), TokenRecord::LEN);
+        assert!(matches(&without_revision, &data));
+        assert!(!matches(&with_revision, &data));
+        assert!(matches(&token_records_by_state(TokenState::Locked), &data));
+        assert!(!matches(
+            &token_records_by_state(TokenState::Unlocked),
+            &data
+        ));
+
+        let data = serialize(&token_record(Some(1), Some(delegate)), TokenRecord::LEN);
+        assert!(!matches(&without_revision, &data));
+        assert!(matches(&with_revision, &data));
+
+        let data = serialize(&token_record(Some(1), None), TokenRecord::LEN);
+        assert!(!matches(&without_revision, &data));
+        assert!(!matches(&with_revision, &data));
+
+        // metadata accounts are not token records
+        let data = serialize(&puffed_metadata(None), 607);
+        assert!(!key(Key::TokenRecord).matches(&data));
+    }
+
+    #[test]
+    fn filter_master_editions_by_token_standard() {
+        // key, supply, max supply (none), fee flag and token standard
+        let mut data = vec![Key::MasterEditionV2 as u8];
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.push(0);
+        data.resize(20, 0);
+        data[19] = TokenStandard::ProgrammableNonFungible as u8;
+
+        assert!(matches(
+            &master_editions_by_token_standard(TokenStandard::ProgrammableNonFungible),
+            &data
+        ));
+        assert!(!matches(
+            &master_editions_by_token_standard(TokenStandard::NonFungible),
+            &data
+        ));
+
+        // master editions that were not resized
+        data.resize(282, 0);
+        assert!(!matches(
+            &master_editions_by_token_standard(TokenStandard::ProgrammableNonFungible),
+            &data
+        ));
+    }
+}
@@ -0,0 +1,374 @@
+pub mod setup;
+pub use setup::*;
+
+use mpl_token_metadata::{
+    accounts::{EditionMarker, MasterEdition, Metadata, MetadataDelegateRecord, TokenRecord},
+    errors::MplTokenMetadataError,
+    instructions::CreateV1InstructionArgs,
+    operations::{
+        AssetOperations, BurnParams, CreateParams, DelegateParams, PrintEditionParent,
+        RevokeParams, TransferParams, COMPUTE_BUDGET_PROGRAM_ID, DEFAULT_COMPUTE_UNITS,
+        MPL_TOKEN_AUTH_RULES_PROGRAM_ID, PROGRAMMABLE_COMPUTE_UNITS,
+    },
+    types::{
+        Collection, DelegateArgs, DelegateExpiry, MetadataDelegateRole, ProgrammableConfig,
+        RevokeArgs, TokenStandard,
+    },
+    ID,
+};
+use solana_program::instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+fn contains(instruction: &Instruction, address: &Pubkey) -> bool {
+    instruction
+        .accounts
+        .iter()
+        .any(|meta| meta.pubkey == *address)
+}
+
+mod operations {
+
+    use super::*;
+
+    #[test]
+    fn plan_create_and_mint() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let plan = AssetOperations::create(CreateParams {
+            mint,
+            create_mint: true,
+            authority: owner,
+            payer: owner,
+            update_authority: owner,
+            update_authority_signer: true,
+            spl_token_program: None,
+            args: CreateV1InstructionArgs {
+                name: String::from("Digital Asset"),
+                symbol: String::from("DA"),
+                uri: String::from("https://digital.asset.org"),
+                seller_fee_basis_points: 500,
+                creators: None,
+                primary_sale_happened: false,
+                is_mutable: true,
+                token_standard: TokenStandard::ProgrammableNonFungible,
+                collection: None,
+                uses: None,
+                collection_details: None,
+                rule_set: None,
+                decimals: Some(0),
+                print_supply: None,
+            },
+            mint_to: Some((owner, 1)),
+        });
+
+        assert_eq!(plan.instructions.len(), 2);
+        assert_eq!(plan.compute_units, 2 * PROGRAMMABLE_COMPUTE_UNITS);
+        assert!(plan.instructions.iter().all(|ix| ix.program_id == ID));
+
+        let master_edition = MasterEdition::find_pda(&mint).0;
+        assert!(contains(&plan.instructions[0], &master_edition));
+
+        let token = spl_associated_token_account::get_associated_token_address(&owner, &mint);
+        assert!(contains(
+            &plan.instructions[1],
+            &TokenRecord::find_pda(&mint, &token).0
+        ));
+
+        let instructions = plan.with_compute_budget();
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(instructions[0].program_id, COMPUTE_BUDGET_PROGRAM_ID);
+    }
+
+    #[test]
+    fn plan_fungible_create_and_mint() {
+        let mint = Pubkey::new_unique();
+        let mint_authority = Pubkey::new_unique();
+        let update_authority = Pubkey::new_unique();
+
+        let plan = AssetOperations::create(CreateParams {
+            mint,
+            create_mint: true,
+            authority: mint_authority,
+            payer: mint_authority,
+            update_authority,
+            update_authority_signer: false,
+            spl_token_program: None,
+            args: CreateV1InstructionArgs {
+                name: String::from("Fungible"),
+                symbol: String::from("FNG"),
+                uri: String::from("https://fungible.org"),
+                seller_fee_basis_points: 0,
+                creators: None,
+                primary_sale_happened: false,
+                is_mutable: true,
+                token_standard: TokenStandard::Fungible,
+                collection: None,
+                uses: None,
+                collection_details: None,
+                rule_set: None,
+                decimals: Some(9),
+                print_supply: None,
+            },
+            mint_to: Some((mint_authority, 1_000)),
+        });
+
+        assert_eq!(plan.instructions.len(), 2);
+
+        // the update authority does not need to sign the create
+        let update_authority_meta = &plan.instructions[0].accounts[5];
+        assert_eq!(update_authority_meta.pubkey, update_authority);
+        assert!(!update_authority_meta.is_signer);
+
+        // fungibles are minted by the mint authority
+        let authority_meta = &plan.instructions[1].accounts[6];
+        assert_eq!(authority_meta.pubkey, mint_authority);
+        assert!(authority_meta.is_signer);
+        assert!(!contains(&plan.instructions[1], &update_authority));
+    }
+
+    #[test]
+    fn plan_programmable_transfer() {
+        let rule_set = Pubkey::new_unique();
+        let mut metadata = metadata(TokenStandard::ProgrammableNonFungible);
+        metadata.programmable_config = Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        });
+
+        let owner = Pubkey::new_unique();
+        let destination_owner = Pubkey::new_unique();
+
+        let plan = AssetOperations::new(&metadata)
+            .unwrap()
+            .transfer(TransferParams {
+                token_owner: owner,
+                token: None,
+                destination_owner,
+                destination_token: None,
+                authority: None,
+                payer: owner,
+                amount: 1,
+                authorization_data: None,
+            });
+
+        let instruction = &plan.instructions[0];
+        let destination_token = spl_associated_token_account::get_associated_token_address(
+            &destination_owner,
+            &metadata.mint,
+        );
+
+        assert!(contains(
+            instruction,
+            &TokenRecord::find_pda(&metadata.mint, &destination_token).0
+        ));
+        assert!(contains(instruction, &rule_set));
+        assert!(contains(instruction, &MPL_TOKEN_AUTH_RULES_PROGRAM_ID));
+    }
+
+    #[test]
+    fn plan_fungible_transfer() {
+        let metadata = metadata(TokenStandard::Fungible);
+        let owner = Pubkey::new_unique();
+
+        let plan = AssetOperations::new(&metadata)
+            .unwrap()
+            .transfer(TransferParams {
+                token_owner: owner,
+                token: None,
+                destination_owner: Pubkey::new_unique(),
+                destination_token: None,
+                authority: None,
+                payer: owner,
+                amount: 100,
+                authorization_data: None,
+            });
+
+        assert_eq!(plan.compute_units, DEFAULT_COMPUTE_UNITS);
+        assert!(!contains(
+            &plan.instructions[0],
+            &MasterEdition::find_pda(&metadata.mint).0
+        ));
+    }
+
+    #[test]
+    fn plan_print_edition_burn() {
+        let mut metadata = metadata(TokenStandard::NonFungibleEdition);
+        let collection = Pubkey::new_unique();
+        metadata.collection = Some(Collection {
+            verified: true,
+            key: collection,
+        });
+
+        let operations = AssetOperations::new(&metadata).unwrap();
+        let owner = Pubkey::new_unique();
+        let parent_mint = Pubkey::new_unique();
+
+        let error = operations
+            .burn(BurnParams {
+                authority: owner,
+                token_owner: owner,
+                token: None,
+                amount: 1,
+                parent: None,
+            })
+            .unwrap_err();
+        assert_eq!(error, MplTokenMetadataError::MissingMasterEditionAccount);
+
+        let plan = operations
+            .burn(BurnParams {
+                authority: owner,
+                token_owner: owner,
+                token: None,
+                amount: 1,
+                parent: Some(PrintEditionParent {
+                    mint: parent_mint,
+                    token: Pubkey::new_unique(),
+                    edition_number: 300,
+                }),
+            })
+            .unwrap();
+
+        let instruction = &plan.instructions[0];
+        assert!(contains(
+            instruction,
+            &EditionMarker::find_pda(&parent_mint, "1".into()).0
+        ));
+        assert!(contains(instruction, &Metadata::find_pda(&collection).0));
+    }
+
+    #[test]
+    fn plan_programmable_burn() {
+        let rule_set = Pubkey::new_unique();
+        let mut metadata = metadata(TokenStandard::ProgrammableNonFungible);
+        metadata.programmable_config = Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        });
+
+        let operations = AssetOperations::new(&metadata).unwrap();
+        let owner = Pubkey::new_unique();
+
+        let plan = operations
+            .burn(BurnParams {
+                authority: owner,
+                token_owner: owner,
+                token: None,
+                amount: 1,
+                parent: None,
+            })
+            .unwrap();
+
+        // the rule set accounts are appended after the declared accounts
+        let instruction = &plan.instructions[0];
+        assert_eq!(
+            instruction.accounts[14].pubkey,
+            MPL_TOKEN_AUTH_RULES_PROGRAM_ID
+        );
+        assert_eq!(instruction.accounts[15].pubkey, rule_set);
+    }
+
+    #[test]
+    fn plan_metadata_delegate_and_revoke() {
+        let metadata = metadata(TokenStandard::NonFungible);
+        let operations = AssetOperations::new(&metadata).unwrap();
+        let delegate = Pubkey::new_unique();
+
+        let delegate_record = MetadataDelegateRecord::find_pda(
+            &metadata.mint,
+            MetadataDelegateRole::Data,
+            &metadata.update_authority,
+            &delegate,
+        )
+        .0;
+
+        let plan = operations
+            .delegate(DelegateParams {
+                delegate,
+                authority: metadata.update_authority,
+                payer: metadata.update_authority,
+                token: None,
+                args: DelegateArgs::DataV1 {
+                    authorization_data: None,
+                },
+            })
+            .unwrap();
+        assert!(contains(&plan.instructions[0], &delegate_record));
+
+        // the delegate revoking itself still uses the update authority derivation
+        let plan = operations
+            .revoke(RevokeParams {
+                delegate,
+                authority: delegate,
+                payer: delegate,
+                token_owner: None,
+                token: None,
+                args: RevokeArgs::DataV1,
+            })
+            .unwrap();
+        assert!(contains(&plan.instructions[0], &delegate_record));
+    }
+
+    #[test]
+    fn plan_expiring_metadata_delegate() {
+        let metadata = metadata(TokenStandard::NonFungible);
+        let delegate = Pubkey::new_unique();
+
+        let delegate_record = MetadataDelegateRecord::find_pda(
+            &metadata.mint,
+            MetadataDelegateRole::Data,
+            &metadata.update_authority,
+            &delegate,
+        )
+        .0;
+
+        let plan = AssetOperations::new(&metadata)
+            .unwrap()
+            .delegate(DelegateParams {
+                delegate,
+                authority: metadata.update_authority,
+                payer: metadata.update_authority,
+                token: None,
+                args: DelegateArgs::DataV2 {
+                    expiry: Some(DelegateExpiry::Slot(100)),
+                    authorization_data: None,
+                },
+            })
+            .unwrap();
+
+        let instruction = &plan.instructions[0];
+        assert!(contains(instruction, &delegate_record));
+        // delegate discriminator followed by the DataV2 variant
+        assert_eq!(instruction.data[..2], [44, 17]);
+    }
+
+    #[test]
+    fn fail_to_plan_invalid_token_delegate() {
+        let metadata = metadata(TokenStandard::NonFungible);
+        let owner = Pubkey::new_unique();
+
+        let error = AssetOperations::new(&metadata)
+            .unwrap()
+            .delegate(DelegateParams {
+                delegate: Pubkey::new_unique(),
+                authority: owner,
+                payer: owner,
+                token: None,
+                args: DelegateArgs::TransferV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+            })
+            .unwrap_err();
+
+        assert_eq!(error, MplTokenMetadataError::InvalidDelegateRole);
+    }
+
+    #[test]
+    fn fail_without_token_standard() {
+        let mut metadata = metadata(TokenStandard::NonFungible);
+        metadata.token_standard = None;
+
+        let error = AssetOperations::new(&metadata).unwrap_err();
+
+        assert_eq!(error, MplTokenMetadataError::CouldNotDetermineTokenStandard);
+    }
+}
@@ -0,0 +1,35 @@
+use borsh::BorshSerialize;
+use mpl_token_metadata::{
+    accounts::Metadata,
+    types::{Key, TokenStandard},
+};
+use solana_program::pubkey::Pubkey;
+
+/// Returns a metadata account with unique addresses for the given token standard.
+pub fn metadata(token_standard: TokenStandard) -> Metadata {
+    Metadata {
+        key: Key::MetadataV1,
+        update_authority: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        name: String::from("Digital Asset"),
+        symbol: String::from("DA"),
+        uri: String::from("https://digital.asset.org"),
+        seller_fee_basis_points: 500,
+        creators: None,
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: Some(255),
+        token_standard: Some(token_standard),
+        collection: None,
+        uses: None,
+        collection_details: None,
+        programmable_config: None,
+    }
+}
+
+/// Serializes the account, padding the data to the given size.
+pub fn serialize<T: BorshSerialize>(account: &T, size: usize) -> Vec<u8> {
+    let mut data = borsh::to_vec(account).unwrap();
+    data.resize(size, 0);
+    data
+}
@@ -1,9 +1,11 @@
 mod digital_asset;
 mod dirty_clone;
+mod fixtures;
 mod token_manager;
 
 pub use digital_asset::*;
 pub use dirty_clone::*;
+pub use fixtures::*;
 use spl_token_2022_interface::extension::{BaseState, StateWithExtensions};
 pub use token_manager::*;
 
@@ -0,0 +1,278 @@
+// #[cfg(feature = "test-sbf")]
+mod setup;
+pub use setup::*;
+
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata, UseAuthorityRecord},
+    instructions::{
+        ApproveUseAuthorityBuilder, CreateV1Builder, RevokeUseAuthorityBuilder, UtilizeBuilder,
+    },
+    types::{PrintSupply, TokenStandard, UseMethod, Uses},
+};
+use solana_program_test::*;
+use solana_pubkey::Pubkey;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_token_2022_interface::state::Account;
+
+async fn create_with_uses(
+    asset: &mut DigitalAsset,
+    context: &mut ProgramTestContext,
+    token_standard: TokenStandard,
+    uses: Uses,
+    spl_token_program: Pubkey,
+) {
+    let mint_pubkey = asset.mint.pubkey();
+    let payer_pubkey = context.payer.pubkey();
+
+    asset.metadata = Metadata::find_pda(&mint_pubkey).0;
+    asset.master_edition = MasterEdition::find_pda(&mint_pubkey).0;
+
+    let create_ix = CreateV1Builder::new()
+        .metadata(asset.metadata)
+        .master_edition(Some(asset.master_edition))
+        .mint(mint_pubkey, true)
+        .authority(payer_pubkey)
+        .payer(payer_pubkey)
+        .update_authority(payer_pubkey, true)
+        .is_mutable(true)
+        .primary_sale_happened(false)
+        .name(String::from("DigitalAsset"))
+        .uri(String::from("http://digital.asset"))
+        .seller_fee_basis_points(500)
+        .token_standard(token_standard)
+        .print_supply(PrintSupply::Zero)
+        .uses(uses)
+        .spl_token_program(Some(spl_token_program))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset.mint],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+mod utilize {
+
+    use super::*;
+
+    #[test_case::test_case(TokenStandard::Fungible, spl_token::ID ; "fungible with spl-token")]
+    #[test_case::test_case(TokenStandard::Fungible, spl_token_2022_interface::ID ; "fungible with spl-token-2022")]
+    #[test_case::test_case(TokenStandard::FungibleAsset, spl_token::ID ; "fungible_asset with spl-token")]
+    #[test_case::test_case(TokenStandard::FungibleAsset, spl_token_2022_interface::ID ; "fungible_asset with spl-token-2022")]
+    #[test_case::test_case(TokenStandard::NonFungible, spl_token::ID ; "non_fungible with spl-token")]
+    #[test_case::test_case(TokenStandard::NonFungible, spl_token_2022_interface::ID ; "non_fungible with spl-token-2022")]
+    #[tokio::test]
+    async fn utilize_and_burn(token_standard: TokenStandard, spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        // given an asset with a single burn use
+
+        let mut asset = DigitalAsset::default();
+        create_with_uses(
+            &mut asset,
+            &mut context,
+            token_standard,
+            Uses {
+                use_method: UseMethod::Burn,
+                remaining: 1,
+                total: 1,
+            },
+            spl_token_program,
+        )
+        .await;
+
+        let payer = context.payer.dirty_clone();
+
+        asset
+            .mint(
+                &mut context,
+                &payer.pubkey(),
+                1,
+                &payer,
+                &payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // when the owner utilizes the asset
+
+        let utilize_ix = UtilizeBuilder::new()
+            .metadata(asset.metadata)
+            .token_account(asset.token)
+            .mint(asset.mint.pubkey())
+            .use_authority(payer.pubkey())
+            .owner(payer.pubkey())
+            .token_program(spl_token_program)
+            .number_of_uses(1)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[utilize_ix],
+            Some(&payer.pubkey()),
+            &[&payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // then the uses are consumed and the token is burned through the same program
+
+        let metadata_account = get_account(&mut context, &asset.metadata).await;
+        let metadata = Metadata::from_bytes(&metadata_account.data).unwrap();
+        assert_eq!(metadata.uses.unwrap().remaining, 0);
+
+        let token_account = get_account(&mut context, &asset.token).await;
+        assert_eq!(token_account.owner, spl_token_program);
+
+        let token = unpack::<Account>(&token_account.data).unwrap().base;
+        assert_eq!(token.amount, 0);
+    }
+
+    #[test_case::test_case(TokenStandard::Fungible, spl_token::ID ; "fungible with spl-token")]
+    #[test_case::test_case(TokenStandard::Fungible, spl_token_2022_interface::ID ; "fungible with spl-token-2022")]
+    #[test_case::test_case(TokenStandard::FungibleAsset, spl_token::ID ; "fungible_asset with spl-token")]
+    #[test_case::test_case(TokenStandard::FungibleAsset, spl_token_2022_interface::ID ; "fungible_asset with spl-token-2022")]
+    #[test_case::test_case(TokenStandard::NonFungible, spl_token::ID ; "non_fungible with spl-token")]
+    #[test_case::test_case(TokenStandard::NonFungible, spl_token_2022_interface::ID ; "non_fungible with spl-token-2022")]
+    #[tokio::test]
+    async fn utilize_with_use_authority(token_standard: TokenStandard, spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        // given an asset with multiple burn uses
+
+        let mut asset = DigitalAsset::default();
+        create_with_uses(
+            &mut asset,
+            &mut context,
+            token_standard,
+            Uses {
+                use_method: UseMethod::Burn,
+                remaining: 5,
+                total: 5,
+            },
+            spl_token_program,
+        )
+        .await;
+
+        let payer = context.payer.dirty_clone();
+
+        asset
+            .mint(
+                &mut context,
+                &payer.pubkey(),
+                1,
+                &payer,
+                &payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // and an approved use authority
+
+        let user = Keypair::new();
+        let use_authority_record =
+            UseAuthorityRecord::find_pda(&asset.mint.pubkey(), &user.pubkey()).0;
+        let burner = Pubkey::find_program_address(
+            &[
+                "metadata".as_bytes(),
+                mpl_token_metadata::ID.as_ref(),
+                "burn".as_bytes(),
+            ],
+            &mpl_token_metadata::ID,
+        )
+        .0;
+
+        let approve_ix = ApproveUseAuthorityBuilder::new()
+            .use_authority_record(use_authority_record)
+            .owner(payer.pubkey())
+            .payer(payer.pubkey())
+            .user(user.pubkey())
+            .owner_token_account(asset.token)
+            .metadata(asset.metadata)
+            .mint(asset.mint.pubkey())
+            .burner(burner)
+            .token_program(spl_token_program)
+            .number_of_uses(2)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[approve_ix],
+            Some(&payer.pubkey()),
+            &[&payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let token_account = get_account(&mut context, &asset.token).await;
+        let token = unpack::<Account>(&token_account.data).unwrap().base;
+        assert_eq!(token.delegate, Some(burner).into());
+
+        // when the use authority utilizes the asset
+
+        let utilize_ix = UtilizeBuilder::new()
+            .metadata(asset.metadata)
+            .token_account(asset.token)
+            .mint(asset.mint.pubkey())
+            .use_authority(user.pubkey())
+            .owner(payer.pubkey())
+            .token_program(spl_token_program)
+            .use_authority_record(Some(use_authority_record))
+            .burner(Some(burner))
+            .number_of_uses(1)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[utilize_ix],
+            Some(&payer.pubkey()),
+            &[&payer, &user],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // then the uses are consumed from the metadata and the record
+
+        let metadata_account = get_account(&mut context, &asset.metadata).await;
+        let metadata = Metadata::from_bytes(&metadata_account.data).unwrap();
+        assert_eq!(metadata.uses.unwrap().remaining, 4);
+
+        let record_account = get_account(&mut context, &use_authority_record).await;
+        let record = UseAuthorityRecord::from_bytes(&record_account.data).unwrap();
+        assert_eq!(record.allowed_uses, 1);
+
+        // and the owner can revoke the use authority
+
+        let revoke_ix = RevokeUseAuthorityBuilder::new()
+            .use_authority_record(use_authority_record)
+            .owner(payer.pubkey())
+            .user(user.pubkey())
+            .owner_token_account(asset.token)
+            .mint(asset.mint.pubkey())
+            .metadata(asset.metadata)
+            .token_program(spl_token_program)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[revoke_ix],
+            Some(&payer.pubkey()),
+            &[&payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        assert!(find_account(&mut context, &use_authority_record)
+            .await
+            .is_none());
+
+        let token_account = get_account(&mut context, &asset.token).await;
+        let token = unpack::<Account>(&token_account.data).unwrap().base;
+        assert!(token.delegate.is_none());
+    }
+}
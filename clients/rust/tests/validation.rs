@@ -0,0 +1,220 @@
+pub mod setup;
+pub use setup::*;
+
+use mpl_token_metadata::{
+    accounts::Metadata,
+    errors::MplTokenMetadataError,
+    types::{
+        Collection, CollectionDetailsToggle, CollectionToggle, CreateArgs, Creator, Data, DataV2,
+        RuleSetToggle, TokenStandard, UpdateArgs, UseMethod, Uses, UsesToggle,
+    },
+};
+use solana_pubkey::Pubkey;
+
+fn creator(address: Pubkey, verified: bool, share: u8) -> Creator {
+    Creator {
+        address,
+        verified,
+        share,
+    }
+}
+
+fn data(creators: Option<Vec<Creator>>) -> Data {
+    Data {
+        name: String::from("Digital Asset"),
+        symbol: String::from("DA"),
+        uri: String::from("https://digital.asset.org"),
+        seller_fee_basis_points: 500,
+        creators,
+    }
+}
+
+mod validation {
+
+    use super::*;
+
+    #[test]
+    fn validate_data() {
+        let authority = Pubkey::new_unique();
+        let data = data(Some(vec![
+            creator(authority, true, 60),
+            creator(Pubkey::new_unique(), false, 40),
+        ]));
+
+        assert_eq!(data.validate(&authority, None), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_validate_data_with_all_violations() {
+        let authority = Pubkey::new_unique();
+        let duplicate = Pubkey::new_unique();
+
+        let data = Data {
+            name: "n".repeat(33),
+            symbol: "s".repeat(11),
+            uri: "u".repeat(201),
+            seller_fee_basis_points: 10001,
+            creators: Some(vec![
+                creator(duplicate, false, 50),
+                creator(duplicate, false, 50),
+                creator(Pubkey::new_unique(), true, 10),
+            ]),
+        };
+
+        let errors = data.validate(&authority, None).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                MplTokenMetadataError::NameTooLong,
+                MplTokenMetadataError::SymbolTooLong,
+                MplTokenMetadataError::UriTooLong,
+                MplTokenMetadataError::InvalidBasisPoints,
+                MplTokenMetadataError::DuplicateCreatorAddress,
+                MplTokenMetadataError::CannotVerifyAnotherCreator,
+                MplTokenMetadataError::ShareTotalMustBe100,
+            ]
+        );
+    }
+
+    #[test]
+    fn fail_to_remove_verified_creator() {
+        let authority = Pubkey::new_unique();
+        let verified = Pubkey::new_unique();
+        let existing = Metadata {
+            update_authority: authority,
+            creators: Some(vec![
+                creator(verified, true, 50),
+                creator(Pubkey::new_unique(), false, 50),
+            ]),
+            ..metadata(TokenStandard::NonFungible)
+        };
+
+        // removing all creators
+        let errors = data(None)
+            .validate(&authority, Some(&existing))
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![MplTokenMetadataError::CannotRemoveVerifiedCreator]
+        );
+
+        // removing the verified creator from the list
+        let errors = data(Some(vec![creator(authority, false, 100)]))
+            .validate(&authority, Some(&existing))
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![MplTokenMetadataError::CannotUnverifyAnotherCreator]
+        );
+
+        // keeping the verified creator
+        let data = data(Some(vec![
+            creator(verified, true, 50),
+            creator(authority, false, 50),
+        ]));
+        assert_eq!(data.validate(&authority, Some(&existing)), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_validate_data_v2_with_verified_collection() {
+        let authority = Pubkey::new_unique();
+        let data = DataV2 {
+            name: String::from("Digital Asset"),
+            symbol: String::from("DA"),
+            uri: String::from("https://digital.asset.org"),
+            seller_fee_basis_points: 500,
+            creators: None,
+            collection: Some(Collection {
+                verified: true,
+                key: Pubkey::new_unique(),
+            }),
+            uses: Some(Uses {
+                use_method: UseMethod::Single,
+                remaining: 2,
+                total: 2,
+            }),
+        };
+
+        let errors = data.validate(&authority, None).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                MplTokenMetadataError::CollectionCannotBeVerifiedInThisInstruction,
+                MplTokenMetadataError::InvalidUseMethod,
+            ]
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_create_args_of_edition() {
+        let authority = Pubkey::new_unique();
+        let args = CreateArgs::V1 {
+            name: String::from("Digital Asset"),
+            symbol: String::from("DA"),
+            uri: String::from("https://digital.asset.org"),
+            seller_fee_basis_points: 500,
+            creators: Some(vec![creator(authority, true, 100)]),
+            primary_sale_happened: false,
+            is_mutable: true,
+            token_standard: TokenStandard::NonFungibleEdition,
+            collection: None,
+            uses: None,
+            collection_details: None,
+            rule_set: None,
+            decimals: None,
+            print_supply: None,
+        };
+
+        let errors = args.validate(&authority).unwrap_err();
+
+        assert_eq!(errors, vec![MplTokenMetadataError::InvalidTokenStandard]);
+    }
+
+    #[test]
+    fn fail_to_validate_update_args() {
+        let authority = Pubkey::new_unique();
+        let mut existing = Metadata {
+            update_authority: authority,
+            ..metadata(TokenStandard::NonFungible)
+        };
+        existing.is_mutable = false;
+        existing.primary_sale_happened = true;
+        existing.collection = Some(Collection {
+            verified: true,
+            key: Pubkey::new_unique(),
+        });
+
+        let args = UpdateArgs::V1 {
+            new_update_authority: None,
+            data: Some(data(None)),
+            primary_sale_happened: Some(false),
+            is_mutable: Some(true),
+            collection: CollectionToggle::Clear,
+            collection_details: CollectionDetailsToggle::None,
+            uses: UsesToggle::None,
+            rule_set: RuleSetToggle::Set(Pubkey::new_unique()),
+            authorization_data: None,
+        };
+
+        let errors = args.validate(&authority, &existing).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                MplTokenMetadataError::DataIsImmutable,
+                MplTokenMetadataError::PrimarySaleCanOnlyBeFlippedToTrue,
+                MplTokenMetadataError::IsMutableCanOnlyBeFlippedToFalse,
+                MplTokenMetadataError::CannotUpdateVerifiedCollection,
+                MplTokenMetadataError::InvalidTokenStandard,
+            ]
+        );
+
+        // an update that does not change any field is valid
+        assert_eq!(
+            UpdateArgs::default().validate(&authority, &existing),
+            Ok(())
+        );
+    }
+}
@@ -778,37 +778,101 @@ codama.update(
 );
 
 // Update versioned instructions.
+// Burns append the rule set accounts fetched from the remaining accounts.
+const burnAccountsTransform = (node) => {
+  assertIsNode(node, ["instructionNode"]);
+  return instructionNode({
+    ...node,
+    accounts: [
+      ...node.accounts,
+      instructionAccountNode({
+        name: "authorizationRulesProgram",
+        isOptional: true,
+        isWritable: false,
+        isSigner: false,
+        docs: ["Token Authorization Rules Program"],
+      }),
+      instructionAccountNode({
+        name: "authorizationRules",
+        isOptional: true,
+        isWritable: false,
+        isSigner: false,
+        docs: ["Token Authorization Rules account"],
+      }),
+    ],
+  });
+};
+
+// Prints append the delegate and rule set accounts fetched from the remaining accounts.
+const printAccountsTransform = (node) => {
+  assertIsNode(node, ["instructionNode"]);
+  return instructionNode({
+    ...node,
+    accounts: [
+      ...node.accounts,
+      instructionAccountNode({
+        name: "holderDelegateRecord",
+        isOptional: true,
+        isWritable: false,
+        isSigner: false,
+        docs: ["The Delegate Record authorizing escrowless edition printing"],
+      }),
+      instructionAccountNode({
+        name: "delegate",
+        isOptional: true,
+        isWritable: false,
+        isSigner: true,
+        docs: ["The authority printing the edition for a delegated print"],
+      }),
+      instructionAccountNode({
+        name: "authorizationRulesProgram",
+        isOptional: true,
+        isWritable: false,
+        isSigner: false,
+        docs: ["Token Authorization Rules Program"],
+      }),
+      instructionAccountNode({
+        name: "authorizationRules",
+        isOptional: true,
+        isWritable: false,
+        isSigner: false,
+        docs: ["Token Authorization Rules account"],
+      }),
+      instructionAccountNode({
+        name: "masterEditionMint",
+        isOptional: true,
+        isWritable: false,
+        isSigner: false,
+        docs: ["Mint of the master edition"],
+      }),
+      instructionAccountNode({
+        name: "masterTokenRecord",
+        isOptional: true,
+        isWritable: false,
+        isSigner: false,
+        docs: ["Token record account of the master edition token"],
+      }),
+    ],
+  });
+};
+
 codama.update(
   bottomUpTransformerVisitor([
+    {
+      select: "[instructionNode]burnV1",
+      transform: burnAccountsTransform,
+    },
+    {
+      select: "[instructionNode]burnV2",
+      transform: burnAccountsTransform,
+    },
     {
       select: "[instructionNode]printV2",
-      transform: (node) => {
-        assertIsNode(node, ["instructionNode"]);
-        return instructionNode({
-          ...node,
-          accounts: [
-            ...node.accounts,
-            instructionAccountNode({
-              name: "holderDelegateRecord",
-              isOptional: true,
-              isWritable: false,
-              isSigner: false,
-              docs: [
-                "The Delegate Record authorizing escrowless edition printing",
-              ],
-            }),
-            instructionAccountNode({
-              name: "delegate",
-              isOptional: true,
-              isWritable: false,
-              isSigner: true,
-              docs: [
-                "The authority printing the edition for a delegated print",
-              ],
-            }),
-          ],
-        });
-      },
+      transform: printAccountsTransform,
+    },
+    {
+      select: "[instructionNode]printV3",
+      transform: printAccountsTransform,
     },
   ])
 );
@@ -1091,6 +1155,50 @@ codama.update(
       },
       arguments: { edition: { name: "editionNumber" } },
     },
+    printV3: {
+      accounts: {
+        editionMarkerPda: {
+          defaultValue: conditionalValueNode({
+            condition: argumentValueNode("tokenStandard"),
+            value: enumValueNode("TokenStandard", "ProgrammableNonFungible"),
+            ifTrue: pdaValueNode("editionMarkerV2", [
+              pdaSeedValueNode("mint", argumentValueNode("masterEditionMint")),
+            ]),
+            ifFalse: pdaValueNode(
+              pdaLinkNode("editionMarkerFromEditionNumber"),
+              [
+                pdaSeedValueNode(
+                  "mint",
+                  argumentValueNode("masterEditionMint")
+                ),
+                pdaSeedValueNode(
+                  "editionNumber",
+                  argumentValueNode("editionNumber")
+                ),
+              ]
+            ),
+          }),
+        },
+        editionMintAuthority: {
+          defaultValue: conditionalValueNode({
+            condition: accountValueNode("holderDelegateRecord"),
+            ifTrue: conditionalValueNode({
+              condition: accountValueNode("delegate"),
+              ifTrue: accountValueNode("delegate"),
+              ifFalse: accountValueNode("payer"),
+            }),
+            ifFalse: accountValueNode("masterTokenAccountOwner"),
+          }),
+        },
+        masterTokenAccountOwner: {
+          defaultValue: conditionalValueNode({
+            condition: accountValueNode("holderDelegateRecord"),
+            ifFalse: identityValueNode(),
+          }),
+        },
+      },
+      arguments: { edition: { name: "editionNumber" } },
+    },
     // Update.
     updateAsAuthorityItemDelegateV2:
       updateAsMetadataDelegateDefaults("AuthorityItem"),
@@ -1128,6 +1236,20 @@ codama.update(
     delegateProgrammableConfigItemV1: metadataDelegateDefaults(
       "ProgrammableConfigItem"
     ),
+    delegateCollectionV2: metadataDelegateDefaults("Collection"),
+    delegateSaleV2: tokenDelegateDefaults,
+    delegateTransferV2: tokenDelegateDefaults,
+    delegateDataV2: metadataDelegateDefaults("Data"),
+    delegateUtilityV2: tokenDelegateDefaults,
+    delegateStakingV2: tokenDelegateDefaults,
+    delegateProgrammableConfigV2:
+      metadataDelegateDefaults("ProgrammableConfig"),
+    delegateAuthorityItemV2: metadataDelegateDefaults("AuthorityItem"),
+    delegateDataItemV2: metadataDelegateDefaults("DataItem"),
+    delegateCollectionItemV2: metadataDelegateDefaults("CollectionItem"),
+    delegateProgrammableConfigItemV2: metadataDelegateDefaults(
+      "ProgrammableConfigItem"
+    ),
     // Revoke.
     revokeCollectionV1: metadataDelegateDefaults("Collection"),
     revokeSaleV1: tokenDelegateDefaults,
@@ -701,34 +701,86 @@ kinobi.update(
   })
 );
 
+// Burns append the rule set accounts fetched from the remaining accounts.
+const burnAccountsTransform = (node) => {
+  k.assertIsNode(node, ["instructionNode"]);
+  return k.instructionNode({
+    ...node,
+    accounts: [
+      ...node.accounts,
+      k.instructionAccountNode({
+        name: "authorizationRulesProgram",
+        isOptional: true,
+        docs: ["Token Authorization Rules Program"],
+      }),
+      k.instructionAccountNode({
+        name: "authorizationRules",
+        isOptional: true,
+        docs: ["Token Authorization Rules account"],
+      }),
+    ],
+  });
+};
+
+// Prints append the delegate and rule set accounts fetched from the remaining accounts.
+const printAccountsTransform = (node) => {
+  k.assertIsNode(node, ["instructionNode"]);
+  return k.instructionNode({
+    ...node,
+    accounts: [
+      ...node.accounts,
+      k.instructionAccountNode({
+        name: "holderDelegateRecord",
+        isOptional: true,
+        docs: ["The Delegate Record authorizing escrowless edition printing"],
+      }),
+      k.instructionAccountNode({
+        name: "delegate",
+        isOptional: true,
+        isSigner: true,
+        docs: ["The authority printing the edition for a delegated print"],
+      }),
+      k.instructionAccountNode({
+        name: "authorizationRulesProgram",
+        isOptional: true,
+        docs: ["Token Authorization Rules Program"],
+      }),
+      k.instructionAccountNode({
+        name: "authorizationRules",
+        isOptional: true,
+        docs: ["Token Authorization Rules account"],
+      }),
+      k.instructionAccountNode({
+        name: "masterEditionMint",
+        isOptional: true,
+        docs: ["Mint of the master edition"],
+      }),
+      k.instructionAccountNode({
+        name: "masterTokenRecord",
+        isOptional: true,
+        docs: ["Token record account of the master edition token"],
+      }),
+    ],
+  });
+};
+
 kinobi.update(
   k.bottomUpTransformerVisitor([
+    {
+      select: "[instructionNode]burnV1",
+      transform: burnAccountsTransform,
+    },
+    {
+      select: "[instructionNode]burnV2",
+      transform: burnAccountsTransform,
+    },
     {
       select: "[instructionNode]printV2",
-      transform: (node) => {
-        k.assertIsNode(node, ["instructionNode"]);
-        return k.instructionNode({
-          ...node,
-          accounts: [
-            ...node.accounts,
-            k.instructionAccountNode({
-              name: "holderDelegateRecord",
-              isOptional: true,
-              docs: [
-                "The Delegate Record authorizing escrowless edition printing",
-              ],
-            }),
-            k.instructionAccountNode({
-              name: "delegate",
-              isOptional: true,
-              isSigner: true,
-              docs: [
-                "The authority printing the edition for a delegated print",
-              ],
-            }),
-          ],
-        });
-      },
+      transform: printAccountsTransform,
+    },
+    {
+      select: "[instructionNode]printV3",
+      transform: printAccountsTransform,
     },
   ])
 );
@@ -994,6 +1046,53 @@ kinobi.update(
       },
       arguments: { edition: { name: "editionNumber" } },
     },
+    printV3: {
+      accounts: {
+        editionMarkerPda: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.argumentValueNode("tokenStandard"),
+            value: k.enumValueNode("TokenStandard", "ProgrammableNonFungible"),
+            ifTrue: k.pdaValueNode("editionMarkerV2", [
+              k.pdaSeedValueNode(
+                "mint",
+                k.argumentValueNode("masterEditionMint")
+              ),
+            ]),
+            ifFalse: k.pdaValueNode(
+              k.pdaLinkNode("editionMarkerFromEditionNumber", "hooked"),
+              [
+                k.pdaSeedValueNode(
+                  "mint",
+                  k.argumentValueNode("masterEditionMint")
+                ),
+                k.pdaSeedValueNode(
+                  "editionNumber",
+                  k.argumentValueNode("editionNumber")
+                ),
+              ]
+            ),
+          }),
+        },
+        editionMintAuthority: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.accountValueNode("holderDelegateRecord"),
+            ifTrue: k.conditionalValueNode({
+              condition: k.accountValueNode("delegate"),
+              ifTrue: k.accountValueNode("delegate"),
+              ifFalse: k.accountValueNode("payer"),
+            }),
+            ifFalse: k.identityValueNode(),
+          }),
+        },
+        masterTokenAccountOwner: {
+          defaultValue: k.conditionalValueNode({
+            condition: k.accountValueNode("holderDelegateRecord"),
+            ifFalse: k.identityValueNode(),
+          }),
+        },
+      },
+      arguments: { edition: { name: "editionNumber" } },
+    },
     // Update.
     updateAsAuthorityItemDelegateV2:
       updateAsMetadataDelegateDefaults("AuthorityItem"),
@@ -1031,6 +1130,20 @@ kinobi.update(
     delegateProgrammableConfigItemV1: metadataDelegateDefaults(
       "ProgrammableConfigItem"
     ),
+    delegateCollectionV2: metadataDelegateDefaults("Collection"),
+    delegateSaleV2: tokenDelegateDefaults,
+    delegateTransferV2: tokenDelegateDefaults,
+    delegateDataV2: metadataDelegateDefaults("Data"),
+    delegateUtilityV2: tokenDelegateDefaults,
+    delegateStakingV2: tokenDelegateDefaults,
+    delegateProgrammableConfigV2:
+      metadataDelegateDefaults("ProgrammableConfig"),
+    delegateAuthorityItemV2: metadataDelegateDefaults("AuthorityItem"),
+    delegateDataItemV2: metadataDelegateDefaults("DataItem"),
+    delegateCollectionItemV2: metadataDelegateDefaults("CollectionItem"),
+    delegateProgrammableConfigItemV2: metadataDelegateDefaults(
+      "ProgrammableConfigItem"
+    ),
     // Revoke.
     revokeCollectionV1: metadataDelegateDefaults("Collection"),
     revokeSaleV1: tokenDelegateDefaults,
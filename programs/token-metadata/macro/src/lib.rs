@@ -2,8 +2,8 @@ use proc_macro::TokenStream;
 use quote::quote;
 use std::collections::HashMap;
 use syn::{
-    self, parse_macro_input, DeriveInput, Expr, ExprPath, GenericArgument, Lit, Meta, MetaList,
-    MetaNameValue, NestedMeta, Path, PathArguments, Type, TypePath,
+    self, parse_macro_input, punctuated::Punctuated, DeriveInput, Expr, ExprPath, GenericArgument,
+    Lit, Meta, MetaList, MetaNameValue, NestedMeta, Path, PathArguments, Type, TypePath,
 };
 
 #[derive(Default)]
@@ -13,24 +13,48 @@ struct Variant {
     pub accounts: Vec<Account>,
     // (name, type, generic type)
     pub args: Vec<(String, String, Option<String>)>,
+    // whether the signer/writable validation is enabled
+    pub checked: bool,
+    // accounts excluded from the signer/writable validation
+    pub unchecked: Vec<String>,
 }
 
 #[derive(Debug)]
 struct Account {
     pub name: String,
+    pub index: usize,
+    pub writable: bool,
+    pub signer: bool,
+    pub optional_signer: bool,
     pub optional: bool,
+    pub desc: String,
 }
 
 // Helper account attribute (reusing from shank annotation).
 const ACCOUNT_ATTRIBUTE: &str = "account";
 // Helper args attribute.
 const ARGS_ATTRIBUTE: &str = "args";
+// Helper attribute to enable the signer/writable validation of accounts.
+const CHECKED_ACCOUNTS_ATTRIBUTE: &str = "checked_accounts";
+// Helper attribute to skip the signer/writable validation of accounts.
+const UNCHECKED_ACCOUNTS_ATTRIBUTE: &str = "unchecked_accounts";
 // Name property in the account attribute.
 const NAME_PROPERTY: &str = "name";
 // Optional property in the account attribute.
 const OPTIONAL_PROPERTY: &str = "optional";
-
-#[proc_macro_derive(AccountContext, attributes(account, args))]
+// Writable property in the account attribute.
+const WRITABLE_PROPERTY: &str = "writable";
+// Signer property in the account attribute.
+const SIGNER_PROPERTY: &str = "signer";
+// Optional signer property in the account attribute.
+const OPTIONAL_SIGNER_PROPERTY: &str = "optional_signer";
+// Description property in the account attribute.
+const DESC_PROPERTY: &str = "desc";
+
+#[proc_macro_derive(
+    AccountContext,
+    attributes(account, args, checked_accounts, unchecked_accounts)
+)]
 pub fn account_context_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
@@ -72,8 +96,13 @@ pub fn account_context_derive(input: TokenStream) -> TokenStream {
 
                 for path in segments {
                     let ident = path.ident.to_string();
-                    // we are only interested in #[account] and #[args] attributes
-                    if ident == ACCOUNT_ATTRIBUTE || ident == ARGS_ATTRIBUTE {
+                    // we are only interested in #[account], #[args], #[checked_accounts] and
+                    // #[unchecked_accounts] attributes
+                    if ident == ACCOUNT_ATTRIBUTE
+                        || ident == ARGS_ATTRIBUTE
+                        || ident == CHECKED_ACCOUNTS_ATTRIBUTE
+                        || ident == UNCHECKED_ACCOUNTS_ATTRIBUTE
+                    {
                         attribute = ident;
                         skip = false;
                     }
@@ -89,8 +118,16 @@ pub fn account_context_derive(input: TokenStream) -> TokenStream {
                             panic!("#[account] requires attributes account name");
                         };
 
-                        // (name, optional)
-                        let mut property: (Option<String>, Option<String>) = (None, None);
+                        let mut account = Account {
+                            name: String::new(),
+                            index: variant.accounts.len(),
+                            writable: false,
+                            signer: false,
+                            optional_signer: false,
+                            optional: false,
+                            desc: String::new(),
+                        };
+                        let mut account_name = None;
 
                         for element in nested_meta {
                             match element {
@@ -110,26 +147,46 @@ pub fn account_context_derive(input: TokenStream) -> TokenStream {
                                                 }
                                                 _ => panic!("Invalid value for property {ident}"),
                                             };
-                                            property.0 = Some(token);
+                                            account_name = Some(token);
+                                        } else if *ident == DESC_PROPERTY {
+                                            account.desc = match lit {
+                                                Lit::Str(lit) => lit.value(),
+                                                _ => panic!("Invalid value for property {ident}"),
+                                            };
                                         }
                                     }
                                 }
-                                // optional
+                                // optional, writable, signer, optional_signer
                                 NestedMeta::Meta(Meta::Path(path)) => {
                                     let name = path.get_ident().map(|x| x.to_string());
-                                    if let Some(name) = name {
-                                        if name == OPTIONAL_PROPERTY {
-                                            property.1 = Some(name);
+                                    match name.as_deref() {
+                                        Some(OPTIONAL_PROPERTY) => account.optional = true,
+                                        Some(WRITABLE_PROPERTY) => account.writable = true,
+                                        Some(SIGNER_PROPERTY) => account.signer = true,
+                                        Some(OPTIONAL_SIGNER_PROPERTY) => {
+                                            account.optional_signer = true
                                         }
+                                        _ => {}
                                     }
                                 }
+                                // index
+                                NestedMeta::Lit(Lit::Int(index)) => {
+                                    account.index = index.base10_parse().unwrap();
+                                }
                                 _ => {}
                             }
                         }
-                        variant.accounts.push(Account {
-                            name: property.0.unwrap(),
-                            optional: property.1.is_some(),
-                        });
+
+                        account.name = account_name.unwrap();
+
+                        if account.signer && account.optional_signer {
+                            panic!(
+                                "#[account] {} cannot be both signer and optional_signer",
+                                account.name
+                            );
+                        }
+
+                        variant.accounts.push(account);
                     } else if attribute == ARGS_ATTRIBUTE {
                         let args_tokens: syn::ExprType = a.parse_args().unwrap();
                         // name
@@ -169,10 +226,36 @@ pub fn account_context_derive(input: TokenStream) -> TokenStream {
                             }
                             _ => panic!("#[args] requires an expression 'name: type'"),
                         }
+                    } else if attribute == CHECKED_ACCOUNTS_ATTRIBUTE {
+                        variant.checked = true;
+                    } else if attribute == UNCHECKED_ACCOUNTS_ATTRIBUTE {
+                        let names = a
+                            .parse_args_with(
+                                Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+                            )
+                            .expect("#[unchecked_accounts] requires a list of account names");
+                        variant
+                            .unchecked
+                            .extend(names.iter().map(|name| name.to_string()));
                     }
                 }
             }
 
+            // unchecked accounts only apply to variants with checked accounts and
+            // must be declared by the variant
+            if !variant.unchecked.is_empty() && !variant.checked {
+                panic!(
+                    "#[unchecked_accounts] requires #[checked_accounts] on {}",
+                    variant.name
+                );
+            }
+
+            for name in &variant.unchecked {
+                if !variant.accounts.iter().any(|account| account.name == *name) {
+                    panic!("#[unchecked_accounts] unknown account {name}");
+                }
+            }
+
             enum_variants.push(variant);
         }
 
@@ -183,6 +266,7 @@ pub fn account_context_derive(input: TokenStream) -> TokenStream {
 
     let mut account_structs = generate_accounts(&variants);
     account_structs.extend(generate_builders(&variants));
+    account_structs.extend(generate_variant_info(&ast.ident, &variants));
 
     account_structs
 }
@@ -192,7 +276,15 @@ pub fn account_context_derive(input: TokenStream) -> TokenStream {
 /// The struct will contain all shank annotated accounts and the impl block
 /// will initialize them using the accounts iterators. It support the use of
 /// optional accounts, which would generate an account field with an
-/// `Option<AccountInfo<'a>>` type.
+/// `Option<AccountInfo<'a>>` type. The impl block also exposes the layout of
+/// the accounts as an `ACCOUNT_SPECS` constant.
+///
+/// For variants annotated with `#[checked_accounts]`, the `to_context` function
+/// also validates the `signer` and `writable` properties of the accounts (optional
+/// accounts are only validated when present), failing with an `AccountContextError`
+/// that identifies the account. Accounts listed in a `#[unchecked_accounts(..)]`
+/// attribute of the variant are not validated, and `optional_signer` accounts are
+/// never required to sign.
 ///
 /// ```ignore
 /// pub struct MyAccount<'a> {
@@ -201,11 +293,30 @@ pub fn account_context_derive(input: TokenStream) -> TokenStream {
 ///     ..
 /// }
 /// impl<'a> MyAccount<'a> {
+///     pub const ACCOUNT_SPECS: &'static [AccountSpec] = &[
+///         AccountSpec {
+///             name: "my_first_account",
+///             index: 0,
+///             writable: true,
+///             signer: false,
+///             optional: false,
+///             description: "My first account",
+///         },
+///         ..
+///     ];
+///
 ///     pub fn to_context(
 ///         accounts: &'a [solana_program::account_info::AccountInfo<'a>]
-///     ) -> Result<Context<'a, Self>, solana_program::sysvar::slot_history::ProgramError> {
+///     ) -> Result<Context<'a, Self>, AccountContextError> {
 ///         let account_info_iter = &mut accounts.iter();
 ///
+///         if !accounts[0].is_writable {
+///             return Err(AccountContextError::NotWritable {
+///                 index: 0,
+///                 name: "my_first_account",
+///             });
+///         }
+///
 ///         let my_first_account = solana_program::account_info::next_account_info(account_info_iter)?;
 ///
 ///         ..
@@ -244,6 +355,68 @@ fn generate_accounts(variants: &[Variant]) -> TokenStream {
                 }
             }
         });
+        // layout of the accounts
+        let account_specs = variant.accounts.iter().map(|account| {
+            let Account { name, index, writable, signer, optional, desc, .. } = account;
+            quote! {
+                AccountSpec {
+                    name: #name,
+                    index: #index,
+                    writable: #writable,
+                    signer: #signer,
+                    optional: #optional,
+                    description: #desc,
+                }
+            }
+        });
+        // signer and writable validation
+        let account_checks = variant.accounts.iter().enumerate().filter(|(_, account)| {
+            variant.checked
+                && (account.signer || account.writable)
+                && !variant.unchecked.contains(&account.name)
+        }).map(|(index, account)| {
+            let account_name = &account.name;
+            // optional_signer accounts are not flagged as signer
+            let signer_check = if account.signer {
+                quote! {
+                    if !accounts[#index].is_signer {
+                        return Err(AccountContextError::NotSigner {
+                            index: #index,
+                            name: #account_name,
+                        });
+                    }
+                }
+            } else {
+                quote! {}
+            };
+            let writable_check = if account.writable {
+                quote! {
+                    if !accounts[#index].is_writable {
+                        return Err(AccountContextError::NotWritable {
+                            index: #index,
+                            name: #account_name,
+                        });
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            if account.optional {
+                // optional accounts are only checked when present
+                quote! {
+                    if accounts[#index].key != &crate::ID {
+                        #signer_check
+                        #writable_check
+                    }
+                }
+            } else {
+                quote! {
+                    #signer_check
+                    #writable_check
+                }
+            }
+        });
         // number of expected accounts
         let expected = variant.accounts.len();
 
@@ -252,10 +425,14 @@ fn generate_accounts(variants: &[Variant]) -> TokenStream {
                 #(#struct_fields,)*
             }
             impl<'a> #name<'a> {
-                pub fn to_context(accounts: &'a [solana_program::account_info::AccountInfo<'a>]) -> Result<Context<Self>, solana_program::sysvar::slot_history::ProgramError> {
+                /// Layout of the accounts expected by the instruction.
+                pub const ACCOUNT_SPECS: &'static [AccountSpec] = &[#(#account_specs,)*];
+
+                pub fn to_context(accounts: &'a [solana_program::account_info::AccountInfo<'a>]) -> Result<Context<Self>, AccountContextError> {
                     if accounts.len() < #expected {
-                        return Err(solana_program::sysvar::slot_history::ProgramError::NotEnoughAccountKeys);
+                        return Err(AccountContextError::NotEnoughAccountKeys);
                     }
+                    #(#account_checks)*
                     Ok(Context {
                         accounts: Self {
                             #(#account_fields,)*
@@ -273,6 +450,123 @@ fn generate_accounts(variants: &[Variant]) -> TokenStream {
     })
 }
 
+/// Implements `ArgsVariantName` for an instruction args type.
+///
+/// Enum args report the name of their variant (e.g. `SaleV1`), while struct args
+/// use the default implementation, which has no variant name.
+#[proc_macro_derive(ArgsVariantName)]
+pub fn args_variant_name_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
+
+    let body = if let syn::Data::Enum(syn::DataEnum { ref variants, .. }) = ast.data {
+        let variant_names = variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let value = ident.to_string();
+            quote! { Self::#ident { .. } => Some(#value) }
+        });
+
+        quote! {
+            fn args_variant_name(&self) -> Option<&'static str> {
+                match self {
+                    #(#variant_names,)*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    TokenStream::from(quote! {
+        impl crate::instruction::ArgsVariantName for #name {
+            #body
+        }
+    })
+}
+
+/// Generates runtime information about each enum variant.
+///
+/// The impl block exposes the name of the variant, the name of its args type and
+/// args variant (for tuple variants) and the layout of the accounts declared in the
+/// `#[account]` attributes, in the order they are expected by the instruction. The
+/// impl block is only generated with the `decoder` feature.
+///
+/// ```ignore
+/// #[cfg(feature = "decoder")]
+/// impl MyInstruction {
+///     pub fn variant_name(&self) -> &'static str {
+///         match self {
+///             Self::MyVariant { .. } => "MyVariant",
+///             ..
+///         }
+///     }
+///     pub fn args_type_name(&self) -> Option<&'static str> { .. }
+///     pub fn args_variant_name(&self) -> Option<&'static str> { .. }
+///     pub fn account_specs(&self) -> &'static [AccountSpec] { .. }
+/// }
+/// ```
+fn generate_variant_info(enum_name: &syn::Ident, variants: &[Variant]) -> TokenStream {
+    let variant_names = variants.iter().map(|variant| {
+        let name = syn::parse_str::<syn::Ident>(&variant.name).unwrap();
+        let value = &variant.name;
+        quote! { Self::#name { .. } => #value }
+    });
+
+    let args_type_names = variants.iter().map(|variant| {
+        let name = syn::parse_str::<syn::Ident>(&variant.name).unwrap();
+        if let Some(ty) = &variant.tuple {
+            quote! { Self::#name { .. } => Some(#ty) }
+        } else {
+            quote! { Self::#name { .. } => None }
+        }
+    });
+
+    let args_variant_names = variants.iter().map(|variant| {
+        let name = syn::parse_str::<syn::Ident>(&variant.name).unwrap();
+        if variant.tuple.is_some() {
+            quote! {
+                Self::#name(args) => crate::instruction::ArgsVariantName::args_variant_name(args)
+            }
+        } else {
+            quote! { Self::#name { .. } => None }
+        }
+    });
+
+    let account_specs = variants.iter().map(|variant| {
+        let name = syn::parse_str::<syn::Ident>(&variant.name).unwrap();
+        quote! { Self::#name { .. } => #name::ACCOUNT_SPECS }
+    });
+
+    TokenStream::from(quote! {
+        #[cfg(feature = "decoder")]
+        impl #enum_name {
+            pub fn variant_name(&self) -> &'static str {
+                match self {
+                    #(#variant_names,)*
+                }
+            }
+
+            pub fn args_type_name(&self) -> Option<&'static str> {
+                match self {
+                    #(#args_type_names,)*
+                }
+            }
+
+            pub fn args_variant_name(&self) -> Option<&'static str> {
+                match self {
+                    #(#args_variant_names,)*
+                }
+            }
+
+            pub fn account_specs(&self) -> &'static [AccountSpec] {
+                match self {
+                    #(#account_specs,)*
+                }
+            }
+        }
+    })
+}
+
 fn generate_builders(variants: &[Variant]) -> TokenStream {
     let mut default_pubkeys = HashMap::new();
     default_pubkeys.insert(
@@ -105,9 +105,7 @@ pub fn assert_collection_verify_is_valid(
 ) -> Result<(), ProgramError> {
     match member_collection {
         Some(collection) => {
-            if collection.key != *collection_mint.key
-                || collection_metadata.mint != *collection_mint.key
-            {
+            if collection.key != *collection_mint.key {
                 return Err(MetadataError::CollectionNotFound.into());
             }
         }
@@ -116,6 +114,20 @@ pub fn assert_collection_verify_is_valid(
         }
     }
 
+    assert_collection_parent_is_valid(collection_metadata, collection_mint, edition_account_info)
+}
+
+/// Checks that the collection metadata belongs to the collection mint and that the edition
+/// account is the collection's master edition.
+pub fn assert_collection_parent_is_valid(
+    collection_metadata: &Metadata,
+    collection_mint: &AccountInfo,
+    edition_account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    if collection_metadata.mint != *collection_mint.key {
+        return Err(MetadataError::CollectionNotFound.into());
+    }
+
     match collection_metadata.edition_nonce {
         Some(nonce) => assert_derivation_with_bump(
             &crate::ID,
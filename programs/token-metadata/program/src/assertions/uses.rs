@@ -1,10 +1,14 @@
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
 
 use crate::{
-    assertions::assert_derivation,
+    assertions::{assert_derivation, assert_token_program_matches_package},
     error::MetadataError,
     pda,
-    state::{UseAuthorityRecord, UseMethod, Uses, PREFIX, USER},
+    state::{Metadata, TokenStandard, UseAuthorityRecord, UseMethod, Uses, PREFIX, USER},
+    utils::{validate_mint, validate_token},
 };
 
 pub fn assert_valid_use(
@@ -36,6 +40,48 @@ pub fn assert_valid_use(
     }
 }
 
+/// Validates the mint and token accounts of the 'uses' instructions, which support
+/// assets from both SPL Token and Token-2022 programs.
+pub fn assert_valid_use_token_accounts(
+    metadata_info: &AccountInfo,
+    metadata: &Metadata,
+    mint_info: &AccountInfo,
+    token_account_info: &AccountInfo,
+    owner_info: &AccountInfo,
+    token_program_info: &AccountInfo,
+) -> ProgramResult {
+    assert_token_program_matches_package(token_program_info)?;
+
+    if mint_info.owner != token_program_info.key {
+        return Err(MetadataError::InvalidTokenProgram.into());
+    }
+
+    // programmable assets are always frozen, so uses cannot be delegated or burned
+    // through the token program
+    if matches!(
+        metadata.token_standard,
+        Some(TokenStandard::ProgrammableNonFungible)
+            | Some(TokenStandard::ProgrammableNonFungibleEdition)
+    ) {
+        return Err(MetadataError::InstructionNotSupported.into());
+    }
+
+    if let Some(token_standard) = metadata.token_standard {
+        validate_mint(mint_info, metadata_info, token_standard)?;
+    }
+
+    validate_token(
+        mint_info,
+        token_account_info,
+        Some(owner_info),
+        token_program_info.key,
+        metadata.token_standard,
+        None,
+    )?;
+
+    Ok(())
+}
+
 pub fn assert_burner(program_as_burner: &Pubkey) -> Result<u8, MetadataError> {
     let (canon_burn, b) = pda::find_program_as_burner_account();
     if &canon_burn != program_as_burner {
@@ -799,6 +799,64 @@ pub enum MetadataError {
     /// 202
     #[error("Conditions for closing not met")]
     ConditionsForClosingNotMet,
+
+    /// 203
+    #[error("Account is required to be a signer")]
+    AccountNotSigner,
+
+    /// 204
+    #[error("Account is required to be writable")]
+    AccountNotWritable,
+
+    /// 205
+    #[error("Authority does not match the pending update authority")]
+    PendingUpdateAuthorityMismatch,
+
+    /// 206
+    #[error("Update authority changed after the handoff was proposed")]
+    StalePendingUpdateAuthority,
+
+    /// 207
+    #[error("Delegate expiry must be in the future")]
+    InvalidDelegateExpiry,
+
+    /// 208
+    #[error("Delegate has not expired")]
+    DelegateNotExpired,
+
+    /// 209
+    #[error("The account that owns the record still exists")]
+    RecordNotOrphaned,
+
+    /// 210
+    #[error(
+        "Rent destination must be the token owner or the authority that approved the delegate"
+    )]
+    InvalidRentDestination,
+
+    /// 211
+    #[error("Attribute key too long")]
+    AttributeKeyTooLong,
+
+    /// 212
+    #[error("Attribute value too long")]
+    AttributeValueTooLong,
+
+    /// 213
+    #[error("Too many attributes")]
+    TooManyAttributes,
+
+    /// 214
+    #[error("Token metadata interface instruction is not supported")]
+    UnsupportedTokenMetadataInterfaceInstruction,
+
+    /// 215
+    #[error("Unlock timestamp must be in the future")]
+    InvalidUnlockTimestamp,
+
+    /// 216
+    #[error("Lock has not reached its unlock timestamp")]
+    LockNotExpired,
 }
 
 impl PrintProgramError for MetadataError {
@@ -0,0 +1,205 @@
+//! Structured events emitted by the program.
+//!
+//! Events are emitted through `sol_log_data` (logged as `Program data: <base64>`) as a
+//! single field: the event version byte followed by the Borsh serialization of the
+//! `MetadataEvent`. The version is incremented when the layout of an existing event changes,
+//! while new events are appended to the end of the enum.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "serde-feature")]
+use serde::{Deserialize, Serialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+use crate::{
+    error::MetadataError,
+    processor::DelegateScenario,
+    state::{Metadata, TokenStandard},
+};
+
+/// Version of the layout of the emitted events.
+pub const EVENT_VERSION: u8 = 1;
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum MetadataEvent {
+    /// Metadata accounts were created for a mint.
+    Created {
+        mint: Pubkey,
+        update_authority: Pubkey,
+        token_standard: Option<TokenStandard>,
+    },
+    /// Tokens were minted to a token account.
+    Minted {
+        mint: Pubkey,
+        token: Pubkey,
+        amount: u64,
+    },
+    /// Tokens were transferred between token accounts.
+    Transferred {
+        mint: Pubkey,
+        source: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// Fields of the metadata account were changed.
+    Updated {
+        mint: Pubkey,
+        fields: Vec<UpdatedField>,
+    },
+    /// A delegate was approved.
+    Delegated {
+        mint: Pubkey,
+        delegate: Pubkey,
+        role: DelegateScenario,
+    },
+    /// A delegate was revoked.
+    Revoked {
+        mint: Pubkey,
+        delegate: Pubkey,
+        role: DelegateScenario,
+    },
+    /// A token account was locked.
+    Locked { mint: Pubkey, token: Pubkey },
+    /// A token account was unlocked.
+    Unlocked { mint: Pubkey, token: Pubkey },
+    /// A creator or collection was verified on the metadata of the mint.
+    Verified {
+        mint: Pubkey,
+        target: VerificationTarget,
+    },
+    /// A creator or collection was unverified on the metadata of the mint.
+    Unverified {
+        mint: Pubkey,
+        target: VerificationTarget,
+    },
+    /// Tokens (and the asset accounts, when applicable) were burned.
+    Burned { mint: Pubkey, amount: u64 },
+    /// An edition was printed from a master edition.
+    Printed {
+        master_mint: Pubkey,
+        edition_mint: Pubkey,
+        edition: u64,
+    },
+}
+
+impl MetadataEvent {
+    /// Emits the event through `sol_log_data`.
+    pub fn emit(&self) -> ProgramResult {
+        let mut data = vec![EVENT_VERSION];
+        borsh::to_writer(&mut data, self).map_err(|_| MetadataError::BorshSerializationError)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+
+    /// Creates an `Updated` event with the fields that differ between the
+    /// `before` and `after` states of a metadata account.
+    pub fn updated(before: &Metadata, after: &Metadata) -> Self {
+        Self::Updated {
+            mint: after.mint,
+            fields: UpdatedField::changed(before, after),
+        }
+    }
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum UpdatedField {
+    /// Name, symbol, uri, seller fee basis points or creators.
+    Data,
+    UpdateAuthority,
+    PrimarySaleHappened,
+    IsMutable,
+    TokenStandard,
+    Collection,
+    CollectionDetails,
+    Uses,
+    ProgrammableConfig,
+    /// On-chain attributes of the asset (stored in the attributes account).
+    Attributes,
+}
+
+impl UpdatedField {
+    /// Returns the fields that differ between two states of a metadata account.
+    pub fn changed(before: &Metadata, after: &Metadata) -> Vec<Self> {
+        let mut fields = Vec::new();
+
+        if before.data != after.data {
+            fields.push(Self::Data);
+        }
+        if before.update_authority != after.update_authority {
+            fields.push(Self::UpdateAuthority);
+        }
+        if before.primary_sale_happened != after.primary_sale_happened {
+            fields.push(Self::PrimarySaleHappened);
+        }
+        if before.is_mutable != after.is_mutable {
+            fields.push(Self::IsMutable);
+        }
+        if before.token_standard != after.token_standard {
+            fields.push(Self::TokenStandard);
+        }
+        if before.collection != after.collection {
+            fields.push(Self::Collection);
+        }
+        if before.collection_details != after.collection_details {
+            fields.push(Self::CollectionDetails);
+        }
+        if before.uses != after.uses {
+            fields.push(Self::Uses);
+        }
+        if before.programmable_config != after.programmable_config {
+            fields.push(Self::ProgrammableConfig);
+        }
+
+        fields
+    }
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum VerificationTarget {
+    /// Address of the creator.
+    Creator(Pubkey),
+    /// Mint of the collection.
+    Collection(Pubkey),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Data;
+
+    #[test]
+    fn changed_fields() {
+        let before = Metadata {
+            mint: Pubkey::new_unique(),
+            data: Data {
+                name: String::from("before"),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut after = before.clone();
+        assert!(UpdatedField::changed(&before, &after).is_empty());
+
+        after.data.name = String::from("after");
+        after.is_mutable = !before.is_mutable;
+        after.update_authority = Pubkey::new_unique();
+
+        assert_eq!(
+            MetadataEvent::updated(&before, &after),
+            MetadataEvent::Updated {
+                mint: before.mint,
+                fields: vec![
+                    UpdatedField::Data,
+                    UpdatedField::UpdateAuthority,
+                    UpdatedField::IsMutable
+                ],
+            }
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
+#[cfg(feature = "serde-feature")]
+use serde::{Deserialize, Serialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::InstructionBuilder;
+use crate::instruction::MetadataInstruction;
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum ProposeUpdateAuthorityArgs {
+    V1 {
+        /// The proposed update authority, which must sign to accept the handoff.
+        new_update_authority: Pubkey,
+    },
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum AcceptUpdateAuthorityArgs {
+    V1,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum CancelUpdateAuthorityArgs {
+    V1,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum UpdateAuthorityBatchArgs {
+    V1 {
+        /// The new update authority of the items.
+        new_update_authority: Pubkey,
+        /// Mint of the collection that all items must be verified members of. It is required
+        /// when the authority is a collection `AuthorityItem` delegate.
+        collection: Option<Pubkey>,
+    },
+}
+
+/// Proposes a new update authority for an asset.
+///
+/// # Accounts:
+///
+///   0. `[signer]` Update authority of the asset
+///   1. `[]` Metadata account
+///   2. `[]` Mint account
+///   3. `[writable]` Pending update authority account
+///   4. `[signer, writable]` Payer
+///   5. `[]` System Program
+impl InstructionBuilder for super::builders::ProposeUpdateAuthority {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.metadata, false),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new(self.pending_update_authority, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::ProposeUpdateAuthority(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Accepts a proposed update authority for an asset.
+///
+/// # Accounts:
+///
+///   0. `[signer]` Proposed update authority
+///   1. `[writable]` Metadata account
+///   2. `[writable]` Pending update authority account
+///   3. `[writable]` Update authority that proposed the handoff
+impl InstructionBuilder for super::builders::AcceptUpdateAuthority {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new_readonly(self.new_update_authority, true),
+            AccountMeta::new(self.metadata, false),
+            AccountMeta::new(self.pending_update_authority, false),
+            AccountMeta::new(self.update_authority, false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::AcceptUpdateAuthority(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Cancels a proposed update authority for an asset.
+///
+/// # Accounts:
+///
+///   0. `[signer]` Update authority of the asset or proposed update authority
+///   1. `[]` Metadata account
+///   2. `[writable]` Pending update authority account
+///   3. `[writable]` Update authority that proposed the handoff
+impl InstructionBuilder for super::builders::CancelUpdateAuthority {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.metadata, false),
+            AccountMeta::new(self.pending_update_authority, false),
+            AccountMeta::new(self.update_authority, false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::CancelUpdateAuthority(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Rotates the update authority of a batch of assets.
+///
+/// # Accounts:
+///
+///   0. `[signer]` Current update authority of the items or collection delegate
+///   1. `[optional]` Delegate record PDA
+///   2. `[writable]` Metadata accounts of the items (one account per item)
+impl InstructionBuilder for super::builders::UpdateAuthorityBatch {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.delegate_record.unwrap_or(crate::ID), false),
+        ];
+
+        accounts.extend(
+            self.items
+                .iter()
+                .map(|metadata| AccountMeta::new(*metadata, false)),
+        );
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::UpdateAuthorityBatch(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
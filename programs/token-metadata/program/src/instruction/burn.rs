@@ -1,4 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use solana_program::{
@@ -7,7 +9,7 @@ use solana_program::{
 };
 
 use super::InstructionBuilder;
-use crate::instruction::MetadataInstruction;
+use crate::{instruction::MetadataInstruction, processor::AuthorizationData};
 
 ///# Burn Edition NFT
 ///
@@ -103,12 +105,19 @@ pub fn burn_nft(
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum BurnArgs {
     V1 {
         /// The amount of the token to burn
         amount: u64,
     },
+    V2 {
+        /// The amount of the token to burn
+        amount: u64,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
 }
 
 /// Burn an asset.
@@ -1,4 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use solana_program::{
@@ -191,6 +193,7 @@ pub fn set_and_verify_sized_collection_item(
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct SetCollectionSizeArgs {
     pub size: u64,
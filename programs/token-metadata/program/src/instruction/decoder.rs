@@ -0,0 +1,210 @@
+use std::fmt::{Display, Formatter};
+
+use borsh::BorshDeserialize;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use super::*;
+use crate::error::MetadataError;
+
+/// An account of a decoded instruction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecodedAccount {
+    /// Position of the account in the instruction.
+    pub index: usize,
+    /// Name of the account (`None` for accounts not declared by the instruction).
+    pub name: Option<&'static str>,
+    pub address: Pubkey,
+}
+
+/// A `MetadataInstruction` decoded from its compiled form.
+#[derive(Clone, Debug)]
+pub struct DecodedInstruction {
+    /// Name of the instruction variant (e.g., `Delegate`).
+    pub name: &'static str,
+    /// Qualified name of the args variant (e.g., `DelegateArgs::SaleV1`), when
+    /// the instruction args are an enum.
+    pub args_variant: Option<String>,
+    pub accounts: Vec<DecodedAccount>,
+    pub instruction: MetadataInstruction,
+}
+
+impl DecodedInstruction {
+    /// Decodes the instruction data and names the accounts of an instruction.
+    ///
+    /// Deprecated instructions that can no longer be deserialized fail with
+    /// `MetadataError::Removed`, mirroring the program behaviour.
+    pub fn decode(
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
+        if *program_id != crate::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let variant = data.first().ok_or(MetadataError::InvalidInstruction)?;
+
+        let instruction =
+            MetadataInstruction::try_from_slice(data).map_err(|_| match *variant {
+                CREATE_METADATA_ACCOUNT
+                | UPDATE_METADATA_ACCOUNT
+                | DEPRECATED_CREATE_MASTER_EDITION
+                | DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN
+                | DEPRECATED_SET_RESERVATION_LIST
+                | DEPRECATED_CREATE_RESERVATION_LIST
+                | DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN
+                | DEPRECATED_MINT_PRINTING_TOKENS
+                | CREATE_METADATA_ACCOUNT_V2
+                | MIGRATE => MetadataError::Removed.into(),
+                _ => ProgramError::InvalidInstructionData,
+            })?;
+
+        let specs = instruction.account_specs();
+
+        let accounts = accounts
+            .iter()
+            .enumerate()
+            .map(|(index, address)| DecodedAccount {
+                index,
+                name: specs.get(index).map(|spec| spec.name),
+                address: *address,
+            })
+            .collect();
+
+        Ok(Self {
+            name: instruction.variant_name(),
+            args_variant: args_variant(&instruction),
+            accounts,
+            instruction,
+        })
+    }
+}
+
+impl Display for DecodedInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.args_variant {
+            Some(args_variant) => writeln!(f, "{} ({})", self.name, args_variant)?,
+            None => writeln!(f, "{}", self.name)?,
+        }
+
+        for account in &self.accounts {
+            let address = if account.address == crate::ID {
+                String::from("(not provided)")
+            } else {
+                account.address.to_string()
+            };
+            writeln!(
+                f,
+                "  #{} {}: {}",
+                account.index,
+                account.name.unwrap_or("(remaining)"),
+                address
+            )?;
+        }
+
+        write!(f, "{:?}", self.instruction)
+    }
+}
+
+/// Returns the qualified name of the args variant of an instruction, e.g.
+/// `DelegateArgs::SaleV1`.
+fn args_variant(instruction: &MetadataInstruction) -> Option<String> {
+    Some(format!(
+        "{}::{}",
+        instruction.args_type_name()?,
+        instruction.args_variant_name()?
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    fn decode(instruction: MetadataInstruction, accounts: &[Pubkey]) -> DecodedInstruction {
+        let data = instruction.try_to_vec().unwrap();
+        DecodedInstruction::decode(&crate::ID, accounts, &data).unwrap()
+    }
+
+    #[test]
+    fn decode_delegate_sub_variant() {
+        let accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let decoded = decode(
+            MetadataInstruction::Delegate(DelegateArgs::SaleV1 {
+                amount: 1,
+                authorization_data: None,
+            }),
+            &accounts,
+        );
+
+        assert_eq!(decoded.name, "Delegate");
+        assert_eq!(
+            decoded.args_variant.as_deref(),
+            Some("DelegateArgs::SaleV1")
+        );
+        assert_eq!(decoded.accounts[0].name, Some("delegate_record"));
+        assert_eq!(decoded.accounts[1].name, Some("delegate"));
+        assert_eq!(decoded.accounts[1].address, accounts[1]);
+    }
+
+    #[test]
+    fn decode_update_sub_variant() {
+        let decoded = decode(
+            MetadataInstruction::Update(UpdateArgs::default_as_collection_delegate()),
+            &[Pubkey::new_unique()],
+        );
+
+        assert_eq!(decoded.name, "Update");
+        assert_eq!(
+            decoded.args_variant.as_deref(),
+            Some("UpdateArgs::AsCollectionDelegateV2")
+        );
+        assert_eq!(decoded.accounts[0].name, Some("authority"));
+    }
+
+    #[test]
+    fn decode_without_sub_variant() {
+        let decoded = decode(MetadataInstruction::SignMetadata, &[]);
+        assert_eq!(decoded.name, "SignMetadata");
+        assert_eq!(decoded.args_variant, None);
+
+        let decoded = decode(
+            MetadataInstruction::SetCollectionSize(SetCollectionSizeArgs { size: 10 }),
+            &[],
+        );
+        assert_eq!(decoded.name, "SetCollectionSize");
+        assert_eq!(decoded.args_variant, None);
+    }
+
+    #[test]
+    fn decode_remaining_accounts() {
+        let accounts = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+
+        // update primary sale happened expects 3 accounts
+        let mut decoded = decode(
+            MetadataInstruction::UpdatePrimarySaleHappenedViaToken,
+            &accounts,
+        );
+        assert_eq!(decoded.accounts[2].name, Some("token"));
+
+        decoded = decode(MetadataInstruction::UpdateMetadataAccount, &accounts);
+        assert_eq!(decoded.accounts[2].name, None);
+    }
+
+    #[test]
+    fn fail_to_decode_removed_instruction() {
+        let error = DecodedInstruction::decode(&crate::ID, &[], &[CREATE_METADATA_ACCOUNT, 1, 2])
+            .unwrap_err();
+        assert_eq!(error, MetadataError::Removed.into());
+
+        let error = DecodedInstruction::decode(&Pubkey::new_unique(), &[], &[]).unwrap_err();
+        assert_eq!(error, ProgramError::IncorrectProgramId);
+    }
+}
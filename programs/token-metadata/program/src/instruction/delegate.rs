@@ -1,6 +1,8 @@
 use std::fmt;
 
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use solana_program::{
@@ -9,11 +11,16 @@ use solana_program::{
 };
 
 use super::InstructionBuilder;
-use crate::{instruction::MetadataInstruction, processor::AuthorizationData};
+use crate::{
+    instruction::MetadataInstruction, processor::AuthorizationData, state::DelegateExpiry,
+};
 
 /// Delegate args can specify Metadata delegates and Token delegates.
+///
+/// `V2` variants can specify an expiry for the delegate, after which it is no longer valid.
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum DelegateArgs {
     CollectionV1 {
@@ -82,10 +89,81 @@ pub enum DelegateArgs {
         /// Required authorization data to validate the request.
         authorization_data: Option<AuthorizationData>,
     },
+    CollectionV2 {
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    SaleV2 {
+        amount: u64,
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    TransferV2 {
+        amount: u64,
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    DataV2 {
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    UtilityV2 {
+        amount: u64,
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    StakingV2 {
+        amount: u64,
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    ProgrammableConfigV2 {
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    AuthorityItemV2 {
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    DataItemV2 {
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    CollectionItemV2 {
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+    ProgrammableConfigItemV2 {
+        /// Expiry of the delegate (never expires when not set).
+        expiry: Option<DelegateExpiry>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum RevokeArgs {
     CollectionV1,
@@ -105,6 +183,14 @@ pub enum RevokeArgs {
     PrintDelegateV1,
 }
 
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum RevokeExpiredDelegateArgs {
+    V1,
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
@@ -270,3 +356,44 @@ impl InstructionBuilder for super::builders::Revoke {
         }
     }
 }
+
+/// Cleans up an expired delegate.
+///
+/// # Accounts:
+///
+///   0. `[optional, writable]` Metadata delegate record account
+///   1. `[]` Mint account
+///   2. `[optional]` Token account
+///   3. `[optional, writable]` Token record account
+///   4. `[writable]` Destination of the delegate record rent
+///   5. `[signer, writable]` Payer
+///   6. `[]` System program
+impl InstructionBuilder for super::builders::RevokeExpiredDelegate {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            if let Some(delegate_record) = self.delegate_record {
+                AccountMeta::new(delegate_record, false)
+            } else {
+                AccountMeta::new_readonly(crate::ID, false)
+            },
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new_readonly(self.token.unwrap_or(crate::ID), false),
+            if let Some(token_record) = self.token_record {
+                AccountMeta::new(token_record, false)
+            } else {
+                AccountMeta::new_readonly(crate::ID, false)
+            },
+            AccountMeta::new(self.destination, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::RevokeExpiredDelegate(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use solana_program::{
@@ -7,7 +9,11 @@ use solana_program::{
     system_program, sysvar,
 };
 
-use crate::{instruction::MetadataInstruction, utils::SPL_TOKEN_ID};
+use crate::{
+    instruction::MetadataInstruction,
+    pda::{find_master_edition_account, find_metadata_account, find_token_record_account},
+    processor::AuthorizationData,
+};
 
 pub fn close_escrow_account(
     program_id: Pubkey,
@@ -78,9 +84,31 @@ pub fn create_escrow_account(
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, PartialEq, Eq, Debug, Clone)]
 pub struct TransferOutOfEscrowArgs {
     pub amount: u64,
+    /// Required only for programmable attributes.
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+// We have a custom implementation of BorshDeserialize for TransferOutOfEscrowArgs so that
+// instructions serialized before the authorization data was added, which end after the
+// amount, are still accepted.
+impl BorshDeserialize for TransferOutOfEscrowArgs {
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, std::io::Error> {
+        let amount = u64::deserialize(buf)?;
+        let authorization_data = if buf.is_empty() {
+            None
+        } else {
+            Option::<AuthorizationData>::deserialize(buf)?
+        };
+
+        Ok(Self {
+            amount,
+            authorization_data,
+        })
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -94,6 +122,7 @@ pub fn transfer_out_of_escrow(
     attribute_dst: Pubkey,
     escrow_mint: Pubkey,
     escrow_account: Pubkey,
+    token_program: Pubkey,
     authority: Option<Pubkey>,
     amount: u64,
 ) -> Instruction {
@@ -108,7 +137,7 @@ pub fn transfer_out_of_escrow(
         AccountMeta::new_readonly(escrow_account, false),
         AccountMeta::new_readonly(solana_program::system_program::ID, false),
         AccountMeta::new_readonly(spl_associated_token_account::ID, false),
-        AccountMeta::new_readonly(SPL_TOKEN_ID, false),
+        AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(sysvar::instructions::ID, false),
     ];
 
@@ -116,9 +145,12 @@ pub fn transfer_out_of_escrow(
         accounts.push(AccountMeta::new_readonly(authority, true));
     }
 
-    let data = MetadataInstruction::TransferOutOfEscrow(TransferOutOfEscrowArgs { amount })
-        .try_to_vec()
-        .unwrap();
+    let data = MetadataInstruction::TransferOutOfEscrow(TransferOutOfEscrowArgs {
+        amount,
+        authorization_data: None,
+    })
+    .try_to_vec()
+    .unwrap();
 
     Instruction {
         program_id,
@@ -126,3 +158,68 @@ pub fn transfer_out_of_escrow(
         data,
     }
 }
+
+/// Transfers a programmable attribute out of the escrow, providing the attribute
+/// metadata, edition and token records accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_programmable_out_of_escrow(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    metadata: Pubkey,
+    payer: Pubkey,
+    attribute_mint: Pubkey,
+    attribute_src: Pubkey,
+    attribute_dst: Pubkey,
+    escrow_mint: Pubkey,
+    escrow_account: Pubkey,
+    token_program: Pubkey,
+    authority: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    authorization_data: Option<AuthorizationData>,
+    amount: u64,
+) -> Instruction {
+    let mut instruction = transfer_out_of_escrow(
+        program_id,
+        escrow,
+        metadata,
+        payer,
+        attribute_mint,
+        attribute_src,
+        attribute_dst,
+        escrow_mint,
+        escrow_account,
+        token_program,
+        // the authority is required when the programmable accounts are present
+        Some(authority.unwrap_or(payer)),
+        amount,
+    );
+
+    instruction.accounts.extend([
+        AccountMeta::new_readonly(find_metadata_account(&attribute_mint).0, false),
+        AccountMeta::new_readonly(find_master_edition_account(&attribute_mint).0, false),
+        AccountMeta::new(
+            find_token_record_account(&attribute_mint, &attribute_src).0,
+            false,
+        ),
+        AccountMeta::new(
+            find_token_record_account(&attribute_mint, &attribute_dst).0,
+            false,
+        ),
+    ]);
+
+    if let Some(authorization_rules) = authorization_rules {
+        instruction.accounts.extend([
+            AccountMeta::new_readonly(mpl_token_auth_rules::ID, false),
+            AccountMeta::new_readonly(authorization_rules, false),
+        ]);
+    }
+
+    instruction.data = MetadataInstruction::TransferOutOfEscrow(TransferOutOfEscrowArgs {
+        amount,
+        authorization_data,
+    })
+    .try_to_vec()
+    .unwrap();
+
+    instruction
+}
@@ -1,4 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
@@ -15,7 +17,7 @@ use crate::{
     instruction::MetadataInstruction,
     processor::AuthorizationData,
     state::{
-        AssetData, Collection, CollectionDetails, Creator, Data, DataV2, PrintSupply,
+        AssetData, Attribute, Collection, CollectionDetails, Creator, Data, DataV2, PrintSupply,
         TokenStandard, Uses,
     },
     utils::SPL_TOKEN_ID,
@@ -27,6 +29,7 @@ use crate::{
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// Args for create call
 pub struct CreateMetadataAccountArgsV3 {
@@ -40,6 +43,7 @@ pub struct CreateMetadataAccountArgsV3 {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum CreateArgs {
     V1 {
@@ -51,6 +55,7 @@ pub enum CreateArgs {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum MintArgs {
     V1 {
@@ -62,6 +67,7 @@ pub enum MintArgs {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum TransferArgs {
     V1 {
@@ -78,6 +84,7 @@ pub enum TransferArgs {
 /// use a "toggle" type that allows the value to be set, cleared, or remain the same.
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum UpdateArgs {
     V1 {
@@ -418,6 +425,7 @@ impl RuleSetToggle {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// Args for update call
 pub struct UpdateMetadataAccountArgsV2 {
@@ -433,10 +441,41 @@ pub struct UpdateMetadataAccountArgsV2 {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum PrintArgs {
-    V1 { edition: u64 },
-    V2 { edition: u64 },
+    V1 {
+        edition: u64,
+    },
+    V2 {
+        edition: u64,
+    },
+    V3 {
+        edition: u64,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum CloseOrphanedRecordArgs {
+    V1,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum UpdateAttributesArgs {
+    V1 {
+        /// Attributes to add, or to update when the key is already present.
+        set: Vec<Attribute>,
+        /// Keys of the attributes to remove.
+        remove: Vec<String>,
+    },
 }
 
 //----------------------+
@@ -944,3 +983,65 @@ impl InstructionBuilder for super::builders::Resize {
         }
     }
 }
+
+/// Closes a token record or delegate record whose owning account no longer exists.
+///
+/// # Accounts:
+///
+///   0. `[writable]` Token record or delegate record account
+///   1. `[]` Mint account
+///   2. `[optional]` Token account (required for token records)
+///   3. `[optional]` Metadata account (required for delegate records)
+///   4. `[writable]` Token owner or update authority that approved the delegate
+impl InstructionBuilder for super::builders::CloseOrphanedRecord {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new(self.record, false),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new_readonly(self.token.unwrap_or(crate::ID), false),
+            AccountMeta::new_readonly(self.metadata.unwrap_or(crate::ID), false),
+            AccountMeta::new(self.destination, false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::CloseOrphanedRecord(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Adds, updates or removes on-chain attributes of an asset.
+///
+/// # Accounts:
+///
+///   0. `[signer]` Update authority or delegate
+///   1. `[optional]` Delegate record account
+///   2. `[]` Metadata account
+///   3. `[]` Mint account
+///   4. `[writable]` Attributes account
+///   5. `[signer, writable]` Payer
+///   6. `[]` System program
+impl InstructionBuilder for super::builders::UpdateAttributes {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.delegate_record.unwrap_or(crate::ID), false),
+            AccountMeta::new_readonly(self.metadata, false),
+            AccountMeta::new_readonly(self.mint, false),
+            AccountMeta::new(self.attributes, false),
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new_readonly(self.system_program, false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::UpdateAttributes(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
+mod authority;
 mod bubblegum;
 mod burn;
 mod collection;
+#[cfg(feature = "decoder")]
+mod decoder;
 mod delegate;
 mod edition;
 pub(crate) mod escrow;
@@ -11,10 +14,13 @@ mod state;
 mod uses;
 mod verification;
 
+pub use authority::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 pub use bubblegum::*;
 pub use burn::*;
 pub use collection::*;
+#[cfg(feature = "decoder")]
+pub use decoder::*;
 pub use delegate::*;
 pub use edition::*;
 pub use escrow::*;
@@ -25,10 +31,13 @@ use mpl_token_metadata_context_derive::AccountContext;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use shank::ShankInstruction;
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
 pub use state::*;
 pub use uses::*;
 pub use verification::*;
 
+use crate::error::MetadataError;
+
 // Deprecated Instructions
 pub const CREATE_METADATA_ACCOUNT: u8 = 0;
 pub const UPDATE_METADATA_ACCOUNT: u8 = 1;
@@ -44,6 +53,7 @@ pub const MIGRATE: u8 = 48;
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 /// Instructions supported by the Metadata program.
+#[cfg_attr(feature = "decoder", derive(Debug))]
 #[derive(BorshSerialize, BorshDeserialize, Clone, ShankInstruction, AccountContext)]
 #[rustfmt::skip]
 pub enum MetadataInstruction {
@@ -278,6 +288,9 @@ pub enum MetadataInstruction {
 
     /// Utilize or Use an NFT , burns the NFT and returns the lamports to the update authority if the use method is burn and its out of uses.
     /// Use Authority can be the Holder of the NFT, or a Delegated Use Authority.
+    /// Programmable assets must pass every account, using the program id in place of the use_authority_record,
+    /// burner and authorization rules accounts that are not present; when provided, the use is validated against
+    /// their rule set if it defines the `Use` operation.
     #[account(0, writable, name="metadata", desc="Metadata account")]
     #[account(1, writable, name="token_account", desc="Token Account Of NFT")]
     #[account(2, writable, name="mint", desc="Mint of the Metadata")]
@@ -291,6 +304,9 @@ pub enum MetadataInstruction {
     #[account(8, name="rent", desc="Rent info")]
     #[account(9, optional, writable, name="use_authority_record", desc="Use Authority Record PDA If present the program Assumes a delegated use authority")]
     #[account(10, optional, name="burner", desc="Program As Signer (Burner)")]
+    #[account(11, optional, name="token_record", desc="Token record account (programmable assets)")]
+    #[account(12, optional, name="authorization_rules_program", desc="Token Authorization Rules Program")]
+    #[account(13, optional, name="authorization_rules", desc="Token Authorization Rules account")]
     #[legacy_optional_accounts_strategy]
     Utilize(UtilizeArgs),
 
@@ -510,6 +526,9 @@ pub enum MetadataInstruction {
     CloseEscrowAccount,
 
     /// Transfer the token out of Escrow.
+    ///
+    /// The token standard of the attribute metadata, when provided, decides the transfer path:
+    /// programmable attributes also require the authority, edition and token records accounts.
     #[account(0, name="escrow", desc="Escrow account")]
     #[account(1, writable, name="metadata", desc="Metadata account")]
     #[account(2, writable, signer, name="payer", desc="Wallet paying for the transaction and new account")]
@@ -523,6 +542,12 @@ pub enum MetadataInstruction {
     #[account(10, name="token_program", desc="Token program")]
     #[account(11, name="sysvar_instructions", desc="Instructions sysvar account")]
     #[account(12, optional, signer, name="authority", desc="Authority/creator of the escrow account")]
+    #[account(13, optional, name="attribute_metadata", desc="Metadata account of the attribute (pNFT)")]
+    #[account(14, optional, name="attribute_edition", desc="Master edition account of the attribute (pNFT)")]
+    #[account(15, optional, writable, name="attribute_src_token_record", desc="Token record account of the source token account (pNFT)")]
+    #[account(16, optional, writable, name="attribute_dst_token_record", desc="Token record account of the destination token account (pNFT)")]
+    #[account(17, optional, name="authorization_rules_program", desc="Token Authorization Rules Program")]
+    #[account(18, optional, name="authorization_rules", desc="Token Authorization Rules account")]
     #[legacy_optional_accounts_strategy]
     TransferOutOfEscrow(TransferOutOfEscrowArgs),
 
@@ -554,6 +579,10 @@ pub enum MetadataInstruction {
     ///
     /// For Fungible assets:
     /// - Only the token account, if all tokens are burned.
+    ///
+    /// Account indexes 14 and 15, authorization_rules_program and authorization_rules, are optional and
+    /// validate the burn against the rule set of ProgrammableNonFungible assets when it defines the `Burn`
+    /// operation. They are fetched from the remaining accounts.
     #[account(0, signer, writable, name="authority", desc="Asset owner or Utility delegate")]
     #[account(1, optional, writable, name="collection_metadata", desc="Metadata of the Collection")]
     #[account(2, writable, name="metadata", desc="Metadata (pda of ['metadata', program id, mint id])")]
@@ -568,6 +597,8 @@ pub enum MetadataInstruction {
     #[account(11, name="system_program", desc="System program")]
     #[account(12, name="sysvar_instructions", desc="Instructions sysvar account")]
     #[account(13, name="spl_token_program", desc="SPL Token Program")]
+    // #[account(14, optional, name="authorization_rules_program", desc="Token Authorization Rules Program")]
+    // #[account(15, optional, name="authorization_rules", desc="Token Authorization Rules account")]
     Burn(BurnArgs),
 
     /// Creates the metadata and associated accounts for a new or existing mint account.
@@ -656,6 +687,8 @@ pub enum MetadataInstruction {
     Revoke(RevokeArgs),
 
     /// Locks an asset. For non-programmable assets, this will also freeze the token account.
+    /// An optional unlock timestamp allows the token owner to unlock the asset once it passes.
+    /// The token record is required for all non-fungible assets, since it stores the unlock timestamp.
     /// 
     /// The configurable `authorization_rules` only apply to `ProgrammableNonFungible` assets and
     /// it may require additional accounts to validate the rules.
@@ -675,10 +708,12 @@ pub enum MetadataInstruction {
     Lock(LockArgs),
 
     /// Unlocks an asset. For non-programmable assets, this will also thaw the token account.
+    /// The token owner can unlock the asset once the unlock timestamp of the lock has passed.
+    /// The token record is required for all non-fungible assets, since it stores the unlock timestamp.
     /// 
     /// The configurable `authorization_rules` only apply to `ProgrammableNonFungible` assets and
     /// it may require additional accounts to validate the rules.
-    #[account(0, signer, name="authority", desc="Delegate or freeze authority")]
+    #[account(0, signer, name="authority", desc="Delegate, freeze authority or token owner (expired lock)")]
     #[account(1, optional, name="token_owner", desc="Token owner account")]
     #[account(2, writable, name="token", desc="Token account")]
     #[account(3, name="mint", desc="Mint account")]
@@ -806,6 +841,10 @@ pub enum MetadataInstruction {
     /// Given a token account containing the master edition token to prove authority, and a brand new non-metadata-ed mint with one token
     /// make a new Metadata + Edition that is a child of the master edition denoted by this authority token.
     /// Account index 18, holder_delegate_record, is optional and only used for escrowless edition printing. It is fetched from the remaining accounts.
+    /// Account indexes 20 and 21, authorization_rules_program and authorization_rules, are optional and
+    /// validate the print against the rule set of ProgrammableNonFungible master editions when it defines the
+    /// `Print` operation. When present, account indexes 22 and 23, master_edition_mint and master_token_record,
+    /// are also required. They are fetched from the remaining accounts.
     #[account(0, writable, name="edition_metadata", desc="New Metadata key (pda of ['metadata', program id, mint id])")]
     #[account(1, writable, name="edition", desc="New Edition (pda of ['metadata', program id, mint id, 'edition'])")]
     #[account(2, writable, name="edition_mint", desc="Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY")]
@@ -826,6 +865,10 @@ pub enum MetadataInstruction {
     #[account(17, name="system_program", desc="System program")]
     // #[account(18, optional, name="holder_delegate_record", desc="The Delegate Record authorizing escrowless edition printing")]
     // #[account(19, optional, signer, name="delegate", desc="The authority printing the edition for a delegated print")]
+    // #[account(20, optional, name="authorization_rules_program", desc="Token Authorization Rules Program")]
+    // #[account(21, optional, name="authorization_rules", desc="Token Authorization Rules account")]
+    // #[account(22, optional, name="master_edition_mint", desc="Mint of the master edition")]
+    // #[account(23, optional, name="master_token_record", desc="Token record account of the master edition token")]
     #[args(initialize_mint: bool)]
     Print(PrintArgs),
 
@@ -860,6 +903,113 @@ pub enum MetadataInstruction {
     #[account(3, signer, name="authority", desc="Authority to close ownerless accounts")]
     #[account(4, writable, name="destination", desc="The destination account that will receive the rent.")]
     CloseAccounts,
+
+    /// Verifies (or unverifies) a batch of items of a collection.
+    ///
+    /// The metadata accounts of the items are expected as (writable) remaining accounts. Each
+    /// item is processed independently: an item that fails validation is left unchanged and its
+    /// error is reported in the per-item results, which are set as the instruction return data
+    /// (a `Vec<VerificationResult>`). The size of a sized collection is updated once at the end.
+    #[account(0, signer, name="authority", desc="Collection update authority or delegate")]
+    #[account(1, optional, name="delegate_record", desc="Delegate record PDA")]
+    #[account(2, name="collection_mint", desc="Mint of the Collection")]
+    #[account(3, writable, name="collection_metadata", desc="Metadata Account of the Collection")]
+    #[account(4, optional, name="collection_master_edition", desc="Master Edition Account of the Collection Token")]
+    #[account(5, name="system_program", desc="System program")]
+    #[account(6, name="sysvar_instructions", desc="Instructions sysvar account")]
+    #[args(items: Vec<Pubkey>)]
+    #[checked_accounts]
+    VerifyCollectionBatch(VerifyCollectionBatchArgs),
+
+    /// Proposes a new update authority for an asset.
+    ///
+    /// The update authority does not change until the proposed authority signs the
+    /// `AcceptUpdateAuthority` instruction. Proposing again replaces any pending proposal.
+    #[account(0, signer, name="authority", desc="Update authority of the asset")]
+    #[account(1, name="metadata", desc="Metadata account")]
+    #[account(2, name="mint", desc="Mint account")]
+    #[account(3, writable, name="pending_update_authority", desc="Pending update authority account (pda of ['metadata', program id, mint id, 'pending_update_authority'])")]
+    #[account(4, signer, writable, name="payer", desc="Payer")]
+    #[account(5, name="system_program", desc="System program")]
+    #[checked_accounts]
+    ProposeUpdateAuthority(ProposeUpdateAuthorityArgs),
+
+    /// Accepts a proposed update authority for an asset, closing the pending update authority
+    /// account.
+    #[account(0, signer, name="new_update_authority", desc="Proposed update authority")]
+    #[account(1, writable, name="metadata", desc="Metadata account")]
+    #[account(2, writable, name="pending_update_authority", desc="Pending update authority account")]
+    #[account(3, writable, name="update_authority", desc="Update authority that proposed the handoff, which receives the rent")]
+    #[checked_accounts]
+    AcceptUpdateAuthority(AcceptUpdateAuthorityArgs),
+
+    /// Cancels a proposed update authority for an asset, closing the pending update authority
+    /// account.
+    #[account(0, signer, name="authority", desc="Update authority of the asset or proposed update authority")]
+    #[account(1, name="metadata", desc="Metadata account")]
+    #[account(2, writable, name="pending_update_authority", desc="Pending update authority account")]
+    #[account(3, writable, name="update_authority", desc="Update authority that proposed the handoff, which receives the rent")]
+    #[checked_accounts]
+    CancelUpdateAuthority(CancelUpdateAuthorityArgs),
+
+    /// Cleans up an expired delegate. This instruction is permissionless: the metadata
+    /// delegate record is closed, with its rent returned to the update authority that
+    /// approved the delegate, and the token delegate is cleared from the token record.
+    ///
+    /// Token delegates of locked or listed tokens cannot be cleaned up. The spl-token
+    /// delegate of the token account is left in place, since only the token owner can
+    /// revoke it.
+    #[account(0, optional, writable, name="delegate_record", desc="Metadata delegate record account")]
+    #[account(1, name="mint", desc="Mint of metadata")]
+    #[account(2, optional, name="token", desc="Token account of mint")]
+    #[account(3, optional, writable, name="token_record", desc="Token record account")]
+    #[account(4, writable, name="destination", desc="Update authority that approved the delegate, which receives the rent of the delegate record")]
+    #[account(5, signer, writable, name="payer", desc="Payer")]
+    #[account(6, name="system_program", desc="System program")]
+    #[checked_accounts]
+    RevokeExpiredDelegate(RevokeExpiredDelegateArgs),
+
+    /// Rotates the update authority of a batch of assets.
+    ///
+    /// The metadata accounts of the items are expected as (writable) remaining accounts. All
+    /// items must share the same current update authority and, when a collection is specified,
+    /// be verified members of it. Items that already have the new update authority are skipped.
+    #[account(0, signer, name="authority", desc="Current update authority of the items or collection delegate")]
+    #[account(1, optional, name="delegate_record", desc="Delegate record PDA")]
+    #[args(items: Vec<Pubkey>)]
+    #[checked_accounts]
+    UpdateAuthorityBatch(UpdateAuthorityBatchArgs),
+
+    /// Closes a token record or delegate record whose owning account no longer exists.
+    ///
+    /// This instruction is permissionless. A token record can be closed once its token
+    /// account is closed, with the rent refunded to the token owner; a delegate record
+    /// can be closed once the metadata account is closed, with the rent refunded to the
+    /// update authority that approved the delegate.
+    ///
+    /// The owner of a closed token account is derived from the associated token account
+    /// address, so only token records of associated token accounts can be closed.
+    #[account(0, writable, name="record", desc="Token record or delegate record account")]
+    #[account(1, name="mint", desc="Mint of the asset")]
+    #[account(2, optional, name="token", desc="Closed token account (required for token records)")]
+    #[account(3, optional, name="metadata", desc="Closed metadata account (required for delegate records)")]
+    #[account(4, writable, name="destination", desc="Token owner or update authority that approved the delegate")]
+    #[checked_accounts]
+    CloseOrphanedRecord(CloseOrphanedRecordArgs),
+
+    /// Adds, updates or removes on-chain attributes of an asset.
+    ///
+    /// The attributes account is created on the first update and resized as attributes
+    /// are added or removed. Attributes of immutable assets cannot be updated.
+    #[account(0, signer, name="authority", desc="Update authority or delegate")]
+    #[account(1, optional, name="delegate_record", desc="Delegate record PDA")]
+    #[account(2, name="metadata", desc="Metadata account")]
+    #[account(3, name="mint", desc="Mint of metadata")]
+    #[account(4, writable, name="attributes", desc="Attributes account (pda of ['metadata', program id, mint id, 'attributes'])")]
+    #[account(5, signer, writable, name="payer", desc="Payer")]
+    #[account(6, name="system_program", desc="System program")]
+    #[checked_accounts]
+    UpdateAttributes(UpdateAttributesArgs),
 }
 
 pub struct Context<T> {
@@ -868,6 +1018,407 @@ pub struct Context<T> {
     //pub remaining_accounts: Vec<AccountInfo<'a>>,
 }
 
+/// Layout information of an account expected by an instruction, as declared
+/// by its `#[account]` attribute.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountSpec {
+    pub name: &'static str,
+    /// Position of the account in the instruction.
+    pub index: usize,
+    pub writable: bool,
+    /// Indicates whether the account is required to sign the transaction.
+    pub signer: bool,
+    /// Optional accounts are set to the program id when not provided.
+    pub optional: bool,
+    pub description: &'static str,
+}
+
+/// Error returned by the `to_context` function of an instruction when the accounts
+/// do not match its layout.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccountContextError {
+    /// Fewer accounts than expected by the instruction.
+    NotEnoughAccountKeys,
+    /// The account at `index` is required to sign the transaction.
+    NotSigner { index: usize, name: &'static str },
+    /// The account at `index` is required to be writable.
+    NotWritable { index: usize, name: &'static str },
+}
+
+impl From<AccountContextError> for ProgramError {
+    fn from(error: AccountContextError) -> Self {
+        match error {
+            AccountContextError::NotEnoughAccountKeys => ProgramError::NotEnoughAccountKeys,
+            AccountContextError::NotSigner { index, name } => {
+                msg!("{} account (index {}) must be a signer", name, index);
+                MetadataError::AccountNotSigner.into()
+            }
+            AccountContextError::NotWritable { index, name } => {
+                msg!("{} account (index {}) must be writable", name, index);
+                MetadataError::AccountNotWritable.into()
+            }
+        }
+    }
+}
+
+/// Name of the variant of an instruction args type, implemented through the
+/// `ArgsVariantName` derive.
+#[cfg(feature = "decoder")]
+pub trait ArgsVariantName {
+    /// Returns the name of the args variant (enum args only).
+    fn args_variant_name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
 pub trait InstructionBuilder {
     fn instruction(&self) -> solana_program::instruction::Instruction;
 }
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+    use super::*;
+    use crate::error::MetadataError;
+
+    // (address, is_signer, is_writable)
+    fn account_infos(accounts: &[(Pubkey, bool, bool)]) -> &'static [AccountInfo<'static>] {
+        let infos = accounts
+            .iter()
+            .map(|(address, is_signer, is_writable)| {
+                AccountInfo::new(
+                    Box::leak(Box::new(*address)),
+                    *is_signer,
+                    *is_writable,
+                    Box::leak(Box::new(0)),
+                    Box::leak(Box::new([])),
+                    Box::leak(Box::new(crate::ID)),
+                    false,
+                    0,
+                )
+            })
+            .collect::<Vec<_>>();
+        infos.leak()
+    }
+
+    #[test]
+    fn account_specs_match_attributes() {
+        let specs = Transfer::ACCOUNT_SPECS;
+        assert_eq!(specs.len(), 17);
+
+        assert_eq!(
+            specs[7],
+            AccountSpec {
+                name: "owner_token_record",
+                index: 7,
+                writable: true,
+                signer: false,
+                optional: true,
+                description: "Owner token record account",
+            }
+        );
+        assert!(specs[10].signer && specs[10].writable && !specs[10].optional);
+
+        // indices follow the position of the accounts
+        for (index, spec) in Transfer::ACCOUNT_SPECS.iter().enumerate() {
+            assert_eq!(spec.index, index);
+        }
+    }
+
+    #[cfg(feature = "decoder")]
+    #[test]
+    fn account_specs_of_instruction() {
+        let instruction = MetadataInstruction::Delegate(DelegateArgs::StandardV1 { amount: 1 });
+        assert_eq!(instruction.account_specs(), Delegate::ACCOUNT_SPECS);
+        assert_eq!(instruction.account_specs()[1].name, "delegate");
+
+        assert_eq!(
+            MetadataInstruction::SignMetadata.account_specs()[0].description,
+            "Metadata (pda of ['metadata', program id, mint id])"
+        );
+    }
+
+    #[test]
+    fn to_context_validates_checked_accounts() {
+        let authority = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        // signer authority (index 0), writable pending update authority (index 3)
+        // and signer, writable payer (index 4)
+        let mut accounts = (0..6)
+            .map(|_| (Pubkey::new_unique(), false, false))
+            .collect::<Vec<_>>();
+        accounts[0] = (authority, true, false);
+        accounts[3].2 = true;
+        accounts[4] = (payer, true, true);
+        assert!(ProposeUpdateAuthority::to_context(account_infos(&accounts)).is_ok());
+
+        accounts[0] = (authority, false, false);
+        assert_eq!(
+            ProposeUpdateAuthority::to_context(account_infos(&accounts)).err(),
+            Some(AccountContextError::NotSigner {
+                index: 0,
+                name: "authority"
+            })
+        );
+
+        accounts[0] = (authority, true, false);
+        accounts[4] = (payer, true, false);
+        assert_eq!(
+            ProposeUpdateAuthority::to_context(account_infos(&accounts)).err(),
+            Some(AccountContextError::NotWritable {
+                index: 4,
+                name: "payer"
+            })
+        );
+        assert_eq!(
+            ProgramError::from(AccountContextError::NotWritable {
+                index: 4,
+                name: "payer"
+            }),
+            ProgramError::from(MetadataError::AccountNotWritable)
+        );
+
+        assert_eq!(
+            ProposeUpdateAuthority::to_context(account_infos(&accounts[..5])).err(),
+            Some(AccountContextError::NotEnoughAccountKeys)
+        );
+    }
+
+    #[test]
+    fn to_context_skips_missing_optional_accounts() {
+        // delegate record (index 0) and token record (index 3) are optional and writable
+        let mut accounts = vec![
+            (crate::ID, false, false),
+            (Pubkey::new_unique(), false, false),
+            (crate::ID, false, false),
+            (crate::ID, false, false),
+            (Pubkey::new_unique(), false, true),
+            (Pubkey::new_unique(), true, true),
+            (Pubkey::new_unique(), false, false),
+        ];
+        assert!(RevokeExpiredDelegate::to_context(account_infos(&accounts)).is_ok());
+
+        accounts[3] = (Pubkey::new_unique(), false, false);
+        assert_eq!(
+            RevokeExpiredDelegate::to_context(account_infos(&accounts)).err(),
+            Some(AccountContextError::NotWritable {
+                index: 3,
+                name: "token_record"
+            })
+        );
+    }
+
+    #[test]
+    fn to_context_skips_unchecked_instructions() {
+        // instructions without #[checked_accounts] accept read-only, unsigned accounts
+        let accounts = (0..17)
+            .map(|_| (Pubkey::new_unique(), false, false))
+            .collect::<Vec<_>>();
+
+        assert!(SignMetadata::to_context(account_infos(&accounts)).is_ok());
+        assert!(Transfer::to_context(account_infos(&accounts)).is_ok());
+        assert!(Resize::to_context(account_infos(&accounts)).is_ok());
+    }
+
+    #[test]
+    fn to_context_accepts_client_account_metas() {
+        use super::builders::{
+            AcceptUpdateAuthorityBuilder, CancelUpdateAuthorityBuilder, CloseOrphanedRecordBuilder,
+            ProposeUpdateAuthorityBuilder, RevokeExpiredDelegateBuilder, UpdateAttributesBuilder,
+            UpdateAuthorityBatchBuilder, VerifyCollectionBatchBuilder,
+        };
+
+        let key = Pubkey::new_unique;
+        let instructions = vec![
+            VerifyCollectionBatchBuilder::new()
+                .authority(key())
+                .collection_mint(key())
+                .collection_metadata(key())
+                .items(vec![key(), key()])
+                .build(VerifyCollectionBatchArgs::VerifyV1)
+                .unwrap()
+                .instruction(),
+            VerifyCollectionBatchBuilder::new()
+                .authority(key())
+                .delegate_record(key())
+                .collection_mint(key())
+                .collection_metadata(key())
+                .collection_master_edition(key())
+                .items(vec![key()])
+                .build(VerifyCollectionBatchArgs::UnverifyV1)
+                .unwrap()
+                .instruction(),
+            ProposeUpdateAuthorityBuilder::new()
+                .authority(key())
+                .metadata(key())
+                .mint(key())
+                .pending_update_authority(key())
+                .payer(key())
+                .build(ProposeUpdateAuthorityArgs::V1 {
+                    new_update_authority: key(),
+                })
+                .unwrap()
+                .instruction(),
+            AcceptUpdateAuthorityBuilder::new()
+                .new_update_authority(key())
+                .metadata(key())
+                .pending_update_authority(key())
+                .update_authority(key())
+                .build(AcceptUpdateAuthorityArgs::V1)
+                .unwrap()
+                .instruction(),
+            CancelUpdateAuthorityBuilder::new()
+                .authority(key())
+                .metadata(key())
+                .pending_update_authority(key())
+                .update_authority(key())
+                .build(CancelUpdateAuthorityArgs::V1)
+                .unwrap()
+                .instruction(),
+            RevokeExpiredDelegateBuilder::new()
+                .delegate_record(key())
+                .mint(key())
+                .destination(key())
+                .payer(key())
+                .build(RevokeExpiredDelegateArgs::V1)
+                .unwrap()
+                .instruction(),
+            RevokeExpiredDelegateBuilder::new()
+                .mint(key())
+                .token(key())
+                .token_record(key())
+                .destination(key())
+                .payer(key())
+                .build(RevokeExpiredDelegateArgs::V1)
+                .unwrap()
+                .instruction(),
+            UpdateAuthorityBatchBuilder::new()
+                .authority(key())
+                .items(vec![key(), key()])
+                .build(UpdateAuthorityBatchArgs::V1 {
+                    new_update_authority: key(),
+                    collection: None,
+                })
+                .unwrap()
+                .instruction(),
+            UpdateAuthorityBatchBuilder::new()
+                .authority(key())
+                .delegate_record(key())
+                .items(vec![key()])
+                .build(UpdateAuthorityBatchArgs::V1 {
+                    new_update_authority: key(),
+                    collection: Some(key()),
+                })
+                .unwrap()
+                .instruction(),
+            CloseOrphanedRecordBuilder::new()
+                .record(key())
+                .mint(key())
+                .token(key())
+                .destination(key())
+                .build(CloseOrphanedRecordArgs::V1)
+                .unwrap()
+                .instruction(),
+            CloseOrphanedRecordBuilder::new()
+                .record(key())
+                .mint(key())
+                .metadata(key())
+                .destination(key())
+                .build(CloseOrphanedRecordArgs::V1)
+                .unwrap()
+                .instruction(),
+            UpdateAttributesBuilder::new()
+                .authority(key())
+                .metadata(key())
+                .mint(key())
+                .attributes(key())
+                .payer(key())
+                .build(UpdateAttributesArgs::V1 {
+                    set: vec![],
+                    remove: vec![],
+                })
+                .unwrap()
+                .instruction(),
+            UpdateAttributesBuilder::new()
+                .authority(key())
+                .delegate_record(key())
+                .metadata(key())
+                .mint(key())
+                .attributes(key())
+                .payer(key())
+                .build(UpdateAttributesArgs::V1 {
+                    set: vec![],
+                    remove: vec![],
+                })
+                .unwrap()
+                .instruction(),
+        ];
+
+        for instruction in instructions {
+            let accounts = account_infos(
+                &instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| (meta.pubkey, meta.is_signer, meta.is_writable))
+                    .collect::<Vec<_>>(),
+            );
+
+            let error = match MetadataInstruction::try_from_slice(&instruction.data).unwrap() {
+                MetadataInstruction::VerifyCollectionBatch(_) => {
+                    VerifyCollectionBatch::to_context(accounts).err()
+                }
+                MetadataInstruction::ProposeUpdateAuthority(_) => {
+                    ProposeUpdateAuthority::to_context(accounts).err()
+                }
+                MetadataInstruction::AcceptUpdateAuthority(_) => {
+                    AcceptUpdateAuthority::to_context(accounts).err()
+                }
+                MetadataInstruction::CancelUpdateAuthority(_) => {
+                    CancelUpdateAuthority::to_context(accounts).err()
+                }
+                MetadataInstruction::RevokeExpiredDelegate(_) => {
+                    RevokeExpiredDelegate::to_context(accounts).err()
+                }
+                MetadataInstruction::UpdateAuthorityBatch(_) => {
+                    UpdateAuthorityBatch::to_context(accounts).err()
+                }
+                MetadataInstruction::CloseOrphanedRecord(_) => {
+                    CloseOrphanedRecord::to_context(accounts).err()
+                }
+                MetadataInstruction::UpdateAttributes(_) => {
+                    UpdateAttributes::to_context(accounts).err()
+                }
+                _ => unreachable!("instruction without checked accounts"),
+            };
+
+            assert_eq!(error, None);
+        }
+    }
+
+    #[test]
+    fn to_context_parses_legacy_optional_accounts() {
+        // fungible burn: the optional accounts are passed as read-only program ids
+        let instruction = super::builders::BurnBuilder::new()
+            .authority(Pubkey::new_unique())
+            .metadata(Pubkey::new_unique())
+            .mint(Pubkey::new_unique())
+            .token(Pubkey::new_unique())
+            .build(BurnArgs::V1 { amount: 1 })
+            .unwrap()
+            .instruction();
+
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey, meta.is_signer, meta.is_writable))
+            .collect::<Vec<_>>();
+
+        let context = Burn::to_context(account_infos(&accounts)).unwrap();
+        assert!(context.accounts.collection_metadata_info.is_none());
+        assert!(context.accounts.edition_info.is_none());
+        assert!(context.accounts.token_record_info.is_none());
+    }
+}
@@ -1,4 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use solana_program::instruction::{AccountMeta, Instruction};
@@ -8,16 +10,25 @@ use crate::processor::AuthorizationData;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum LockArgs {
     V1 {
         /// Required authorization data to validate the request.
         authorization_data: Option<AuthorizationData>,
     },
+    V2 {
+        /// Unix timestamp after which the holder can unlock the asset without the
+        /// delegate (the lock never expires when not set).
+        unlock_after: Option<i64>,
+        /// Required authorization data to validate the request.
+        authorization_data: Option<AuthorizationData>,
+    },
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum UnlockArgs {
     V1 {
@@ -28,6 +39,10 @@ pub enum UnlockArgs {
 
 /// Locks an asset. For non-programmable assets, this will also freeze the token account.
 ///
+/// When an unlock timestamp is specified, it is stored on the token record account so the
+/// holder can unlock the asset once it passes; non-programmable assets require the token
+/// record account in this case, which is created if needed.
+///
 /// # Accounts:
 ///
 ///   0. `[signer]` Delegate account
@@ -84,9 +99,12 @@ impl InstructionBuilder for super::builders::Lock {
 
 /// Unlocks an asset. For non-programmable assets, this will also thaw the token account.
 ///
+/// The holder (token owner) can also unlock the asset once the unlock timestamp of the lock
+/// has passed.
+///
 /// # Accounts:
 ///
-///   0. `[signer]` Delegate account
+///   0. `[signer]` Delegate, freeze authority or token owner account
 ///   1. `[optional]` Token owner
 ///   2. `[writable]` Token account
 ///   3. `[]` Mint account
@@ -1,4 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use solana_program::{
@@ -11,6 +13,7 @@ use crate::{instruction::MetadataInstruction, processor::AuthorizationData, util
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct ApproveUseAuthorityArgs {
     pub number_of_uses: u64,
@@ -18,6 +21,7 @@ pub struct ApproveUseAuthorityArgs {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct UtilizeArgs {
     pub number_of_uses: u64,
@@ -25,6 +29,7 @@ pub struct UtilizeArgs {
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum UseArgs {
     V1 {
@@ -149,6 +154,9 @@ pub fn revoke_use_authority(
 ///   7. `[]` System program
 ///   8. Optional `[]` Rent info
 ///   9. Optional `[writable]` Use Authority Record PDA If present the program Assumes a delegated use authority
+///   10. Optional `[]` Token Record account of programmable assets validating the rule set
+///   11. Optional `[]` Token Authorization Rules Program
+///   12. Optional `[]` Token Authorization Rules account
 #[allow(clippy::too_many_arguments)]
 pub fn utilize(
     program_id: Pubkey,
@@ -1,4 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "decoder")]
+use mpl_token_metadata_context_derive::ArgsVariantName;
 #[cfg(feature = "serde-feature")]
 use serde::{Deserialize, Serialize};
 use solana_program::instruction::{AccountMeta, Instruction};
@@ -8,12 +10,36 @@ use crate::instruction::MetadataInstruction;
 
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub enum VerificationArgs {
     CreatorV1,
     CollectionV1,
 }
 
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "decoder", derive(ArgsVariantName))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum VerifyCollectionBatchArgs {
+    VerifyV1,
+    UnverifyV1,
+}
+
+/// Result of a batch verification for a single item, reported in the same order as the
+/// item metadata accounts.
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum VerificationResult {
+    /// The verified status of the item was changed.
+    Updated,
+    /// The item was already in the requested state.
+    Unchanged,
+    /// The item was not changed; `error` is the `ProgramError` (as `u64`) that prevented it.
+    Failed { error: u64 },
+}
+
 /// Verifies a creator or collection for an asset.
 ///
 /// # Accounts:
@@ -89,3 +115,43 @@ impl InstructionBuilder for super::builders::Unverify {
         }
     }
 }
+
+/// Verifies or unverifies a batch of collection items.
+///
+/// # Accounts:
+///
+///   0. `[signer]` Collection update authority or delegate
+///   1. `[optional]` Delegate record PDA
+///   2. `[]` Mint of the Collection
+///   3. `[writable]` Metadata Account of the Collection
+///   4. `[optional]` Master Edition Account of the Collection Token
+///   5. `[]` System program
+///   6. `[]` Instructions sysvar account
+///   7. `[writable]` Metadata accounts of the items (one account per item)
+impl InstructionBuilder for super::builders::VerifyCollectionBatch {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.authority, true),
+            AccountMeta::new_readonly(self.delegate_record.unwrap_or(crate::ID), false),
+            AccountMeta::new_readonly(self.collection_mint, false),
+            AccountMeta::new(self.collection_metadata, false),
+            AccountMeta::new_readonly(self.collection_master_edition.unwrap_or(crate::ID), false),
+            AccountMeta::new_readonly(self.system_program, false),
+            AccountMeta::new_readonly(self.sysvar_instructions, false),
+        ];
+
+        accounts.extend(
+            self.items
+                .iter()
+                .map(|metadata| AccountMeta::new(*metadata, false)),
+        );
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: MetadataInstruction::VerifyCollectionBatch(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
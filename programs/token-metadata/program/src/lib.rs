@@ -13,9 +13,12 @@ pub mod escrow {
 #[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
 pub mod error;
+pub mod event;
 pub mod instruction;
 pub mod pda;
 pub mod processor;
+#[cfg(feature = "simulator")]
+pub mod simulator;
 pub mod state;
 pub mod utils;
 
@@ -2,7 +2,7 @@ use solana_program::pubkey::Pubkey;
 
 use crate::{
     instruction::{HolderDelegateRole, MetadataDelegateRole},
-    state::TOKEN_RECORD_SEED,
+    state::{ATTRIBUTES_SEED, PENDING_UPDATE_AUTHORITY_SEED, TOKEN_RECORD_SEED},
 };
 
 /// prefix used for PDAs to avoid certain collision attacks:
@@ -135,3 +135,27 @@ pub fn find_token_record_account(mint: &Pubkey, token: &Pubkey) -> (Pubkey, u8)
         &crate::ID,
     )
 }
+
+pub fn find_pending_update_authority_account(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            mint.as_ref(),
+            PENDING_UPDATE_AUTHORITY_SEED.as_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_metadata_attributes_account(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            mint.as_ref(),
+            ATTRIBUTES_SEED.as_bytes(),
+        ],
+        &crate::ID,
+    )
+}
@@ -0,0 +1,73 @@
+use mpl_utils::{assert_signer, close_account_raw};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use super::load_pending_update_authority;
+use crate::{
+    assertions::{assert_keys_equal, assert_owned_by},
+    error::MetadataError,
+    event::MetadataEvent,
+    instruction::{AcceptUpdateAuthority, AcceptUpdateAuthorityArgs},
+    state::{Metadata, TokenMetadataAccount},
+    utils::metadata::clean_write_metadata,
+};
+
+pub fn accept_update_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: AcceptUpdateAuthorityArgs,
+) -> ProgramResult {
+    let context = AcceptUpdateAuthority::to_context(accounts)?;
+
+    match args {
+        AcceptUpdateAuthorityArgs::V1 => accept_update_authority_v1(program_id, context),
+    }
+}
+
+fn accept_update_authority_v1(
+    program_id: &Pubkey,
+    ctx: crate::instruction::Context<AcceptUpdateAuthority>,
+) -> ProgramResult {
+    // signers
+
+    assert_signer(ctx.accounts.new_update_authority_info)?;
+
+    // ownership
+
+    assert_owned_by(ctx.accounts.metadata_info, program_id)?;
+
+    // account relationships
+
+    let mut metadata = Metadata::from_account_info(ctx.accounts.metadata_info)?;
+    let pending = load_pending_update_authority(
+        program_id,
+        ctx.accounts.pending_update_authority_info,
+        &metadata.mint,
+    )?;
+
+    // only the proposed authority can accept the handoff
+    if pending.new_update_authority != *ctx.accounts.new_update_authority_info.key {
+        return Err(MetadataError::PendingUpdateAuthorityMismatch.into());
+    }
+    // the proposal is no longer valid if the update authority changed in the meantime
+    if pending.update_authority != metadata.update_authority {
+        return Err(MetadataError::StalePendingUpdateAuthority.into());
+    }
+    // rent is returned to the authority that proposed the handoff
+    assert_keys_equal(
+        ctx.accounts.update_authority_info.key,
+        &pending.update_authority,
+    )?;
+
+    let before = metadata.clone();
+    metadata.update_authority = pending.new_update_authority;
+    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)?;
+
+    close_account_raw(
+        ctx.accounts.update_authority_info,
+        ctx.accounts.pending_update_authority_info,
+    )?;
+
+    MetadataEvent::updated(&before, &metadata).emit()?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    assertions::assert_owned_by,
+    error::MetadataError,
+    event::MetadataEvent,
+    instruction::{Context, MetadataDelegateRole, UpdateAuthorityBatch, UpdateAuthorityBatchArgs},
+    state::{
+        AuthorityRequest, AuthorityType, Metadata, MetadataDelegateRecord, TokenMetadataAccount,
+    },
+    utils::metadata::clean_write_metadata,
+};
+
+/// Number of accounts of the `UpdateAuthorityBatch` instruction that precede the
+/// item metadata accounts.
+const UPDATE_AUTHORITY_BATCH_ACCOUNTS: usize = 2;
+
+pub fn update_authority_batch<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: UpdateAuthorityBatchArgs,
+) -> ProgramResult {
+    let context = UpdateAuthorityBatch::to_context(accounts)?;
+    let items = &accounts[UPDATE_AUTHORITY_BATCH_ACCOUNTS..];
+
+    if items.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    match args {
+        UpdateAuthorityBatchArgs::V1 {
+            new_update_authority,
+            collection,
+        } => {
+            update_authority_batch_v1(program_id, context, items, new_update_authority, collection)
+        }
+    }
+}
+
+fn update_authority_batch_v1<'a>(
+    program_id: &Pubkey,
+    ctx: Context<UpdateAuthorityBatch<'a>>,
+    items: &'a [AccountInfo<'a>],
+    new_update_authority: Pubkey,
+    collection: Option<Pubkey>,
+) -> ProgramResult {
+    // signers
+
+    assert_signer(ctx.accounts.authority_info)?;
+
+    // authority – this can be either:
+    //  1. the current update authority of the items
+    //  2. an 'AuthorityItem' delegate of the collection: the update authority is the one
+    //     that approved the delegate
+
+    let update_authority = match ctx.accounts.delegate_record_info {
+        Some(delegate_record_info) => {
+            MetadataDelegateRecord::from_account_info(delegate_record_info)?.update_authority
+        }
+        None => *ctx.accounts.authority_info.key,
+    };
+
+    // Note: `ctx.accounts.delegate_record_info` derivation check done inside of
+    // `get_authority_type`; the delegate is only valid when a collection is specified.
+    let authority_response = AuthorityType::get_authority_type(AuthorityRequest {
+        authority: ctx.accounts.authority_info.key,
+        update_authority: &update_authority,
+        collection_mint: collection.as_ref(),
+        metadata_delegate_record_info: ctx.accounts.delegate_record_info,
+        collection_metadata_delegate_roles: vec![MetadataDelegateRole::AuthorityItem],
+        precedence: &[AuthorityType::Metadata, AuthorityType::MetadataDelegate],
+        ..Default::default()
+    })?;
+
+    match authority_response.authority_type {
+        AuthorityType::Metadata | AuthorityType::MetadataDelegate => (),
+        _ => return Err(MetadataError::UpdateAuthorityIncorrect.into()),
+    }
+
+    // the whole batch fails if any of the items is not valid
+    for metadata_info in items {
+        assert_owned_by(metadata_info, program_id)?;
+
+        if !metadata_info.is_writable {
+            return Err(MetadataError::AccountNotWritable.into());
+        }
+
+        let mut metadata = Metadata::from_account_info(metadata_info)?;
+
+        // items rotated by a previous batch are skipped so a batch can be retried
+        if metadata.update_authority == new_update_authority {
+            continue;
+        }
+
+        if metadata.update_authority != update_authority {
+            return Err(MetadataError::UpdateAuthorityIncorrect.into());
+        }
+
+        if let Some(collection) = collection {
+            match &metadata.collection {
+                Some(item_collection) if item_collection.key == collection => {
+                    if !item_collection.verified {
+                        return Err(MetadataError::NotVerifiedMemberOfCollection.into());
+                    }
+                }
+                _ => return Err(MetadataError::NotAMemberOfCollection.into()),
+            }
+        }
+
+        let before = metadata.clone();
+        metadata.update_authority = new_update_authority;
+        clean_write_metadata(&mut metadata, metadata_info)?;
+
+        MetadataEvent::updated(&before, &metadata).emit()?;
+    }
+
+    Ok(())
+}
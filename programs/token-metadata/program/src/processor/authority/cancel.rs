@@ -0,0 +1,61 @@
+use mpl_utils::{assert_signer, close_account_raw};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use super::load_pending_update_authority;
+use crate::{
+    assertions::{assert_keys_equal, assert_owned_by},
+    error::MetadataError,
+    instruction::{CancelUpdateAuthority, CancelUpdateAuthorityArgs},
+    state::{Metadata, TokenMetadataAccount},
+};
+
+pub fn cancel_update_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: CancelUpdateAuthorityArgs,
+) -> ProgramResult {
+    let context = CancelUpdateAuthority::to_context(accounts)?;
+
+    match args {
+        CancelUpdateAuthorityArgs::V1 => cancel_update_authority_v1(program_id, context),
+    }
+}
+
+fn cancel_update_authority_v1(
+    program_id: &Pubkey,
+    ctx: crate::instruction::Context<CancelUpdateAuthority>,
+) -> ProgramResult {
+    // signers
+
+    assert_signer(ctx.accounts.authority_info)?;
+
+    // ownership
+
+    assert_owned_by(ctx.accounts.metadata_info, program_id)?;
+
+    // account relationships
+
+    let metadata = Metadata::from_account_info(ctx.accounts.metadata_info)?;
+    let pending = load_pending_update_authority(
+        program_id,
+        ctx.accounts.pending_update_authority_info,
+        &metadata.mint,
+    )?;
+
+    // the current update authority can withdraw the proposal and the proposed
+    // authority can decline it
+    let authority = ctx.accounts.authority_info.key;
+    if *authority != metadata.update_authority && *authority != pending.new_update_authority {
+        return Err(MetadataError::PendingUpdateAuthorityMismatch.into());
+    }
+    // rent is returned to the authority that proposed the handoff
+    assert_keys_equal(
+        ctx.accounts.update_authority_info.key,
+        &pending.update_authority,
+    )?;
+
+    close_account_raw(
+        ctx.accounts.update_authority_info,
+        ctx.accounts.pending_update_authority_info,
+    )
+}
@@ -0,0 +1,30 @@
+mod accept;
+mod batch;
+mod cancel;
+mod propose;
+
+pub use accept::*;
+pub use batch::*;
+pub use cancel::*;
+pub use propose::*;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    assertions::{assert_keys_equal, assert_owned_by},
+    pda::find_pending_update_authority_account,
+    state::{PendingUpdateAuthority, TokenMetadataAccount},
+};
+
+/// Loads the pending update authority account of the given mint, validating its derivation
+/// and ownership.
+fn load_pending_update_authority(
+    program_id: &Pubkey,
+    pending_update_authority_info: &AccountInfo,
+    mint: &Pubkey,
+) -> Result<PendingUpdateAuthority, ProgramError> {
+    let (pda_key, _) = find_pending_update_authority_account(mint);
+    assert_keys_equal(&pda_key, pending_update_authority_info.key)?;
+    assert_owned_by(pending_update_authority_info, program_id)?;
+
+    PendingUpdateAuthority::from_account_info(pending_update_authority_info)
+}
@@ -0,0 +1,97 @@
+use mpl_utils::{assert_signer, create_or_allocate_account_raw, token::SPL_TOKEN_PROGRAM_IDS};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    assertions::{
+        assert_derivation, assert_keys_equal, assert_owned_by, assert_owner_in,
+        metadata::assert_update_authority_is_correct,
+    },
+    error::MetadataError,
+    instruction::{ProposeUpdateAuthority, ProposeUpdateAuthorityArgs},
+    state::{
+        Metadata, PendingUpdateAuthority, TokenMetadataAccount, PENDING_UPDATE_AUTHORITY_SEED,
+        PREFIX,
+    },
+};
+
+pub fn propose_update_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: ProposeUpdateAuthorityArgs,
+) -> ProgramResult {
+    let context = ProposeUpdateAuthority::to_context(accounts)?;
+
+    match args {
+        ProposeUpdateAuthorityArgs::V1 {
+            new_update_authority,
+        } => propose_update_authority_v1(program_id, context, new_update_authority),
+    }
+}
+
+fn propose_update_authority_v1(
+    program_id: &Pubkey,
+    ctx: crate::instruction::Context<ProposeUpdateAuthority>,
+    new_update_authority: Pubkey,
+) -> ProgramResult {
+    // signers
+
+    assert_signer(ctx.accounts.authority_info)?;
+    assert_signer(ctx.accounts.payer_info)?;
+
+    // ownership
+
+    assert_owned_by(ctx.accounts.metadata_info, program_id)?;
+    assert_owner_in(ctx.accounts.mint_info, &SPL_TOKEN_PROGRAM_IDS)?;
+
+    // key match
+
+    assert_keys_equal(ctx.accounts.system_program_info.key, &system_program::ID)?;
+
+    // account relationships
+
+    let metadata = Metadata::from_account_info(ctx.accounts.metadata_info)?;
+    // mint must match mint account key
+    if metadata.mint != *ctx.accounts.mint_info.key {
+        return Err(MetadataError::MintMismatch.into());
+    }
+    // only the current update authority can propose a new one
+    assert_update_authority_is_correct(&metadata, ctx.accounts.authority_info)?;
+
+    let pending_info = ctx.accounts.pending_update_authority_info;
+
+    let mut signer_seeds = vec![
+        PREFIX.as_bytes(),
+        program_id.as_ref(),
+        ctx.accounts.mint_info.key.as_ref(),
+        PENDING_UPDATE_AUTHORITY_SEED.as_bytes(),
+    ];
+    let bump = &[assert_derivation(program_id, pending_info, &signer_seeds)?];
+    signer_seeds.push(bump);
+
+    if pending_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            *program_id,
+            pending_info,
+            ctx.accounts.system_program_info,
+            ctx.accounts.payer_info,
+            PendingUpdateAuthority::size(),
+            &signer_seeds,
+        )?;
+    } else {
+        // a new proposal replaces the pending one
+        PendingUpdateAuthority::from_account_info(pending_info)?;
+    }
+
+    let pending = PendingUpdateAuthority {
+        bump: bump[0],
+        mint: metadata.mint,
+        update_authority: metadata.update_authority,
+        new_update_authority,
+        ..Default::default()
+    };
+    borsh::to_writer(&mut pending_info.try_borrow_mut_data()?[..], &pending)?;
+
+    Ok(())
+}
@@ -3,10 +3,16 @@ use spl_token_2022::state::Account;
 
 use super::*;
 use crate::{
+    event::MetadataEvent,
     pda::find_token_record_account,
     processor::burn::{fungible::burn_fungible, nonfungible_edition::burn_nonfungible_edition},
-    state::{AuthorityRequest, AuthorityType, TokenDelegateRole, TokenRecord, TokenState},
-    utils::{assert_token_program_matches_package, check_token_standard, thaw, unpack_initialized},
+    state::{
+        AuthorityRequest, AuthorityType, Operation, TokenDelegateRole, TokenRecord, TokenState,
+    },
+    utils::{
+        assert_token_program_matches_package, auth_rules_validate, check_token_standard, thaw,
+        unpack_initialized, AuthRulesValidateParams,
+    },
 };
 
 /// Burn an asset, closing associated accounts.
@@ -35,6 +41,11 @@ use crate::{
 ///
 /// For Fungible assets:
 /// - Only the token account, if all tokens are burned.
+///
+/// Programmable assets with a rule set can also include the authorization rules program
+/// and account (indexes 14 and 15), which are fetched from the remaining accounts. The burn
+/// is only validated against the rule set when they are provided and the rule set defines
+/// the `Burn` operation.
 pub fn burn<'a>(
     program_id: &Pubkey,
     accounts: &'a [AccountInfo<'a>],
@@ -42,14 +53,36 @@ pub fn burn<'a>(
 ) -> ProgramResult {
     let context = Burn::to_context(accounts)?;
 
+    let authorization_rules_program_info = accounts.get(14).filter(|a| a.key != &crate::ID);
+    let authorization_rules_info = accounts.get(15).filter(|a| a.key != &crate::ID);
+
+    if let Some(authorization_rules_program_info) = authorization_rules_program_info {
+        if authorization_rules_program_info.key != &mpl_token_auth_rules::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+    }
+
     match args {
-        BurnArgs::V1 { .. } => burn_v1(program_id, context, args),
+        BurnArgs::V1 { .. } | BurnArgs::V2 { .. } => {
+            burn_v1(program_id, context, args, authorization_rules_info)
+        }
     }
 }
 
 // V1 implementation of the burn instruction.
-fn burn_v1(program_id: &Pubkey, ctx: Context<Burn>, args: BurnArgs) -> ProgramResult {
-    let BurnArgs::V1 { amount } = args;
+fn burn_v1<'a>(
+    program_id: &Pubkey,
+    ctx: Context<Burn<'a>>,
+    args: BurnArgs,
+    authorization_rules_info: Option<&'a AccountInfo<'a>>,
+) -> ProgramResult {
+    let (amount, authorization_data) = match args {
+        BurnArgs::V1 { amount } => (amount, None),
+        BurnArgs::V2 {
+            amount,
+            authorization_data,
+        } => (amount, authorization_data),
+    };
 
     // Validate accounts
 
@@ -201,6 +234,24 @@ fn burn_v1(program_id: &Pubkey, ctx: Context<Burn>, args: BurnArgs) -> ProgramRe
                 return Err(MetadataError::IncorrectTokenState.into());
             }
 
+            // The rule set (if any) can restrict who burns the asset.
+            auth_rules_validate(AuthRulesValidateParams {
+                mint_info: ctx.accounts.mint_info,
+                source_info: None,
+                destination_info: None,
+                authority_info: Some(ctx.accounts.authority_info),
+                owner_info: None,
+                programmable_config: metadata.programmable_config.clone(),
+                amount,
+                auth_data: authorization_data,
+                auth_rules_info: authorization_rules_info,
+                operation: Operation::Burn,
+                is_wallet_to_wallet: false,
+                rule_set_revision: token_record
+                    .rule_set_revision
+                    .map(|revision| revision as usize),
+            })?;
+
             let edition_info = ctx
                 .accounts
                 .edition_info
@@ -260,6 +311,24 @@ fn burn_v1(program_id: &Pubkey, ctx: Context<Burn>, args: BurnArgs) -> ProgramRe
                 return Err(MetadataError::IncorrectTokenState.into());
             }
 
+            // The rule set (if any) can restrict who burns the asset.
+            auth_rules_validate(AuthRulesValidateParams {
+                mint_info: ctx.accounts.mint_info,
+                source_info: None,
+                destination_info: None,
+                authority_info: Some(ctx.accounts.authority_info),
+                owner_info: None,
+                programmable_config: metadata.programmable_config.clone(),
+                amount,
+                auth_data: authorization_data,
+                auth_rules_info: authorization_rules_info,
+                operation: Operation::Burn,
+                is_wallet_to_wallet: false,
+                rule_set_revision: token_record
+                    .rule_set_revision
+                    .map(|revision| revision as usize),
+            })?;
+
             let edition_info = ctx
                 .accounts
                 .edition_info
@@ -302,5 +371,11 @@ fn burn_v1(program_id: &Pubkey, ctx: Context<Burn>, args: BurnArgs) -> ProgramRe
         }
     }
 
+    MetadataEvent::Burned {
+        mint: *ctx.accounts.mint_info.key,
+        amount,
+    }
+    .emit()?;
+
     Ok(())
 }
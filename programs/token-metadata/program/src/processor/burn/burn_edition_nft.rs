@@ -6,6 +6,7 @@ use super::nonfungible_edition::burn_nonfungible_edition;
 use crate::{
     assertions::assert_owned_by,
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{Burn, Context},
     processor::all_account_infos,
     state::{Metadata, TokenMetadataAccount, TokenStandard},
@@ -91,5 +92,13 @@ pub fn process_burn_edition_nft<'a>(
     };
     let context = Context { accounts };
 
-    burn_nonfungible_edition(&context, false, &TokenStandard::NonFungibleEdition)
+    burn_nonfungible_edition(&context, false, &TokenStandard::NonFungibleEdition)?;
+
+    MetadataEvent::Burned {
+        mint: *print_edition_mint_info.key,
+        amount: 1,
+    }
+    .emit()?;
+
+    Ok(())
 }
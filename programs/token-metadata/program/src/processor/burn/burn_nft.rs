@@ -13,6 +13,7 @@ use super::{
 };
 use crate::{
     assertions::assert_owned_by,
+    event::MetadataEvent,
     instruction::{Burn, Context},
     state::{Metadata, TokenMetadataAccount},
     utils::{unpack_initialized, SPL_TOKEN_ID},
@@ -97,5 +98,13 @@ pub fn process_burn_nft<'a>(program_id: &Pubkey, accounts: &'a [AccountInfo<'a>]
         metadata,
         me_close_authority: false,
     };
-    burn_nonfungible(&context, args)
+    burn_nonfungible(&context, args)?;
+
+    MetadataEvent::Burned {
+        mint: *mint_info.key,
+        amount: 1,
+    }
+    .emit()?;
+
+    Ok(())
 }
@@ -1,3 +1,5 @@
+mod orphaned;
+
 use mpl_utils::{assert_signer, close_account_raw, token::SPL_TOKEN_PROGRAM_IDS};
 use solana_program::{program_option::COption, program_pack::Pack, system_program};
 use spl_token_2022::state::Mint;
@@ -9,6 +11,8 @@ use crate::{
     utils::{assert_derivation, assert_owned_by},
 };
 
+pub(crate) use orphaned::*;
+
 use super::*;
 
 pub(crate) fn process_close_accounts<'a>(
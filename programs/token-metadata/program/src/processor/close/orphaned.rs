@@ -0,0 +1,146 @@
+use mpl_utils::{close_account_raw, token::SPL_TOKEN_PROGRAM_IDS};
+use num_traits::FromPrimitive;
+use solana_program::{program_error::ProgramError, system_program};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::{
+    assertions::{assert_keys_equal, assert_keys_equal_with_error},
+    instruction::{CloseOrphanedRecord, CloseOrphanedRecordArgs, Context, HolderDelegateRole},
+    pda::{find_holder_delegate_record_account, find_metadata_account, find_token_record_account},
+    processor::find_metadata_delegate_role,
+    state::{HolderDelegateRecord, MetadataDelegateRecord, DISCRIMINATOR_INDEX},
+    utils::assert_owned_by,
+};
+
+use super::*;
+
+/// Closes a token record or delegate record whose owning account no longer exists.
+///
+/// Anyone can close an orphaned record: a token record once its token account is
+/// closed, with the rent refunded to the token owner; a delegate record once the
+/// metadata account is closed, with the rent refunded to the update authority that
+/// approved the delegate.
+pub(crate) fn close_orphaned_record<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: CloseOrphanedRecordArgs,
+) -> ProgramResult {
+    let context = CloseOrphanedRecord::to_context(accounts)?;
+
+    match args {
+        CloseOrphanedRecordArgs::V1 => close_orphaned_record_v1(program_id, context),
+    }
+}
+
+fn close_orphaned_record_v1(
+    program_id: &Pubkey,
+    ctx: Context<CloseOrphanedRecord>,
+) -> ProgramResult {
+    // ownership
+
+    assert_owned_by(ctx.accounts.record_info, program_id)?;
+
+    let key = ctx
+        .accounts
+        .record_info
+        .try_borrow_data()?
+        .get(DISCRIMINATOR_INDEX)
+        .and_then(|key| FromPrimitive::from_u8(*key))
+        .ok_or(MetadataError::DataTypeMismatch)?;
+
+    match key {
+        Key::TokenRecord => assert_orphaned_token_record(&ctx)?,
+        Key::MetadataDelegate | Key::HolderDelegate => assert_orphaned_delegate_record(&ctx, key)?,
+        _ => return Err(MetadataError::DataTypeMismatch.into()),
+    }
+
+    close_account_raw(ctx.accounts.destination_info, ctx.accounts.record_info)
+}
+
+fn assert_orphaned_token_record(ctx: &Context<CloseOrphanedRecord>) -> ProgramResult {
+    let token_info = ctx
+        .accounts
+        .token_info
+        .ok_or(MetadataError::MissingTokenAccount)?;
+
+    // key match
+
+    let (token_record, _) = find_token_record_account(ctx.accounts.mint_info.key, token_info.key);
+    assert_keys_equal(&token_record, ctx.accounts.record_info.key)?;
+
+    // the token account must be closed
+    if !is_closed(token_info) {
+        return Err(MetadataError::RecordNotOrphaned.into());
+    }
+
+    // the owner of a closed token account is only known through the associated
+    // token account derivation (the token record seeds do not include the owner),
+    // so records of other token accounts cannot be closed by this instruction
+    let is_owner = SPL_TOKEN_PROGRAM_IDS.iter().any(|token_program| {
+        get_associated_token_address_with_program_id(
+            ctx.accounts.destination_info.key,
+            ctx.accounts.mint_info.key,
+            token_program,
+        ) == *token_info.key
+    });
+
+    if !is_owner {
+        return Err(MetadataError::InvalidRentDestination.into());
+    }
+
+    Ok(())
+}
+
+fn assert_orphaned_delegate_record(ctx: &Context<CloseOrphanedRecord>, key: Key) -> ProgramResult {
+    let metadata_info = ctx
+        .accounts
+        .metadata_info
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // key match
+
+    let (metadata, _) = find_metadata_account(ctx.accounts.mint_info.key);
+    assert_keys_equal(&metadata, metadata_info.key)?;
+
+    // the metadata account must be closed
+    if !is_closed(metadata_info) {
+        return Err(MetadataError::RecordNotOrphaned.into());
+    }
+
+    let update_authority = if key == Key::MetadataDelegate {
+        let record = MetadataDelegateRecord::from_account_info(ctx.accounts.record_info)?;
+
+        if record.mint != *ctx.accounts.mint_info.key {
+            return Err(MetadataError::MintMismatch.into());
+        }
+        // the role is not stored on the record, so the derivation is checked for
+        // each of the roles
+        find_metadata_delegate_role(ctx.accounts.record_info.key, &record)?;
+
+        record.update_authority
+    } else {
+        let record = HolderDelegateRecord::from_account_info(ctx.accounts.record_info)?;
+
+        let (holder_delegate_record, _) = find_holder_delegate_record_account(
+            ctx.accounts.mint_info.key,
+            HolderDelegateRole::PrintDelegate,
+            &record.update_authority,
+            &record.delegate,
+        );
+        assert_keys_equal(&holder_delegate_record, ctx.accounts.record_info.key)?;
+
+        record.update_authority
+    };
+
+    // account relationships
+
+    assert_keys_equal_with_error(
+        &update_authority,
+        ctx.accounts.destination_info.key,
+        MetadataError::InvalidRentDestination,
+    )
+}
+
+fn is_closed(account_info: &AccountInfo) -> bool {
+    account_info.data_is_empty() && account_info.owner == &system_program::ID
+}
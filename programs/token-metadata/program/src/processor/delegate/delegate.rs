@@ -1,7 +1,10 @@
 use std::fmt::Display;
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_token_auth_rules::utils::get_latest_revision;
 use mpl_utils::{assert_signer, create_or_allocate_account_raw, token::SPL_TOKEN_PROGRAM_IDS};
+#[cfg(feature = "serde-feature")]
+use serde::{Deserialize, Serialize};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke, program_option::COption,
     pubkey::Pubkey, system_program, sysvar,
@@ -14,12 +17,14 @@ use crate::{
         metadata::{assert_holding_amount, assert_update_authority_is_correct},
     },
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{Context, Delegate, DelegateArgs, HolderDelegateRole, MetadataDelegateRole},
     pda::{find_token_record_account, PREFIX},
     processor::AuthorizationData,
     state::{
-        HolderDelegateRecord, Metadata, MetadataDelegateRecord, Operation, ProgrammableConfig,
-        Resizable, TokenDelegateRole, TokenMetadataAccount, TokenRecord, TokenStandard, TokenState,
+        has_expired, DelegateExpiry, HolderDelegateRecord, Metadata, MetadataDelegateRecord,
+        Operation, ProgrammableConfig, Resizable, TokenDelegateRole, TokenMetadataAccount,
+        TokenRecord, TokenStandard, TokenState,
     },
     utils::{
         assert_token_program_matches_package, auth_rules_validate, freeze, thaw, unpack,
@@ -27,7 +32,9 @@ use crate::{
     },
 };
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub enum DelegateScenario {
     Metadata(MetadataDelegateRole),
     Holder(HolderDelegateRole),
@@ -74,30 +81,75 @@ pub fn delegate<'a>(
 ) -> ProgramResult {
     let context = Delegate::to_context(accounts)?;
 
+    let mint = *context.accounts.mint_info.key;
+    let delegate = *context.accounts.delegate_info.key;
+
     // checks if it is a TokenDelegate creation
     let delegate_args = match &args {
         // Sale
         DelegateArgs::SaleV1 {
             amount,
             authorization_data,
-        } => Some((TokenDelegateRole::Sale, amount, authorization_data)),
+        } => Some((TokenDelegateRole::Sale, amount, None, authorization_data)),
+        DelegateArgs::SaleV2 {
+            amount,
+            expiry,
+            authorization_data,
+        } => Some((TokenDelegateRole::Sale, amount, *expiry, authorization_data)),
         // Transfer
         DelegateArgs::TransferV1 {
             amount,
             authorization_data,
-        } => Some((TokenDelegateRole::Transfer, amount, authorization_data)),
+        } => Some((
+            TokenDelegateRole::Transfer,
+            amount,
+            None,
+            authorization_data,
+        )),
+        DelegateArgs::TransferV2 {
+            amount,
+            expiry,
+            authorization_data,
+        } => Some((
+            TokenDelegateRole::Transfer,
+            amount,
+            *expiry,
+            authorization_data,
+        )),
         // Utility
         DelegateArgs::UtilityV1 {
             amount,
             authorization_data,
-        } => Some((TokenDelegateRole::Utility, amount, authorization_data)),
+        } => Some((TokenDelegateRole::Utility, amount, None, authorization_data)),
+        DelegateArgs::UtilityV2 {
+            amount,
+            expiry,
+            authorization_data,
+        } => Some((
+            TokenDelegateRole::Utility,
+            amount,
+            *expiry,
+            authorization_data,
+        )),
         // Staking
         DelegateArgs::StakingV1 {
             amount,
             authorization_data,
-        } => Some((TokenDelegateRole::Staking, amount, authorization_data)),
+        } => Some((TokenDelegateRole::Staking, amount, None, authorization_data)),
+        DelegateArgs::StakingV2 {
+            amount,
+            expiry,
+            authorization_data,
+        } => Some((
+            TokenDelegateRole::Staking,
+            amount,
+            *expiry,
+            authorization_data,
+        )),
         // Standard
-        DelegateArgs::StandardV1 { amount } => Some((TokenDelegateRole::Standard, amount, &None)),
+        DelegateArgs::StandardV1 { amount } => {
+            Some((TokenDelegateRole::Standard, amount, None, &None))
+        }
         // LockedTransfer
         DelegateArgs::LockedTransferV1 {
             amount,
@@ -106,6 +158,7 @@ pub fn delegate<'a>(
         } => Some((
             TokenDelegateRole::LockedTransfer,
             amount,
+            None,
             authorization_data,
         )),
 
@@ -113,40 +166,107 @@ pub fn delegate<'a>(
         _ => None,
     };
 
-    if let Some((role, amount, authorization_data)) = delegate_args {
+    if let Some((role, amount, expiry, authorization_data)) = delegate_args {
+        assert_valid_expiry(&expiry)?;
+
         // proceed with the delegate creation if we have a match
-        return create_persistent_delegate_v1(
+        create_persistent_delegate_v1(
             program_id,
             context,
             &args,
             role,
             *amount,
+            expiry,
             authorization_data,
-        );
+        )?;
+
+        MetadataEvent::Delegated {
+            mint,
+            delegate,
+            role: DelegateScenario::Token(role),
+        }
+        .emit()?;
+
+        return Ok(());
     }
 
     // checks if it is a MetadataDelegate creation
     let delegate_args = match &args {
         DelegateArgs::CollectionV1 { authorization_data } => {
-            Some((MetadataDelegateRole::Collection, authorization_data))
+            Some((MetadataDelegateRole::Collection, None, authorization_data))
         }
         DelegateArgs::DataV1 { authorization_data } => {
-            Some((MetadataDelegateRole::Data, authorization_data))
-        }
-        DelegateArgs::ProgrammableConfigV1 { authorization_data } => {
-            Some((MetadataDelegateRole::ProgrammableConfig, authorization_data))
-        }
-        DelegateArgs::AuthorityItemV1 { authorization_data } => {
-            Some((MetadataDelegateRole::AuthorityItem, authorization_data))
+            Some((MetadataDelegateRole::Data, None, authorization_data))
         }
+        DelegateArgs::ProgrammableConfigV1 { authorization_data } => Some((
+            MetadataDelegateRole::ProgrammableConfig,
+            None,
+            authorization_data,
+        )),
+        DelegateArgs::AuthorityItemV1 { authorization_data } => Some((
+            MetadataDelegateRole::AuthorityItem,
+            None,
+            authorization_data,
+        )),
         DelegateArgs::DataItemV1 { authorization_data } => {
-            Some((MetadataDelegateRole::DataItem, authorization_data))
-        }
-        DelegateArgs::CollectionItemV1 { authorization_data } => {
-            Some((MetadataDelegateRole::CollectionItem, authorization_data))
+            Some((MetadataDelegateRole::DataItem, None, authorization_data))
         }
+        DelegateArgs::CollectionItemV1 { authorization_data } => Some((
+            MetadataDelegateRole::CollectionItem,
+            None,
+            authorization_data,
+        )),
         DelegateArgs::ProgrammableConfigItemV1 { authorization_data } => Some((
             MetadataDelegateRole::ProgrammableConfigItem,
+            None,
+            authorization_data,
+        )),
+        DelegateArgs::CollectionV2 {
+            expiry,
+            authorization_data,
+        } => Some((
+            MetadataDelegateRole::Collection,
+            *expiry,
+            authorization_data,
+        )),
+        DelegateArgs::DataV2 {
+            expiry,
+            authorization_data,
+        } => Some((MetadataDelegateRole::Data, *expiry, authorization_data)),
+        DelegateArgs::ProgrammableConfigV2 {
+            expiry,
+            authorization_data,
+        } => Some((
+            MetadataDelegateRole::ProgrammableConfig,
+            *expiry,
+            authorization_data,
+        )),
+        DelegateArgs::AuthorityItemV2 {
+            expiry,
+            authorization_data,
+        } => Some((
+            MetadataDelegateRole::AuthorityItem,
+            *expiry,
+            authorization_data,
+        )),
+        DelegateArgs::DataItemV2 {
+            expiry,
+            authorization_data,
+        } => Some((MetadataDelegateRole::DataItem, *expiry, authorization_data)),
+        DelegateArgs::CollectionItemV2 {
+            expiry,
+            authorization_data,
+        } => Some((
+            MetadataDelegateRole::CollectionItem,
+            *expiry,
+            authorization_data,
+        )),
+        DelegateArgs::ProgrammableConfigItemV2 {
+            expiry,
+            authorization_data,
+        } => Some((
+            MetadataDelegateRole::ProgrammableConfigItem,
+            *expiry,
             authorization_data,
         )),
 
@@ -154,8 +274,24 @@ pub fn delegate<'a>(
         _ => None,
     };
 
-    if let Some((role, _authorization_data)) = delegate_args {
-        return create_other_delegate_v1(program_id, context, DelegateScenario::Metadata(role));
+    if let Some((role, expiry, _authorization_data)) = delegate_args {
+        assert_valid_expiry(&expiry)?;
+
+        create_other_delegate_v1(
+            program_id,
+            context,
+            DelegateScenario::Metadata(role),
+            expiry,
+        )?;
+
+        MetadataEvent::Delegated {
+            mint,
+            delegate,
+            role: DelegateScenario::Metadata(role),
+        }
+        .emit()?;
+
+        return Ok(());
     }
 
     // checks if it is a HolderDelegate creation
@@ -169,7 +305,16 @@ pub fn delegate<'a>(
     };
 
     if let Some((role, _authorization_data)) = delegate_args {
-        return create_other_delegate_v1(program_id, context, DelegateScenario::Holder(role));
+        create_other_delegate_v1(program_id, context, DelegateScenario::Holder(role), None)?;
+
+        MetadataEvent::Delegated {
+            mint,
+            delegate,
+            role: DelegateScenario::Holder(role),
+        }
+        .emit()?;
+
+        return Ok(());
     }
 
     // this only happens if we did not find a match
@@ -183,6 +328,7 @@ fn create_other_delegate_v1(
     program_id: &Pubkey,
     ctx: Context<Delegate>,
     delegate_scenario: DelegateScenario,
+    expiry: Option<DelegateExpiry>,
 ) -> ProgramResult {
     // signers
 
@@ -256,6 +402,7 @@ fn create_other_delegate_v1(
         ctx.accounts.payer_info,
         ctx.accounts.system_program_info,
         delegate_scenario,
+        expiry,
     )
 }
 
@@ -270,6 +417,7 @@ fn create_persistent_delegate_v1(
     args: &DelegateArgs,
     role: TokenDelegateRole,
     amount: u64,
+    expiry: Option<DelegateExpiry>,
     authorization_data: &Option<AuthorizationData>,
 ) -> ProgramResult {
     // retrieving required optional accounts
@@ -423,6 +571,7 @@ fn create_persistent_delegate_v1(
 
             token_record.delegate = Some(*ctx.accounts.delegate_info.key);
             token_record.delegate_role = Some(role);
+            token_record.delegate_expiry = expiry;
             token_record.save(
                 token_record_info,
                 ctx.accounts.payer_info,
@@ -534,6 +683,7 @@ fn create_pda_account<'a>(
     payer_info: &'a AccountInfo<'a>,
     system_program_info: &'a AccountInfo<'a>,
     delegate_scenario: DelegateScenario,
+    expiry: Option<DelegateExpiry>,
 ) -> ProgramResult {
     // validates the delegate derivation
 
@@ -564,12 +714,17 @@ fn create_pda_account<'a>(
 
     // allocate the delegate account
 
+    let size = match delegate_scenario {
+        DelegateScenario::Metadata(_) => MetadataDelegateRecord::size(),
+        _ => HolderDelegateRecord::size(),
+    };
+
     create_or_allocate_account_raw(
         *program_id,
         delegate_record_info,
         system_program_info,
         payer_info,
-        MetadataDelegateRecord::size(),
+        size,
         &signer_seeds,
     )?;
 
@@ -580,6 +735,7 @@ fn create_pda_account<'a>(
                 mint: *mint_info.key,
                 delegate: *delegate_info.key,
                 update_authority: *authority_info.key,
+                expiry,
                 ..Default::default()
             };
             borsh::to_writer(&mut delegate_record_info.try_borrow_mut_data()?[..], &pda)?;
@@ -599,3 +755,12 @@ fn create_pda_account<'a>(
 
     Ok(())
 }
+
+/// Asserts that the expiry of a new delegate (if any) has not been reached.
+fn assert_valid_expiry(expiry: &Option<DelegateExpiry>) -> ProgramResult {
+    if has_expired(expiry)? {
+        return Err(MetadataError::InvalidDelegateExpiry.into());
+    }
+
+    Ok(())
+}
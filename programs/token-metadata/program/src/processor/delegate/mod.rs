@@ -1,6 +1,8 @@
 #![allow(clippy::module_inception)]
 mod delegate;
 mod revoke;
+mod revoke_expired;
 
 pub use delegate::*;
 pub use revoke::*;
+pub use revoke_expired::*;
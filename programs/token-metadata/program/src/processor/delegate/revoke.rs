@@ -11,6 +11,7 @@ use crate::{
         metadata::assert_update_authority_is_correct,
     },
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{Context, HolderDelegateRole, MetadataDelegateRole, Revoke, RevokeArgs},
     pda::{
         find_holder_delegate_record_account, find_metadata_delegate_record_account,
@@ -35,6 +36,9 @@ pub fn revoke<'a>(
 ) -> ProgramResult {
     let context = Revoke::to_context(accounts)?;
 
+    let mint = *context.accounts.mint_info.key;
+    let delegate = *context.accounts.delegate_info.key;
+
     // checks if it is a TokenDelegate creation
     let token_delegate = match &args {
         // Sale
@@ -57,7 +61,16 @@ pub fn revoke<'a>(
 
     if let Some(role) = token_delegate {
         // proceed with the delegate revoke if we have a match
-        return revoke_persistent_delegate_v1(program_id, context, role);
+        revoke_persistent_delegate_v1(program_id, context, role)?;
+
+        MetadataEvent::Revoked {
+            mint,
+            delegate,
+            role: DelegateScenario::Token(role),
+        }
+        .emit()?;
+
+        return Ok(());
     }
 
     // checks if it is a MetadataDelegate creation
@@ -74,7 +87,16 @@ pub fn revoke<'a>(
     };
 
     if let Some(role) = metadata_delegate {
-        return revoke_other_delegate_v1(program_id, context, DelegateScenario::Metadata(role));
+        revoke_other_delegate_v1(program_id, context, DelegateScenario::Metadata(role))?;
+
+        MetadataEvent::Revoked {
+            mint,
+            delegate,
+            role: DelegateScenario::Metadata(role),
+        }
+        .emit()?;
+
+        return Ok(());
     }
 
     // checks if it is a HolderDelegate creation
@@ -85,7 +107,16 @@ pub fn revoke<'a>(
     };
 
     if let Some(role) = holder_delegate {
-        return revoke_other_delegate_v1(program_id, context, DelegateScenario::Holder(role));
+        revoke_other_delegate_v1(program_id, context, DelegateScenario::Holder(role))?;
+
+        MetadataEvent::Revoked {
+            mint,
+            delegate,
+            role: DelegateScenario::Holder(role),
+        }
+        .emit()?;
+
+        return Ok(());
     }
 
     // this only happens if we did not find a match
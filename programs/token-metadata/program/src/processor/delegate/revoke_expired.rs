@@ -0,0 +1,191 @@
+use mpl_utils::close_account_raw;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    assertions::{assert_keys_equal, assert_keys_equal_with_error},
+    error::MetadataError,
+    event::MetadataEvent,
+    instruction::{
+        Context, MetadataDelegateRole, RevokeExpiredDelegate, RevokeExpiredDelegateArgs,
+    },
+    pda::{find_token_record_account, PREFIX},
+    processor::DelegateScenario,
+    state::{MetadataDelegateRecord, Resizable, TokenMetadataAccount, TokenRecord, TokenState},
+    utils::assert_owned_by,
+};
+
+const METADATA_DELEGATE_ROLES: [MetadataDelegateRole; 8] = [
+    MetadataDelegateRole::AuthorityItem,
+    MetadataDelegateRole::Collection,
+    MetadataDelegateRole::Use,
+    MetadataDelegateRole::Data,
+    MetadataDelegateRole::ProgrammableConfig,
+    MetadataDelegateRole::DataItem,
+    MetadataDelegateRole::CollectionItem,
+    MetadataDelegateRole::ProgrammableConfigItem,
+];
+
+/// Cleans up an expired delegate.
+///
+/// Anyone can clean up an expired delegate: the metadata delegate record is closed
+/// and its rent returned to the update authority that approved the delegate, while
+/// the token delegate is cleared from the token record. Token delegates of locked or
+/// listed tokens are not cleaned up, since that would change the state of the token.
+pub fn revoke_expired_delegate<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: RevokeExpiredDelegateArgs,
+) -> ProgramResult {
+    let context = RevokeExpiredDelegate::to_context(accounts)?;
+
+    match args {
+        RevokeExpiredDelegateArgs::V1 => revoke_expired_delegate_v1(program_id, context),
+    }
+}
+
+fn revoke_expired_delegate_v1(
+    program_id: &Pubkey,
+    ctx: Context<RevokeExpiredDelegate>,
+) -> ProgramResult {
+    if ctx.accounts.delegate_record_info.is_none() && ctx.accounts.token_record_info.is_none() {
+        return Err(MetadataError::MissingDelegateRecord.into());
+    }
+
+    assert_keys_equal(ctx.accounts.system_program_info.key, &system_program::ID)?;
+
+    if let Some(delegate_record_info) = ctx.accounts.delegate_record_info {
+        revoke_expired_metadata_delegate(
+            program_id,
+            delegate_record_info,
+            ctx.accounts.mint_info.key,
+            ctx.accounts.destination_info,
+        )?;
+    }
+
+    if let Some(token_record_info) = ctx.accounts.token_record_info {
+        let token_info = ctx
+            .accounts
+            .token_info
+            .ok_or(MetadataError::MissingTokenAccount)?;
+
+        revoke_expired_token_delegate(
+            program_id,
+            token_record_info,
+            ctx.accounts.mint_info.key,
+            token_info.key,
+            ctx.accounts.payer_info,
+            ctx.accounts.system_program_info,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn revoke_expired_metadata_delegate<'a>(
+    program_id: &Pubkey,
+    delegate_record_info: &'a AccountInfo<'a>,
+    mint: &Pubkey,
+    destination_info: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    assert_owned_by(delegate_record_info, program_id)?;
+
+    let record = MetadataDelegateRecord::from_account_info(delegate_record_info)
+        .map_err(|_| MetadataError::DelegateNotFound)?;
+
+    if record.mint != *mint {
+        return Err(MetadataError::MintMismatch.into());
+    }
+
+    if !record.is_expired()? {
+        return Err(MetadataError::DelegateNotExpired.into());
+    }
+
+    // the rent is returned to the update authority that paid for the record
+    assert_keys_equal_with_error(
+        &record.update_authority,
+        destination_info.key,
+        MetadataError::InvalidRentDestination,
+    )?;
+
+    // the role is not stored on the record, so we find it through the derivation
+    let role = find_metadata_delegate_role(delegate_record_info.key, &record)?;
+
+    close_account_raw(destination_info, delegate_record_info)?;
+
+    MetadataEvent::Revoked {
+        mint: *mint,
+        delegate: record.delegate,
+        role: DelegateScenario::Metadata(role),
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+fn revoke_expired_token_delegate<'a>(
+    program_id: &Pubkey,
+    token_record_info: &'a AccountInfo<'a>,
+    mint: &Pubkey,
+    token: &Pubkey,
+    payer_info: &'a AccountInfo<'a>,
+    system_program_info: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    let (pda_key, _) = find_token_record_account(mint, token);
+    assert_keys_equal(&pda_key, token_record_info.key)?;
+    assert_owned_by(token_record_info, program_id)?;
+
+    let mut token_record = TokenRecord::from_account_info(token_record_info)?;
+
+    let (delegate, role) = match (token_record.delegate, token_record.delegate_role) {
+        (Some(delegate), Some(role)) if token_record.is_delegate_expired()? => (delegate, role),
+        _ => return Err(MetadataError::DelegateNotExpired.into()),
+    };
+
+    // the state set by the delegate can only be changed through the token owner
+    if !matches!(token_record.state, TokenState::Unlocked) {
+        return Err(MetadataError::LockedToken.into());
+    }
+
+    // the spl-token delegate can only be revoked by the token owner; it has no effect
+    // while the token account is frozen, and is replaced by the next token delegate
+    token_record.clear_delegate();
+    token_record.save(token_record_info, payer_info, system_program_info)?;
+
+    MetadataEvent::Revoked {
+        mint: *mint,
+        delegate,
+        role: DelegateScenario::Token(role),
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+pub(crate) fn find_metadata_delegate_role(
+    delegate_record: &Pubkey,
+    record: &MetadataDelegateRecord,
+) -> Result<MetadataDelegateRole, ProgramError> {
+    METADATA_DELEGATE_ROLES
+        .into_iter()
+        .find(|role| {
+            let role = role.to_string();
+            Pubkey::create_program_address(
+                &[
+                    PREFIX.as_bytes(),
+                    crate::ID.as_ref(),
+                    record.mint.as_ref(),
+                    role.as_bytes(),
+                    record.update_authority.as_ref(),
+                    record.delegate.as_ref(),
+                    &[record.bump],
+                ],
+                &crate::ID,
+            )
+            .map(|key| key == *delegate_record)
+            .unwrap_or(false)
+        })
+        .ok_or_else(|| MetadataError::DerivedKeyInvalid.into())
+}
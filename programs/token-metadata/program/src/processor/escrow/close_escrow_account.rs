@@ -1,16 +1,16 @@
-use mpl_utils::{assert_signer, close_account_raw};
+use mpl_utils::{assert_signer, close_account_raw, token::SPL_TOKEN_PROGRAM_IDS};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, system_program,
 };
 
 use super::find_escrow_seeds;
 use crate::{
-    assertions::{assert_derivation, assert_initialized, assert_keys_equal, assert_owned_by},
+    assertions::{assert_derivation, assert_keys_equal, assert_owned_by, assert_owner_in},
     error::MetadataError,
     pda::{EDITION, PREFIX},
     processor::all_account_infos,
     state::{EscrowAuthority, Metadata, TokenMetadataAccount, TokenOwnedEscrow},
-    utils::SPL_TOKEN_ID,
+    utils::validate_token,
 };
 
 pub fn process_close_escrow_account(
@@ -30,8 +30,8 @@ pub fn process_close_escrow_account(
 
     assert_owned_by(escrow_account_info, &crate::ID)?;
     assert_owned_by(metadata_account_info, &crate::ID)?;
-    assert_owned_by(mint_account_info, &SPL_TOKEN_ID)?;
-    assert_owned_by(token_account_info, &SPL_TOKEN_ID)?;
+    assert_owner_in(mint_account_info, &SPL_TOKEN_PROGRAM_IDS)?;
+    assert_owner_in(token_account_info, &SPL_TOKEN_PROGRAM_IDS)?;
     assert_owned_by(edition_account_info, &crate::ID)?;
     assert_signer(payer_account_info)?;
 
@@ -58,19 +58,15 @@ pub fn process_close_escrow_account(
         ],
     )?;
 
-    let token_account: spl_token_2022::state::Account = assert_initialized(token_account_info)?;
-
-    if token_account.mint != *mint_account_info.key {
-        return Err(MetadataError::MintMismatch.into());
-    }
-
-    if token_account.amount != 1 {
-        return Err(MetadataError::NotEnoughTokens.into());
-    }
-
-    if token_account.mint != metadata.mint {
-        return Err(MetadataError::MintMismatch.into());
-    }
+    // The token account must belong to the same token program as the mint.
+    let token_account = validate_token(
+        mint_account_info,
+        token_account_info,
+        None,
+        mint_account_info.owner,
+        metadata.token_standard,
+        Some(1),
+    )?;
 
     let creator_type = if token_account.owner == *payer_account_info.key {
         EscrowAuthority::TokenOwner
@@ -83,7 +79,6 @@ pub fn process_close_escrow_account(
 
     let bump_seed = assert_derivation(&crate::ID, escrow_account_info, &escrow_seeds)?;
 
-    let token_account: spl_token_2022::state::Account = assert_initialized(token_account_info)?;
     let toe = TokenOwnedEscrow::from_account_info(escrow_account_info)?;
     assert_keys_equal(&toe.base_token, mint_account_info.key)?;
 
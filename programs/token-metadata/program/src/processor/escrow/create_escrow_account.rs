@@ -1,5 +1,5 @@
 use borsh::BorshSerialize;
-use mpl_utils::{assert_signer, create_or_allocate_account_raw};
+use mpl_utils::{assert_signer, create_or_allocate_account_raw, token::SPL_TOKEN_PROGRAM_IDS};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -10,13 +10,13 @@ use solana_program::{
 
 use super::find_escrow_seeds;
 use crate::{
-    assertions::{assert_derivation, assert_initialized, assert_owned_by},
+    assertions::{assert_derivation, assert_owned_by, assert_owner_in},
     error::MetadataError,
     pda::{EDITION, PREFIX},
     state::{
         EscrowAuthority, Key, Metadata, TokenMetadataAccount, TokenOwnedEscrow, TokenStandard,
     },
-    utils::{check_token_standard, SPL_TOKEN_ID},
+    utils::{check_token_standard, validate_token},
 };
 
 pub fn process_create_escrow_account(
@@ -34,10 +34,10 @@ pub fn process_create_escrow_account(
     assert_owned_by(metadata_account_info, &crate::ID)?;
 
     let mint_account_info = next_account_info(account_info_iter)?;
-    assert_owned_by(mint_account_info, &SPL_TOKEN_ID)?;
+    assert_owner_in(mint_account_info, &SPL_TOKEN_PROGRAM_IDS)?;
 
     let token_account_info = next_account_info(account_info_iter)?;
-    assert_owned_by(token_account_info, &SPL_TOKEN_ID)?;
+    assert_owner_in(token_account_info, &SPL_TOKEN_PROGRAM_IDS)?;
 
     let edition_account_info = next_account_info(account_info_iter)?;
     assert_owned_by(edition_account_info, &crate::ID)?;
@@ -94,11 +94,15 @@ pub fn process_create_escrow_account(
     let creator = maybe_authority_info.unwrap_or(payer_account_info);
     assert_signer(creator)?;
 
-    let token_account: spl_token_2022::state::Account = assert_initialized(token_account_info)?;
-
-    if token_account.mint != *mint_account_info.key {
-        return Err(MetadataError::MintMismatch.into());
-    }
+    // The token account must belong to the same token program as the mint.
+    let token_account = validate_token(
+        mint_account_info,
+        token_account_info,
+        None,
+        mint_account_info.owner,
+        Some(token_standard),
+        None,
+    )?;
 
     if token_account.amount < 1 {
         return Err(MetadataError::NotEnoughTokens.into());
@@ -1,25 +1,53 @@
-use mpl_utils::assert_signer;
+use mpl_utils::{
+    assert_signer,
+    token::{TokenTransferCheckedParams, SPL_TOKEN_PROGRAM_IDS},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_option::COption,
     pubkey::Pubkey,
 };
-use spl_token_2022::{generic_token_account::is_initialized_account, state::Account};
+use spl_token_2022::{
+    generic_token_account::is_initialized_account,
+    state::{Account, Mint},
+};
 
 use super::find_escrow_seeds;
 use crate::{
-    assertions::{assert_derivation, assert_owned_by},
+    assertions::{
+        assert_derivation, assert_keys_equal, assert_owned_by, assert_owner_in,
+        metadata::assert_not_locked,
+    },
     error::MetadataError,
     instruction::TransferOutOfEscrowArgs,
-    state::{EscrowAuthority, TokenMetadataAccount, TokenOwnedEscrow},
-    utils::{unpack, SPL_TOKEN_ID},
+    pda::find_token_record_account,
+    processor::TransferScenario,
+    state::{
+        EscrowAuthority, Key, Metadata, Operation, TokenDelegateRole, TokenMetadataAccount,
+        TokenOwnedEscrow, TokenRecord, TokenStandard,
+    },
+    utils::{
+        auth_rules_validate, clear_close_authority, close_program_account,
+        create_token_record_account, frozen_transfer, unpack, validate_token,
+        AuthRulesValidateParams, ClearCloseAuthorityParams,
+    },
 };
 
-#[allow(deprecated)]
-pub fn process_transfer_out_of_escrow(
-    _program_id: &Pubkey,
-    accounts: &[AccountInfo],
+/// Accounts required to transfer a programmable attribute out of the escrow.
+struct ProgrammableAccounts<'a> {
+    metadata: Metadata,
+    edition_info: &'a AccountInfo<'a>,
+    owner_token_record_info: &'a AccountInfo<'a>,
+    destination_token_record_info: &'a AccountInfo<'a>,
+    authorization_rules_info: Option<&'a AccountInfo<'a>>,
+}
+
+pub fn process_transfer_out_of_escrow<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
     args: TransferOutOfEscrowArgs,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
@@ -36,19 +64,20 @@ pub fn process_transfer_out_of_escrow(
     assert_signer(payer_info)?;
 
     let attribute_mint_info = next_account_info(account_info_iter)?;
-    assert_owned_by(attribute_mint_info, &SPL_TOKEN_ID)?;
+    assert_owner_in(attribute_mint_info, &SPL_TOKEN_PROGRAM_IDS)?;
 
     let attribute_src_info = next_account_info(account_info_iter)?;
-    assert_owned_by(attribute_src_info, &SPL_TOKEN_ID)?;
+    assert_owner_in(attribute_src_info, &SPL_TOKEN_PROGRAM_IDS)?;
 
     // We don't check attribute destination ownership because it may not be initialized yet.
     let attribute_dst_info = next_account_info(account_info_iter)?;
 
+    // The escrow mint can belong to a different token program than the attribute mint.
     let escrow_mint_info = next_account_info(account_info_iter)?;
-    assert_owned_by(escrow_mint_info, &SPL_TOKEN_ID)?;
+    assert_owner_in(escrow_mint_info, &SPL_TOKEN_PROGRAM_IDS)?;
 
     let escrow_account_info = next_account_info(account_info_iter)?;
-    assert_owned_by(escrow_account_info, &SPL_TOKEN_ID)?;
+    assert_owner_in(escrow_account_info, &SPL_TOKEN_PROGRAM_IDS)?;
 
     let system_program_info = next_account_info(account_info_iter)?;
     if system_program_info.key != &solana_program::system_program::ID {
@@ -60,8 +89,11 @@ pub fn process_transfer_out_of_escrow(
         return Err(MetadataError::InvalidAssociatedTokenAccountProgram.into());
     }
 
+    // The token program must be the one that owns the attribute mint.
     let token_program_info = next_account_info(account_info_iter)?;
-    if token_program_info.key != &SPL_TOKEN_ID {
+    if !SPL_TOKEN_PROGRAM_IDS.contains(token_program_info.key)
+        || attribute_mint_info.owner != token_program_info.key
+    {
         return Err(MetadataError::InvalidTokenProgram.into());
     }
 
@@ -71,16 +103,69 @@ pub fn process_transfer_out_of_escrow(
     }
 
     // Allow the option to set a different authority than the payer.
-    let is_using_authority = account_info_iter.len() == 1;
-    let maybe_authority_info: Option<&AccountInfo> = if is_using_authority {
-        let auth = next_account_info(account_info_iter)?;
-        assert_signer(auth)?;
-        Some(auth)
-    } else {
-        None
+    let maybe_authority_info: Option<&AccountInfo> = match account_info_iter.next() {
+        Some(auth) => {
+            assert_signer(auth)?;
+            Some(auth)
+        }
+        None => None,
     };
     let authority = maybe_authority_info.unwrap_or(payer_info);
 
+    // The token standard of the attribute decides the transfer path: programmable
+    // attributes require their edition and token records, and optionally the auth
+    // rules accounts.
+    let attribute_metadata = match account_info_iter.next() {
+        Some(attribute_metadata_info) => {
+            assert_owned_by(attribute_metadata_info, program_id)?;
+            let attribute_metadata = Metadata::from_account_info(attribute_metadata_info)?;
+            if attribute_metadata.mint != *attribute_mint_info.key {
+                return Err(MetadataError::MintMismatch.into());
+            }
+            Some(attribute_metadata)
+        }
+        None => None,
+    };
+
+    let programmable_accounts = match attribute_metadata {
+        Some(metadata)
+            if matches!(
+                metadata.token_standard,
+                Some(TokenStandard::ProgrammableNonFungible)
+                    | Some(TokenStandard::ProgrammableNonFungibleEdition)
+            ) =>
+        {
+            let edition_info = next_account_info(account_info_iter)?;
+            assert_owned_by(edition_info, program_id)?;
+
+            let owner_token_record_info = next_account_info(account_info_iter)?;
+            assert_owned_by(owner_token_record_info, program_id)?;
+
+            // The destination token record may not be initialized yet.
+            let destination_token_record_info = next_account_info(account_info_iter)?;
+
+            if let Some(auth_rules_program_info) = account_info_iter.next() {
+                if auth_rules_program_info.key != &mpl_token_auth_rules::ID {
+                    return Err(ProgramError::IncorrectProgramId);
+                }
+            }
+
+            let authorization_rules_info = account_info_iter.next();
+            if let Some(authorization_rules_info) = authorization_rules_info {
+                assert_owned_by(authorization_rules_info, &mpl_token_auth_rules::ID)?;
+            }
+
+            Some(ProgrammableAccounts {
+                metadata,
+                edition_info,
+                owner_token_record_info,
+                destination_token_record_info,
+                authorization_rules_info,
+            })
+        }
+        _ => None,
+    };
+
     let toe = TokenOwnedEscrow::from_account_info(escrow_info)?;
 
     // Derive the seeds for PDA signing.
@@ -97,7 +182,7 @@ pub fn process_transfer_out_of_escrow(
                 payer_info.key,
                 payer_info.key,
                 attribute_mint_info.key,
-                &SPL_TOKEN_ID,
+                token_program_info.key,
             );
 
         invoke(
@@ -114,10 +199,14 @@ pub fn process_transfer_out_of_escrow(
     }
 
     // Deserialize the token accounts and perform checks.
-    let attribute_src = unpack::<Account>(&attribute_src_info.data.borrow())?;
-    if attribute_src.mint != *attribute_mint_info.key {
-        return Err(MetadataError::MintMismatch.into());
-    }
+    let attribute_src = validate_token(
+        attribute_mint_info,
+        attribute_src_info,
+        None,
+        token_program_info.key,
+        None,
+        None,
+    )?;
     if attribute_src.amount < args.amount {
         return Err(MetadataError::InsufficientTokens.into());
     }
@@ -126,10 +215,14 @@ pub fn process_transfer_out_of_escrow(
     }
 
     // Check that the authority matches based on the authority type.
-    let escrow_account = unpack::<Account>(&escrow_account_info.data.borrow())?;
-    if escrow_account.mint != *escrow_mint_info.key {
-        return Err(MetadataError::MintMismatch.into());
-    }
+    let escrow_account = validate_token(
+        escrow_mint_info,
+        escrow_account_info,
+        None,
+        escrow_mint_info.owner,
+        None,
+        None,
+    )?;
     if escrow_account.amount != 1 {
         return Err(MetadataError::AmountMustBeGreaterThanZero.into());
     }
@@ -147,25 +240,160 @@ pub fn process_transfer_out_of_escrow(
         }
     }
 
-    let attribute_dst = unpack::<Account>(&attribute_dst_info.data.borrow())?;
-    if attribute_dst.mint != *attribute_mint_info.key {
-        return Err(MetadataError::MintMismatch.into());
+    let attribute_dst = validate_token(
+        attribute_mint_info,
+        attribute_dst_info,
+        None,
+        token_program_info.key,
+        None,
+        None,
+    )?;
+
+    let attribute_mint = unpack::<Mint>(&attribute_mint_info.data.borrow())?;
+
+    // Programmable attributes are transferred through the same thaw/validate/freeze
+    // flow used by the 'Transfer' instruction, with the escrow as the holder.
+    if let Some(programmable_accounts) = programmable_accounts {
+        let attribute_metadata = programmable_accounts.metadata;
+
+        // The payer receives the attribute, so the destination must be owned by it.
+        if attribute_dst.owner != *payer_info.key {
+            return Err(MetadataError::IncorrectOwner.into());
+        }
+
+        let owner_token_record_info = programmable_accounts.owner_token_record_info;
+        let destination_token_record_info = programmable_accounts.destination_token_record_info;
+
+        let (pda_key, _) =
+            find_token_record_account(attribute_mint_info.key, attribute_src_info.key);
+        assert_keys_equal(&pda_key, owner_token_record_info.key)?;
+
+        let (new_pda_key, _) =
+            find_token_record_account(attribute_mint_info.key, attribute_dst_info.key);
+        assert_keys_equal(&new_pda_key, destination_token_record_info.key)?;
+
+        // Same as the 'Transfer' instruction, we do not know the type of an existing
+        // delegate on the destination if it does not match its token record.
+        if let COption::Some(delegate) = attribute_dst.delegate {
+            if destination_token_record_info.data_is_empty() {
+                return Err(MetadataError::DelegateAlreadyExists.into());
+            }
+
+            let destination_token_record =
+                TokenRecord::from_account_info(destination_token_record_info)?;
+
+            if destination_token_record.delegate != Some(delegate) {
+                return Err(MetadataError::DelegateAlreadyExists.into());
+            }
+        }
+
+        let owner_token_record = TokenRecord::from_account_info(owner_token_record_info)?;
+
+        // Same as the 'Transfer' instruction, a locked attribute cannot be transferred
+        // and a listed one can only be transferred by its sale delegate.
+        assert_not_locked(&owner_token_record)?;
+
+        let is_sale_delegate = owner_token_record
+            .delegate_role
+            .map(|role| role == TokenDelegateRole::Sale)
+            .unwrap_or(false)
+            && !owner_token_record.is_delegate_expired()?;
+
+        if is_sale_delegate {
+            return Err(MetadataError::OnlySaleDelegateCanTransfer.into());
+        }
+
+        auth_rules_validate(AuthRulesValidateParams {
+            mint_info: attribute_mint_info,
+            owner_info: None,
+            authority_info: Some(escrow_info),
+            source_info: Some(escrow_info),
+            destination_info: Some(payer_info),
+            programmable_config: attribute_metadata.programmable_config,
+            amount: args.amount,
+            auth_data: args.authorization_data,
+            auth_rules_info: programmable_accounts.authorization_rules_info,
+            operation: Operation::Transfer {
+                scenario: TransferScenario::Holder,
+            },
+            is_wallet_to_wallet: false,
+            rule_set_revision: owner_token_record
+                .rule_set_revision
+                .map(|revision| revision as usize),
+        })?;
+
+        frozen_transfer(
+            TokenTransferCheckedParams {
+                mint: attribute_mint_info.clone(),
+                source: attribute_src_info.clone(),
+                destination: attribute_dst_info.clone(),
+                amount: args.amount,
+                authority: escrow_info.clone(),
+                authority_signer_seeds: Some(&escrow_authority_seeds),
+                token_program: token_program_info.clone(),
+                decimals: attribute_mint.decimals,
+            },
+            attribute_metadata.edition_nonce,
+            Some(programmable_accounts.edition_info),
+        )?;
+
+        clear_close_authority(ClearCloseAuthorityParams {
+            token_info: attribute_src_info,
+            mint_info: attribute_mint_info,
+            token: &attribute_src,
+            master_edition_info: programmable_accounts.edition_info,
+            authority_info: programmable_accounts.edition_info,
+            spl_token_program_info: token_program_info,
+            edition_bump: attribute_metadata.edition_nonce,
+        })?;
+
+        if destination_token_record_info.data_is_empty() {
+            create_token_record_account(
+                program_id,
+                destination_token_record_info,
+                attribute_mint_info,
+                attribute_dst_info,
+                payer_info,
+                system_program_info,
+            )?;
+        }
+
+        close_escrow_token(
+            attribute_src_info,
+            payer_info,
+            escrow_info,
+            token_program_info,
+            &escrow_authority_seeds,
+        )?;
+
+        // Close the source token record after the CPI calls so as to avoid
+        // unbalanced accounts errors due to the manual lamport math.
+        return close_program_account(owner_token_record_info, payer_info, Key::TokenRecord);
+    }
+
+    // The escrow token account has no delegate, so it can only be frozen when the
+    // attribute is programmable, which requires its metadata.
+    if attribute_src.is_frozen() {
+        return Err(MetadataError::MissingTokenRecord.into());
     }
 
     // Transfer the token out of the escrow to the destination ATA.
-    let transfer_ix = spl_token_2022::instruction::transfer(
-        &SPL_TOKEN_ID,
+    let transfer_ix = spl_token_2022::instruction::transfer_checked(
+        token_program_info.key,
         attribute_src_info.key,
+        attribute_mint_info.key,
         attribute_dst_info.key,
         escrow_info.key,
         &[escrow_info.key],
         args.amount,
+        attribute_mint.decimals,
     )?;
 
     invoke_signed(
         &transfer_ix,
         &[
             attribute_src_info.clone(),
+            attribute_mint_info.clone(),
             attribute_dst_info.clone(),
             escrow_info.clone(),
             token_program_info.clone(),
@@ -173,12 +401,28 @@ pub fn process_transfer_out_of_escrow(
         &[&escrow_authority_seeds],
     )?;
 
+    close_escrow_token(
+        attribute_src_info,
+        payer_info,
+        escrow_info,
+        token_program_info,
+        &escrow_authority_seeds,
+    )
+}
+
+/// Closes the escrow token account once it is empty and returns funds to the payer.
+fn close_escrow_token<'a>(
+    attribute_src_info: &'a AccountInfo<'a>,
+    payer_info: &'a AccountInfo<'a>,
+    escrow_info: &'a AccountInfo<'a>,
+    token_program_info: &'a AccountInfo<'a>,
+    escrow_authority_seeds: &[&[u8]],
+) -> ProgramResult {
     let attribute_src = unpack::<Account>(&attribute_src_info.data.borrow())?;
 
-    // Close the source ATA and return funds to the user.
     if attribute_src.amount == 0 {
         let close_ix = spl_token_2022::instruction::close_account(
-            &SPL_TOKEN_ID,
+            token_program_info.key,
             attribute_src_info.key,
             payer_info.key,
             escrow_info.key,
@@ -193,7 +437,7 @@ pub fn process_transfer_out_of_escrow(
                 escrow_info.clone(),
                 token_program_info.clone(),
             ],
-            &[&escrow_authority_seeds],
+            &[escrow_authority_seeds],
         )?;
     }
 
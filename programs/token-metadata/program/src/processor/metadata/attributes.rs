@@ -0,0 +1,148 @@
+use borsh::BorshSerialize;
+use mpl_utils::{assert_signer, create_or_allocate_account_raw, token::SPL_TOKEN_PROGRAM_IDS};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, system_program,
+};
+
+use crate::{
+    assertions::{assert_derivation, assert_keys_equal, assert_owned_by, assert_owner_in},
+    error::MetadataError,
+    event::{MetadataEvent, UpdatedField},
+    instruction::{Context, MetadataDelegateRole, UpdateAttributes, UpdateAttributesArgs},
+    state::{
+        Attribute, AuthorityRequest, AuthorityType, Collection, Metadata, MetadataAttributes,
+        TokenMetadataAccount, ATTRIBUTES_SEED, PREFIX,
+    },
+};
+
+pub fn update_attributes<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: UpdateAttributesArgs,
+) -> ProgramResult {
+    let context = UpdateAttributes::to_context(accounts)?;
+
+    match args {
+        UpdateAttributesArgs::V1 { set, remove } => {
+            update_attributes_v1(program_id, context, set, remove)
+        }
+    }
+}
+
+fn update_attributes_v1<'a>(
+    program_id: &Pubkey,
+    ctx: Context<UpdateAttributes<'a>>,
+    set: Vec<Attribute>,
+    remove: Vec<String>,
+) -> ProgramResult {
+    // signers
+
+    assert_signer(ctx.accounts.authority_info)?;
+    assert_signer(ctx.accounts.payer_info)?;
+
+    // ownership
+
+    assert_owned_by(ctx.accounts.metadata_info, program_id)?;
+    assert_owner_in(ctx.accounts.mint_info, &SPL_TOKEN_PROGRAM_IDS)?;
+
+    // key match
+
+    assert_keys_equal(ctx.accounts.system_program_info.key, &system_program::ID)?;
+
+    // account relationships
+
+    let metadata = Metadata::from_account_info(ctx.accounts.metadata_info)?;
+    // mint must match mint account key
+    if metadata.mint != *ctx.accounts.mint_info.key {
+        return Err(MetadataError::MintMismatch.into());
+    }
+
+    // attributes follow the mutability of the metadata
+    if !metadata.is_mutable {
+        return Err(MetadataError::DataIsImmutable.into());
+    }
+
+    // authority – this can be either:
+    //  1. the update authority of the asset
+    //  2. a 'Data' or 'DataItem' delegate of the asset or a 'Data' delegate of its
+    //     collection
+    let collection_mint = metadata
+        .collection
+        .as_ref()
+        .map(|Collection { key, .. }| key);
+
+    let authority_response = AuthorityType::get_authority_type(AuthorityRequest {
+        authority: ctx.accounts.authority_info.key,
+        update_authority: &metadata.update_authority,
+        mint: ctx.accounts.mint_info.key,
+        collection_mint,
+        metadata_delegate_record_info: ctx.accounts.delegate_record_info,
+        metadata_delegate_roles: vec![MetadataDelegateRole::Data, MetadataDelegateRole::DataItem],
+        collection_metadata_delegate_roles: vec![MetadataDelegateRole::Data],
+        precedence: &[AuthorityType::Metadata, AuthorityType::MetadataDelegate],
+        ..Default::default()
+    })?;
+
+    match authority_response.authority_type {
+        AuthorityType::Metadata | AuthorityType::MetadataDelegate => (),
+        _ => return Err(MetadataError::UpdateAuthorityIncorrect.into()),
+    }
+
+    let attributes_info = ctx.accounts.attributes_info;
+
+    let mut signer_seeds = vec![
+        PREFIX.as_bytes(),
+        program_id.as_ref(),
+        ctx.accounts.mint_info.key.as_ref(),
+        ATTRIBUTES_SEED.as_bytes(),
+    ];
+    let bump = &[assert_derivation(
+        program_id,
+        attributes_info,
+        &signer_seeds,
+    )?];
+    signer_seeds.push(bump);
+
+    let mut attributes = if attributes_info.data_is_empty() {
+        let attributes = MetadataAttributes {
+            bump: bump[0],
+            mint: metadata.mint,
+            ..Default::default()
+        };
+
+        create_or_allocate_account_raw(
+            *program_id,
+            attributes_info,
+            ctx.accounts.system_program_info,
+            ctx.accounts.payer_info,
+            attributes.try_to_vec()?.len(),
+            &signer_seeds,
+        )?;
+
+        attributes
+    } else {
+        MetadataAttributes::from_account_info(attributes_info)?
+    };
+
+    for key in remove {
+        attributes.remove(&key);
+    }
+
+    for Attribute { key, value } in set {
+        attributes.set(key, value)?;
+    }
+
+    attributes.save(
+        attributes_info,
+        ctx.accounts.payer_info,
+        ctx.accounts.system_program_info,
+    )?;
+
+    MetadataEvent::Updated {
+        mint: metadata.mint,
+        fields: vec![UpdatedField::Attributes],
+    }
+    .emit()?;
+
+    Ok(())
+}
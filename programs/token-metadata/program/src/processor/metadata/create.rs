@@ -2,6 +2,7 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg,
 
 use crate::{
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{Context, Create, CreateArgs},
     state::{
         Metadata, ProgrammableConfig, TokenMetadataAccount, TokenStandard,
@@ -191,6 +192,13 @@ fn create_v1(program_id: &Pubkey, ctx: Context<Create>, args: CreateArgs) -> Pro
     // saves the metadata state
     metadata.save(&mut ctx.accounts.metadata_info.try_borrow_mut_data()?)?;
 
+    MetadataEvent::Created {
+        mint: metadata.mint,
+        update_authority: metadata.update_authority,
+        token_standard: metadata.token_standard,
+    }
+    .emit()?;
+
     // Set fee flag after metadata account is created.
     set_fee_flag(ctx.accounts.metadata_info)
 }
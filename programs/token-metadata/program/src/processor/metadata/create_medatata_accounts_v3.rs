@@ -1,8 +1,9 @@
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 use crate::{
+    event::MetadataEvent,
     processor::all_account_infos,
-    state::{CollectionDetails, DataV2},
+    state::{CollectionDetails, DataV2, Metadata, TokenMetadataAccount},
     utils::{
         fee::{levy, set_fee_flag, LevyArgs},
         process_create_metadata_accounts_logic, CreateMetadataAccountsLogicArgs,
@@ -52,6 +53,15 @@ pub fn process_create_metadata_accounts_v3<'a>(
         None,
     )?;
 
+    let metadata = Metadata::from_account_info(metadata_account_info)?;
+
+    MetadataEvent::Created {
+        mint: metadata.mint,
+        update_authority: metadata.update_authority,
+        token_standard: metadata.token_standard,
+    }
+    .emit()?;
+
     // Set fee flag after metadata account is created.
     set_fee_flag(metadata_account_info)
 }
@@ -0,0 +1,72 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::set_return_data,
+    pubkey::Pubkey,
+};
+use spl_token_metadata_interface::{
+    borsh::BorshSerialize,
+    instruction::{Emit, TokenMetadataInstruction},
+    state::TokenMetadata,
+};
+
+use crate::{
+    assertions::assert_owned_by,
+    error::MetadataError,
+    state::{Metadata, TokenMetadataAccount},
+};
+
+/// Processes the instructions of the `spl-token-metadata-interface`.
+///
+/// Only `Emit` is supported, so Token-2022 tooling following the `MetadataPointer`
+/// extension can read the metadata; updates must use the Token Metadata instructions.
+pub fn process_token_metadata_interface(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: TokenMetadataInstruction,
+) -> ProgramResult {
+    match instruction {
+        TokenMetadataInstruction::Emit(data) => {
+            msg!("IX: Token Metadata Interface Emit");
+            emit(program_id, accounts, data)
+        }
+        _ => Err(MetadataError::UnsupportedTokenMetadataInterfaceInstruction.into()),
+    }
+}
+
+fn emit(program_id: &Pubkey, accounts: &[AccountInfo], data: Emit) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let metadata_info = next_account_info(account_info_iter)?;
+
+    assert_owned_by(metadata_info, program_id)?;
+    let metadata = Metadata::from_account_info(metadata_info)?;
+
+    let token_metadata = TokenMetadata {
+        // an immutable asset has no update authority in the interface
+        update_authority: if metadata.is_mutable {
+            Some(metadata.update_authority)
+        } else {
+            None
+        }
+        .try_into()?,
+        mint: metadata.mint,
+        name: trim(&metadata.data.name),
+        symbol: trim(&metadata.data.symbol),
+        uri: trim(&metadata.data.uri),
+        additional_metadata: vec![],
+    };
+
+    let bytes = token_metadata.try_to_vec()?;
+
+    if let Some(range) = TokenMetadata::get_slice(&bytes, data.start, data.end) {
+        set_return_data(range);
+    }
+
+    Ok(())
+}
+
+/// Removes the padding added to the fixed-size metadata strings.
+fn trim(value: &str) -> String {
+    value.trim_end_matches(char::from(0)).to_string()
+}
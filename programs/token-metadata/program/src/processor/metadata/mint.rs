@@ -13,6 +13,7 @@ use crate::{
         assert_derivation, assert_keys_equal, assert_mint_authority_matches_mint, assert_owned_by,
     },
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{Context, Mint, MintArgs},
     pda::{find_token_record_account, EDITION, PREFIX},
     state::{Metadata, TokenMetadataAccount, TokenStandard},
@@ -140,7 +141,7 @@ pub fn mint_v1(program_id: &Pubkey, ctx: Context<Mint>, args: MintArgs) -> Progr
             ctx.accounts.mint_info,
             ctx.accounts.token_info,
             ctx.accounts.token_owner_info,
-            ctx.accounts.spl_token_program_info,
+            ctx.accounts.spl_token_program_info.key,
             metadata.token_standard,
             None, // we already checked the supply of the mint account
         )?;
@@ -291,5 +292,12 @@ pub fn mint_v1(program_id: &Pubkey, ctx: Context<Mint>, args: MintArgs) -> Progr
         }
     }
 
+    MetadataEvent::Minted {
+        mint: *ctx.accounts.mint_info.key,
+        token: *ctx.accounts.token_info.key,
+        amount,
+    }
+    .emit()?;
+
     Ok(())
 }
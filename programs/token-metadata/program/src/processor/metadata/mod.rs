@@ -1,5 +1,7 @@
+mod attributes;
 mod create;
 mod create_medatata_accounts_v3;
+mod interface;
 mod mint;
 mod print;
 mod puff_metadata;
@@ -11,8 +13,10 @@ mod update;
 mod update_metadata_account_v2;
 mod update_primary_sale_happened_via_token;
 
+pub use attributes::*;
 pub use create::*;
 pub use create_medatata_accounts_v3::*;
+pub use interface::*;
 pub use mint::*;
 pub use print::*;
 pub use puff_metadata::*;
@@ -7,17 +7,18 @@ use solana_program::{
 use crate::{
     assertions::assert_keys_equal,
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{Context, Print, PrintArgs},
     pda::find_token_record_account,
     state::{
-        Metadata, TokenMetadataAccount, TokenStandard, EDITION_TOKEN_STANDARD_OFFSET,
-        MAX_EDITION_LEN,
+        Metadata, Operation, TokenMetadataAccount, TokenRecord, TokenStandard,
+        EDITION_TOKEN_STANDARD_OFFSET, MAX_EDITION_LEN,
     },
     utils::{
-        assert_owned_by, create_mint, create_token_record_account,
+        assert_owned_by, auth_rules_validate, create_mint, create_token_record_account,
         fee::{levy, set_fee_flag, LevyArgs},
         freeze, process_mint_new_edition_from_master_edition_via_token_logic, validate_mint,
-        validate_token, MintNewEditionFromMasterEditionViaTokenLogicArgs,
+        validate_token, AuthRulesValidateParams, MintNewEditionFromMasterEditionViaTokenLogicArgs,
     },
 };
 
@@ -26,26 +27,59 @@ pub fn print<'a>(
     accounts: &'a [AccountInfo<'a>],
     args: PrintArgs,
 ) -> ProgramResult {
+    // the authorization rules accounts are optional and only used for programmable
+    // assets with a rule set
+    let authorization_rules_program_info = accounts.get(20).filter(|a| a.key != &crate::ID);
+    let authorization_rules_info = accounts.get(21).filter(|a| a.key != &crate::ID);
+    // the master edition mint and token record are required when the rule set
+    // accounts are provided
+    let master_edition_mint_info = accounts.get(22).filter(|a| a.key != &crate::ID);
+    let master_token_record_info = accounts.get(23).filter(|a| a.key != &crate::ID);
+
+    if let Some(authorization_rules_program_info) = authorization_rules_program_info {
+        if authorization_rules_program_info.key != &mpl_token_auth_rules::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+    }
+
+    let rule_set_accounts = RuleSetAccounts {
+        authorization_rules_info,
+        master_edition_mint_info,
+        master_token_record_info,
+    };
+
     match args {
-        PrintArgs::V1 { .. } => print_v1(program_id, accounts, args),
-        PrintArgs::V2 { .. } => print_v2(program_id, accounts, args),
+        PrintArgs::V1 { .. } => print_v1(program_id, accounts, args, rule_set_accounts),
+        PrintArgs::V2 { .. } | PrintArgs::V3 { .. } => {
+            print_v2(program_id, accounts, args, rule_set_accounts)
+        }
     }
 }
 
+/// Optional accounts used to validate the print against the rule set of the
+/// master edition.
+pub struct RuleSetAccounts<'a> {
+    pub authorization_rules_info: Option<&'a AccountInfo<'a>>,
+    pub master_edition_mint_info: Option<&'a AccountInfo<'a>>,
+    pub master_token_record_info: Option<&'a AccountInfo<'a>>,
+}
+
 pub fn print_v1<'a>(
     program_id: &'a Pubkey,
     accounts: &'a [AccountInfo<'a>],
     args: PrintArgs,
+    rule_set_accounts: RuleSetAccounts<'a>,
 ) -> ProgramResult {
     let context = Print::to_context(accounts)?;
 
-    print_logic(program_id, context, args, None, None)
+    print_logic(program_id, context, args, None, None, rule_set_accounts)
 }
 
 pub fn print_v2<'a>(
     program_id: &'a Pubkey,
     accounts: &'a [AccountInfo<'a>],
     args: PrintArgs,
+    rule_set_accounts: RuleSetAccounts<'a>,
 ) -> ProgramResult {
     let context = Print::to_context(&accounts[0..18])?;
 
@@ -71,6 +105,7 @@ pub fn print_v2<'a>(
         args,
         holder_delegate_record_info,
         delegate_info,
+        rule_set_accounts,
     )
 }
 
@@ -80,11 +115,16 @@ fn print_logic<'a>(
     args: PrintArgs,
     holder_delegate_record_info: Option<&'a AccountInfo<'a>>,
     delegate_info: Option<&'a AccountInfo<'a>>,
+    rule_set_accounts: RuleSetAccounts<'a>,
 ) -> ProgramResult {
     // Get the args for the instruction
-    let edition = match args {
-        PrintArgs::V1 { edition } => edition,
-        PrintArgs::V2 { edition } => edition,
+    let (edition, authorization_data) = match args {
+        PrintArgs::V1 { edition } => (edition, None),
+        PrintArgs::V2 { edition } => (edition, None),
+        PrintArgs::V3 {
+            edition,
+            authorization_data,
+        } => (edition, authorization_data),
     };
 
     // CHECK: Checked in process_mint_new_edition_from_master_edition_via_token_logic
@@ -202,7 +242,7 @@ fn print_logic<'a>(
             edition_mint_info,
             edition_token_account_info,
             Some(edition_token_account_owner_info),
-            token_program,
+            token_program.key,
             Some(token_standard),
             Some(1), // we must have a token already
         )?;
@@ -258,6 +298,41 @@ fn print_logic<'a>(
             } else {
                 assert_owned_by(token_record_info, &crate::ID)?;
             }
+
+            // the rule set (if any) of the master edition can restrict who prints
+            // editions and who receives them
+            if let Some(authorization_rules_info) = rule_set_accounts.authorization_rules_info {
+                let master_edition_mint_info = rule_set_accounts
+                    .master_edition_mint_info
+                    .ok_or(MetadataError::MissingMasterEditionMintAccount)?;
+                assert_keys_equal(master_edition_mint_info.key, &master_metadata.mint)?;
+
+                let master_token_record_info = rule_set_accounts
+                    .master_token_record_info
+                    .ok_or(MetadataError::MissingTokenRecord)?;
+                let (pda_key, _) =
+                    find_token_record_account(&master_metadata.mint, master_token_account_info.key);
+                // validates the derivation
+                assert_keys_equal(&pda_key, master_token_record_info.key)?;
+                let master_token_record = TokenRecord::from_account_info(master_token_record_info)?;
+
+                auth_rules_validate(AuthRulesValidateParams {
+                    mint_info: master_edition_mint_info,
+                    source_info: None,
+                    destination_info: Some(edition_token_account_owner_info),
+                    authority_info: Some(delegate_info.unwrap_or(master_token_account_owner_info)),
+                    owner_info: None,
+                    programmable_config: master_metadata.programmable_config.clone(),
+                    amount: 1,
+                    auth_data: authorization_data,
+                    auth_rules_info: Some(authorization_rules_info),
+                    operation: Operation::Print,
+                    is_wallet_to_wallet: false,
+                    rule_set_revision: master_token_record
+                        .rule_set_revision
+                        .map(|revision| revision as usize),
+                })?;
+            }
         }
         _ => return Err(MetadataError::InvalidTokenStandard.into()),
     };
@@ -311,6 +386,13 @@ fn print_logic<'a>(
             TokenStandard::ProgrammableNonFungible as u8;
     }
 
+    MetadataEvent::Printed {
+        master_mint: master_metadata.mint,
+        edition_mint: *edition_mint_info.key,
+        edition,
+    }
+    .emit()?;
+
     // Set fee flag after metadata account is created.
     set_fee_flag(edition_metadata_info)
 }
@@ -4,6 +4,7 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubke
 use crate::{
     assertions::assert_owned_by,
     error::MetadataError,
+    event::{MetadataEvent, VerificationTarget},
     processor::all_account_infos,
     state::{Metadata, TokenMetadataAccount},
 };
@@ -36,5 +37,11 @@ pub fn process_remove_creator_verification(
     }
     metadata.save(&mut metadata_info.try_borrow_mut_data()?)?;
 
+    MetadataEvent::Unverified {
+        mint: metadata.mint,
+        target: VerificationTarget::Creator(*creator_info.key),
+    }
+    .emit()?;
+
     Ok(())
 }
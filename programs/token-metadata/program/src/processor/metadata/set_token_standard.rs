@@ -9,6 +9,7 @@ use crate::{
         assert_derivation, assert_owned_by, metadata::assert_update_authority_is_correct,
     },
     error::MetadataError,
+    event::MetadataEvent,
     state::{Metadata, TokenMetadataAccount, EDITION, PREFIX},
     utils::{check_token_standard, metadata::clean_write_metadata},
 };
@@ -51,6 +52,12 @@ pub fn process_set_token_standard(program_id: &Pubkey, accounts: &[AccountInfo])
         check_token_standard(mint_account_info, None)?
     };
 
+    let before = metadata.clone();
+
     metadata.token_standard = Some(token_standard);
-    clean_write_metadata(&mut metadata, metadata_account_info)
+    clean_write_metadata(&mut metadata, metadata_account_info)?;
+
+    MetadataEvent::updated(&before, &metadata).emit()?;
+
+    Ok(())
 }
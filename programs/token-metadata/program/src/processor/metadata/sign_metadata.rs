@@ -4,6 +4,7 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubke
 use crate::{
     assertions::assert_owned_by,
     error::MetadataError,
+    event::{MetadataEvent, VerificationTarget},
     processor::all_account_infos,
     state::{Metadata, TokenMetadataAccount},
 };
@@ -33,5 +34,11 @@ pub fn process_sign_metadata(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
     }
     metadata.save(&mut metadata_info.try_borrow_mut_data()?)?;
 
+    MetadataEvent::Verified {
+        mint: metadata.mint,
+        target: VerificationTarget::Creator(*creator_info.key),
+    }
+    .emit()?;
+
     Ok(())
 }
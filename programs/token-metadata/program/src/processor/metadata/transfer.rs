@@ -16,6 +16,7 @@ use spl_token_2022::state::{Account, Mint};
 use crate::{
     assertions::{assert_keys_equal, assert_owned_by, metadata::assert_holding_amount},
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{Context, Transfer, TransferArgs},
     pda::find_token_record_account,
     state::{
@@ -144,7 +145,7 @@ fn transfer_v1(program_id: &Pubkey, ctx: Context<Transfer>, args: TransferArgs)
             ctx.accounts.mint_info,
             ctx.accounts.destination_info,
             Some(ctx.accounts.destination_owner_info),
-            ctx.accounts.spl_token_program_info,
+            ctx.accounts.spl_token_program_info.key,
             metadata.token_standard,
             None, // we already checked the supply of the mint account
         )?;
@@ -351,10 +352,12 @@ fn transfer_v1(program_id: &Pubkey, ctx: Context<Transfer>, args: TransferArgs)
 
             let owner_token_record = TokenRecord::from_account_info(owner_token_record_info)?;
 
+            // an expired sale delegate no longer restricts holder transfers
             let is_sale_delegate = owner_token_record
                 .delegate_role
                 .map(|role| role == TokenDelegateRole::Sale)
-                .unwrap_or(false);
+                .unwrap_or(false)
+                && !owner_token_record.is_delegate_expired()?;
 
             let scenario = match authority_type {
                 AuthorityType::Holder => {
@@ -467,5 +470,13 @@ fn transfer_v1(program_id: &Pubkey, ctx: Context<Transfer>, args: TransferArgs)
         _ => mpl_utils::token::spl_token_transfer_checked(token_transfer_params).unwrap(),
     }
 
+    MetadataEvent::Transferred {
+        mint: *ctx.accounts.mint_info.key,
+        source: *ctx.accounts.token_info.key,
+        destination: *ctx.accounts.destination_info.key,
+        amount,
+    }
+    .emit()?;
+
     Ok(())
 }
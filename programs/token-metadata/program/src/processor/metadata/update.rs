@@ -10,6 +10,7 @@ use spl_token_2022::state::Account;
 use crate::{
     assertions::{assert_owned_by, assert_owner_in, programmable::assert_valid_authorization},
     error::MetadataError,
+    event::MetadataEvent,
     instruction::{
         CollectionDetailsToggle, CollectionToggle, Context, MetadataDelegateRole, Update,
         UpdateArgs, UsesToggle,
@@ -246,6 +247,8 @@ fn update_v1(program_id: &Pubkey, ctx: Context<Update>, args: UpdateArgs) -> Pro
 
     // If we reach here without errors we have validated that the authority is allowed to
     // perform an update.
+    let before = metadata.clone();
+
     metadata.update_v1(
         args,
         ctx.accounts.authority_info,
@@ -254,6 +257,8 @@ fn update_v1(program_id: &Pubkey, ctx: Context<Update>, args: UpdateArgs) -> Pro
         token_standard,
     )?;
 
+    MetadataEvent::updated(&before, &metadata).emit()?;
+
     Ok(())
 }
 
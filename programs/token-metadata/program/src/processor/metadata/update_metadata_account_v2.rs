@@ -8,6 +8,7 @@ use crate::{
         uses::assert_valid_use,
     },
     error::MetadataError,
+    event::MetadataEvent,
     processor::all_account_infos,
     state::{DataV2, Metadata, TokenMetadataAccount},
     utils::{metadata::clean_write_metadata, puff_out_data_fields},
@@ -25,6 +26,7 @@ pub fn process_update_metadata_accounts_v2(
     all_account_infos!(accounts, metadata_account_info, update_authority_info);
 
     let mut metadata = Metadata::from_account_info(metadata_account_info)?;
+    let before = metadata.clone();
 
     assert_owned_by(metadata_account_info, program_id)?;
     assert_update_authority_is_correct(&metadata, update_authority_info)?;
@@ -85,5 +87,9 @@ pub fn process_update_metadata_accounts_v2(
     }
 
     puff_out_data_fields(&mut metadata);
-    clean_write_metadata(&mut metadata, metadata_account_info)
+    clean_write_metadata(&mut metadata, metadata_account_info)?;
+
+    MetadataEvent::updated(&before, &metadata).emit()?;
+
+    Ok(())
 }
@@ -7,6 +7,7 @@ use spl_token_2022::state::Account;
 use crate::{
     assertions::{assert_initialized, assert_owned_by},
     error::MetadataError,
+    event::MetadataEvent,
     processor::all_account_infos,
     state::{Metadata, TokenMetadataAccount},
     utils::SPL_TOKEN_ID,
@@ -45,8 +46,12 @@ pub fn process_update_primary_sale_happened_via_token(
         return Err(MetadataError::MintMismatch.into());
     }
 
+    let before = metadata.clone();
+
     metadata.primary_sale_happened = true;
     metadata.save(&mut metadata_account_info.try_borrow_mut_data()?)?;
 
+    MetadataEvent::updated(&before, &metadata).emit()?;
+
     Ok(())
 }
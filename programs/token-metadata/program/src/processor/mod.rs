@@ -1,3 +1,4 @@
+mod authority;
 mod bubblegum;
 mod burn;
 mod close;
@@ -13,6 +14,7 @@ mod state;
 mod uses;
 mod verification;
 
+pub use authority::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 pub use bubblegum::*;
 pub use burn::*;
@@ -29,6 +31,7 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
+use spl_token_metadata_interface::instruction::TokenMetadataInstruction;
 pub use state::*;
 pub use uses::*;
 pub use verification::*;
@@ -82,6 +85,13 @@ pub fn process_instruction<'a>(
     accounts: &'a [AccountInfo<'a>],
     input: &[u8],
 ) -> ProgramResult {
+    // instructions of the spl-token-metadata-interface start with an 8-byte
+    // discriminator, whose first byte does not overlap with any of the variants
+    // of `MetadataInstruction`
+    if let Ok(instruction) = TokenMetadataInstruction::unpack(input) {
+        return process_token_metadata_interface(program_id, accounts, instruction);
+    }
+
     let (variant, _args) = input
         .split_first()
         .ok_or(MetadataError::InvalidInstruction)?;
@@ -107,8 +117,14 @@ pub fn process_instruction<'a>(
     // checks if there is a locked token; this will block any instruction that
     // requires the token record account when the token is locked – 'Update' is
     // an example of an instruction that does not require the token record, so
-    // it can be executed even when a token is locked
-    if is_locked(program_id, accounts) && !matches!(instruction, MetadataInstruction::Unlock(_)) {
+    // it can be executed even when a token is locked; records of closed token
+    // accounts can always be closed
+    if is_locked(program_id, accounts)
+        && !matches!(
+            instruction,
+            MetadataInstruction::Unlock(_) | MetadataInstruction::CloseOrphanedRecord(_)
+        )
+    {
         return Err(MetadataError::LockedToken.into());
     }
 
@@ -169,11 +185,43 @@ pub fn process_instruction<'a>(
             resize::process_resize(program_id, accounts)
         }
         MetadataInstruction::CloseAccounts => close::process_close_accounts(program_id, accounts),
+        MetadataInstruction::VerifyCollectionBatch(args) => {
+            msg!("IX: Verify Collection Batch");
+            verification::verify_collection_batch(program_id, accounts, args)
+        }
+        MetadataInstruction::ProposeUpdateAuthority(args) => {
+            msg!("IX: Propose Update Authority");
+            authority::propose_update_authority(program_id, accounts, args)
+        }
+        MetadataInstruction::AcceptUpdateAuthority(args) => {
+            msg!("IX: Accept Update Authority");
+            authority::accept_update_authority(program_id, accounts, args)
+        }
+        MetadataInstruction::CancelUpdateAuthority(args) => {
+            msg!("IX: Cancel Update Authority");
+            authority::cancel_update_authority(program_id, accounts, args)
+        }
+        MetadataInstruction::RevokeExpiredDelegate(args) => {
+            msg!("IX: Revoke Expired Delegate");
+            delegate::revoke_expired_delegate(program_id, accounts, args)
+        }
+        MetadataInstruction::UpdateAuthorityBatch(args) => {
+            msg!("IX: Update Authority Batch");
+            authority::update_authority_batch(program_id, accounts, args)
+        }
+        MetadataInstruction::CloseOrphanedRecord(args) => {
+            msg!("IX: Close Orphaned Record");
+            close::close_orphaned_record(program_id, accounts, args)
+        }
+        MetadataInstruction::UpdateAttributes(args) => {
+            msg!("IX: Update Attributes");
+            metadata::update_attributes(program_id, accounts, args)
+        }
         _ => {
             // pNFT accounts and SPL Token-2022 program can only be used by the "new" API; before
             // forwarding the transaction to the "legacy" processor we determine whether we are
             // dealing with a pNFT or not
-            if !incompatible_accounts(program_id, accounts)? {
+            if !incompatible_accounts(program_id, accounts, &instruction)? {
                 process_legacy_instruction(program_id, accounts, instruction)
             } else {
                 Err(MetadataError::InstructionNotSupported.into())
@@ -271,18 +319,6 @@ fn process_legacy_instruction<'a>(
             msg!("IX: Unverify Collection");
             unverify_collection(program_id, accounts)
         }
-        MetadataInstruction::Utilize(args) => {
-            msg!("IX: Use/Utilize Token");
-            process_utilize(program_id, accounts, args.number_of_uses)
-        }
-        MetadataInstruction::ApproveUseAuthority(args) => {
-            msg!("IX: Approve Use Authority");
-            process_approve_use_authority(program_id, accounts, args.number_of_uses)
-        }
-        MetadataInstruction::RevokeUseAuthority => {
-            msg!("IX: Revoke Use Authority");
-            process_revoke_use_authority(program_id, accounts)
-        }
         MetadataInstruction::ApproveCollectionAuthority => {
             msg!("IX: Approve Collection Authority");
             process_approve_collection_authority(program_id, accounts)
@@ -331,6 +367,18 @@ fn process_legacy_instruction<'a>(
             msg!("IX: Bubblegum Program Set Collection Size");
             bubblegum_set_collection_size(program_id, accounts, args)
         }
+        MetadataInstruction::Utilize(args) => {
+            msg!("IX: Use/Utilize Token");
+            process_utilize(program_id, accounts, args.number_of_uses)
+        }
+        MetadataInstruction::ApproveUseAuthority(args) => {
+            msg!("IX: Approve Use Authority");
+            process_approve_use_authority(program_id, accounts, args.number_of_uses)
+        }
+        MetadataInstruction::RevokeUseAuthority => {
+            msg!("IX: Revoke Use Authority");
+            process_revoke_use_authority(program_id, accounts)
+        }
         MetadataInstruction::CreateEscrowAccount => {
             msg!("IX: Create Escrow Account");
             process_create_escrow_account(program_id, accounts)
@@ -350,17 +398,35 @@ fn process_legacy_instruction<'a>(
 /// Checks whether the instruction's accounts are compatible with legacy instructions or not.
 ///
 /// The test will return `true` if we are dealing with a pNFT metadata or SPL Token-2022 program;
-/// otherwise, it will return `false`.
+/// otherwise, it will return `false`. Legacy instructions that support pNFTs or SPL Token-2022
+/// assets skip the corresponding check.
 fn incompatible_accounts(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    instruction: &MetadataInstruction,
 ) -> Result<bool, ProgramError> {
+    // 'Utilize' validates the use against the rule set and 'TransferOutOfEscrow'
+    // transfers programmable attributes with the same flow as 'Transfer'
+    let supports_programmable = matches!(
+        instruction,
+        MetadataInstruction::Utilize(_) | MetadataInstruction::TransferOutOfEscrow(_)
+    );
+    let supports_token_2022 = matches!(
+        instruction,
+        MetadataInstruction::Utilize(_)
+            | MetadataInstruction::ApproveUseAuthority(_)
+            | MetadataInstruction::RevokeUseAuthority
+            | MetadataInstruction::CreateEscrowAccount
+            | MetadataInstruction::CloseEscrowAccount
+            | MetadataInstruction::TransferOutOfEscrow(_)
+    );
+
     for account_info in accounts {
         // checks the account is owned by Token Metadata and it has data
         if account_info.owner == program_id && !account_info.data_is_empty() {
             let discriminator = account_info.data.borrow()[DISCRIMINATOR_INDEX];
             // checks if the account is a Metadata account
-            if discriminator == Key::MetadataV1 as u8 {
+            if discriminator == Key::MetadataV1 as u8 && !supports_programmable {
                 let metadata = Metadata::from_account_info(account_info)?;
 
                 if matches!(
@@ -370,8 +436,9 @@ fn incompatible_accounts(
                     return Ok(true);
                 }
             }
-        } else if account_info.key == &spl_token_2022::ID
-            || account_info.owner == &spl_token_2022::ID
+        } else if (account_info.key == &spl_token_2022::ID
+            || account_info.owner == &spl_token_2022::ID)
+            && !supports_token_2022
         {
             return Ok(true);
         }
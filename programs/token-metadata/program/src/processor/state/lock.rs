@@ -2,6 +2,7 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubke
 
 use super::toggle_asset_state;
 use crate::{
+    event::MetadataEvent,
     instruction::{Lock, LockArgs},
     state::TokenState,
 };
@@ -13,23 +14,41 @@ pub fn lock<'a>(
 ) -> ProgramResult {
     let context = Lock::to_context(accounts)?;
 
-    match args {
-        LockArgs::V1 { .. } => toggle_asset_state(
-            program_id,
-            super::ToggleAccounts {
-                payer_info: context.accounts.payer_info,
-                authority_info: context.accounts.authority_info,
-                mint_info: context.accounts.mint_info,
-                token_info: context.accounts.token_info,
-                edition_info: context.accounts.edition_info,
-                metadata_info: context.accounts.metadata_info,
-                token_record_info: context.accounts.token_record_info,
-                system_program_info: context.accounts.system_program_info,
-                sysvar_instructions_info: context.accounts.sysvar_instructions_info,
-                spl_token_program_info: context.accounts.spl_token_program_info,
-            },
-            TokenState::Unlocked,
-            TokenState::Locked,
-        ),
+    let (unlock_after, authorization_data) = match args {
+        LockArgs::V1 { authorization_data } => (None, authorization_data),
+        LockArgs::V2 {
+            unlock_after,
+            authorization_data,
+        } => (unlock_after, authorization_data),
+    };
+
+    toggle_asset_state(
+        program_id,
+        super::ToggleAccounts {
+            payer_info: context.accounts.payer_info,
+            authority_info: context.accounts.authority_info,
+            mint_info: context.accounts.mint_info,
+            token_info: context.accounts.token_info,
+            edition_info: context.accounts.edition_info,
+            metadata_info: context.accounts.metadata_info,
+            token_record_info: context.accounts.token_record_info,
+            system_program_info: context.accounts.system_program_info,
+            sysvar_instructions_info: context.accounts.sysvar_instructions_info,
+            spl_token_program_info: context.accounts.spl_token_program_info,
+            authorization_rules_program_info: context.accounts.authorization_rules_program_info,
+            authorization_rules_info: context.accounts.authorization_rules_info,
+        },
+        TokenState::Unlocked,
+        TokenState::Locked,
+        unlock_after,
+        authorization_data,
+    )?;
+
+    MetadataEvent::Locked {
+        mint: *context.accounts.mint_info.key,
+        token: *context.accounts.token_info.key,
     }
+    .emit()?;
+
+    Ok(())
 }
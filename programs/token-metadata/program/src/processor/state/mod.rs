@@ -17,13 +17,16 @@ use crate::{
     assertions::{assert_keys_equal, assert_owner_in, metadata::assert_state},
     error::MetadataError,
     pda::find_token_record_account,
+    processor::AuthorizationData,
     state::{
-        AuthorityRequest, AuthorityResponse, AuthorityType, Metadata, TokenDelegateRole,
-        TokenMetadataAccount, TokenRecord, TokenStandard, TokenState,
+        has_expired, AuthorityRequest, AuthorityResponse, AuthorityType, DelegateExpiry, Metadata,
+        Operation, Resizable, TokenDelegateRole, TokenMetadataAccount, TokenRecord, TokenStandard,
+        TokenState,
     },
     utils::{
         assert_delegated_tokens, assert_freeze_authority_matches_mint, assert_owned_by,
-        assert_token_program_matches_package, freeze, thaw, unpack, unpack_initialized,
+        assert_token_program_matches_package, auth_rules_validate, create_token_record_account,
+        freeze, thaw, unpack, unpack_initialized, AuthRulesValidateParams,
     },
 };
 
@@ -38,6 +41,8 @@ pub(crate) struct ToggleAccounts<'a> {
     system_program_info: &'a AccountInfo<'a>,
     sysvar_instructions_info: &'a AccountInfo<'a>,
     spl_token_program_info: Option<&'a AccountInfo<'a>>,
+    authorization_rules_program_info: Option<&'a AccountInfo<'a>>,
+    authorization_rules_info: Option<&'a AccountInfo<'a>>,
 }
 
 pub(crate) fn toggle_asset_state(
@@ -45,6 +50,8 @@ pub(crate) fn toggle_asset_state(
     accounts: ToggleAccounts,
     from: TokenState,
     to: TokenState,
+    unlock_after: Option<i64>,
+    authorization_data: Option<AuthorizationData>,
 ) -> ProgramResult {
     // signers
 
@@ -65,6 +72,13 @@ pub(crate) fn toggle_asset_state(
         &sysvar::instructions::ID,
     )?;
 
+    if let Some(authorization_rules_program_info) = accounts.authorization_rules_program_info {
+        assert_keys_equal(
+            authorization_rules_program_info.key,
+            &mpl_token_auth_rules::ID,
+        )?;
+    }
+
     // account relationships
 
     let metadata = Metadata::from_account_info(accounts.metadata_info)?;
@@ -82,20 +96,32 @@ pub(crate) fn toggle_asset_state(
     if matches!(to, TokenState::Locked) && token.amount == 0 {
         return Err(MetadataError::InsufficientTokenBalance.into());
     }
+    // the unlock timestamp (if any) must be in the future
+    if has_expired(&unlock_after.map(DelegateExpiry::UnixTimestamp))? {
+        return Err(MetadataError::InvalidUnlockTimestamp.into());
+    }
 
     // authority – this can be either:
     //  1. token delegate (programmable non-fungible): valid token_record.delegate
     //  2. spl-delegate (non-fungibles): authority == token.delegate
     //  3. freeze authority (fungibles): authority == freeze_authority
+    //  4. holder (non-fungibles): authority == token.owner when unlocking an
+    //     asset whose lock has reached its unlock timestamp
 
     if matches!(
         metadata.token_standard,
         Some(TokenStandard::ProgrammableNonFungible)
             | Some(TokenStandard::ProgrammableNonFungibleEdition)
     ) {
+        let precedence: &[AuthorityType] = if matches!(to, TokenState::Unlocked) {
+            &[AuthorityType::TokenDelegate, AuthorityType::Holder]
+        } else {
+            &[AuthorityType::TokenDelegate]
+        };
+
         let AuthorityResponse { authority_type, .. } =
             AuthorityType::get_authority_type(AuthorityRequest {
-                precedence: &[AuthorityType::TokenDelegate],
+                precedence,
                 authority: accounts.authority_info.key,
                 update_authority: &metadata.update_authority,
                 mint: accounts.mint_info.key,
@@ -110,10 +136,6 @@ pub(crate) fn toggle_asset_state(
                 ],
                 ..Default::default()
             })?;
-        // only a delegate can lock/unlock
-        if !matches!(authority_type, AuthorityType::TokenDelegate) {
-            return Err(MetadataError::InvalidAuthorityType.into());
-        }
 
         let (mut token_record, token_record_info) = match accounts.token_record_info {
             Some(token_record_info) => {
@@ -134,17 +156,50 @@ pub(crate) fn toggle_asset_state(
             }
         };
 
+        match authority_type {
+            AuthorityType::TokenDelegate => {
+                // the rule set (if any) can restrict who locks/unlocks the asset
+                auth_rules_validate(AuthRulesValidateParams {
+                    mint_info: accounts.mint_info,
+                    source_info: None,
+                    destination_info: None,
+                    authority_info: Some(accounts.authority_info),
+                    owner_info: None,
+                    programmable_config: metadata.programmable_config,
+                    amount: token.amount,
+                    auth_data: authorization_data,
+                    auth_rules_info: accounts.authorization_rules_info,
+                    operation: if matches!(to, TokenState::Locked) {
+                        Operation::Lock
+                    } else {
+                        Operation::Unlock
+                    },
+                    is_wallet_to_wallet: false,
+                    rule_set_revision: token_record
+                        .rule_set_revision
+                        .map(|revision| revision as usize),
+                })?;
+            }
+            // the holder unlocking an expired lock is not subject to the rule set,
+            // otherwise the asset could remain locked indefinitely
+            AuthorityType::Holder => assert_lock_expired(&token_record)?,
+            // only a delegate can lock/unlock
+            _ => return Err(MetadataError::InvalidAuthorityType.into()),
+        }
+
         // make sure we are on the expected state
         assert_state(&token_record, from)?;
-        // for pNFTs, we only need to flip the programmable state
+        // for pNFTs, we only need to flip the programmable state (and keep
+        // the unlock timestamp while locked)
         token_record.state = to;
+        token_record.unlock_after = unlock_after;
 
-        // save the state
-        borsh::to_writer(
-            &mut token_record_info.try_borrow_mut_data()?[..],
-            &token_record,
+        // save the state (this resizes records created with a previous layout)
+        token_record.save(
+            token_record_info,
+            accounts.payer_info,
+            accounts.system_program_info,
         )
-        .map_err(|_| MetadataError::BorshSerializationError.into())
     } else {
         let spl_token_program_info = match accounts.spl_token_program_info {
             Some(spl_token_program_info) => {
@@ -161,21 +216,80 @@ pub(crate) fn toggle_asset_state(
         // authority and we allow lock/unlock if the authority is a delegate; for
         // fungibles, the authority must match the freeze authority of the mint
         if let Some(edition_info) = accounts.edition_info {
+            // non-fungibles have no programmable state, so the unlock timestamp
+            // of the lock is stored on the token record account (when it exists);
+            // the record is required on every lock/unlock so the timestamp of a
+            // previous lock cannot be left behind and used to unlock a later one
+            let token_record_info = accounts
+                .token_record_info
+                .ok_or(MetadataError::MissingTokenRecord)?;
+
+            let (pda_key, _) =
+                find_token_record_account(accounts.mint_info.key, accounts.token_info.key);
+            assert_keys_equal(&pda_key, token_record_info.key)?;
+
+            let mut lock_record = if token_record_info.data_is_empty() {
+                None
+            } else {
+                assert_owned_by(token_record_info, &crate::ID)?;
+                Some(TokenRecord::from_account_info(token_record_info)?)
+            };
+
             // check whether the authority is an spl-token delegate or not
-            assert_delegated_tokens(
+            if let Err(error) = assert_delegated_tokens(
                 accounts.authority_info,
                 accounts.mint_info,
                 accounts.token_info,
                 spl_token_program_info.key,
-            )
-            .map_err(|error| {
+            ) {
                 let custom: ProgramError = MetadataError::InvalidDelegate.into();
-                if error == custom {
-                    MetadataError::InvalidAuthorityType.into()
-                } else {
-                    error
+                if error != custom {
+                    return Err(error);
                 }
-            })?;
+
+                // otherwise the authority must be the holder unlocking an asset
+                // locked by the current delegate
+                match &lock_record {
+                    Some(lock_record)
+                        if matches!(to, TokenState::Unlocked)
+                            && token.owner == *accounts.authority_info.key
+                            && lock_record.delegate == Option::from(token.delegate) =>
+                    {
+                        assert_lock_expired(lock_record)?
+                    }
+                    _ => return Err(MetadataError::InvalidAuthorityType.into()),
+                }
+            }
+
+            if unlock_after.is_some() && lock_record.is_none() {
+                create_token_record_account(
+                    program_id,
+                    token_record_info,
+                    accounts.mint_info,
+                    accounts.token_info,
+                    accounts.payer_info,
+                    accounts.system_program_info,
+                )?;
+
+                lock_record = Some(TokenRecord::from_account_info(token_record_info)?);
+            }
+
+            // stores the unlock timestamp of a new lock or clears the one of the
+            // previous lock
+            if let Some(mut lock_record) = lock_record {
+                lock_record.reset();
+
+                if unlock_after.is_some() {
+                    lock_record.delegate = Some(*accounts.authority_info.key);
+                    lock_record.unlock_after = unlock_after;
+                }
+
+                lock_record.save(
+                    token_record_info,
+                    accounts.payer_info,
+                    accounts.system_program_info,
+                )?;
+            }
 
             match to {
                 TokenState::Locked => {
@@ -203,6 +317,12 @@ pub(crate) fn toggle_asset_state(
                 TokenState::Listed => Err(MetadataError::IncorrectTokenState.into()),
             }
         } else {
+            // fungibles are locked by the freeze authority of the mint, which does
+            // not go away, so their locks do not expire
+            if unlock_after.is_some() {
+                return Err(MetadataError::FeatureNotSupported.into());
+            }
+
             // fungibles: the authority must be the mint freeze authority
             let mint = unpack_initialized::<Mint>(&accounts.mint_info.data.borrow())?;
 
@@ -251,3 +371,18 @@ pub(crate) fn toggle_asset_state(
         }
     }
 }
+
+/// Asserts that the holder can unlock an asset: the lock must have an unlock
+/// timestamp and it must have passed.
+fn assert_lock_expired(token_record: &TokenRecord) -> ProgramResult {
+    // only the delegate can unlock an asset when the lock has no unlock timestamp
+    if token_record.unlock_after.is_none() {
+        return Err(MetadataError::InvalidAuthorityType.into());
+    }
+
+    if !token_record.is_lock_expired()? {
+        return Err(MetadataError::LockNotExpired.into());
+    }
+
+    Ok(())
+}
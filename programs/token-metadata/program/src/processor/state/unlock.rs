@@ -2,6 +2,7 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubke
 
 use super::toggle_asset_state;
 use crate::{
+    event::MetadataEvent,
     instruction::{Unlock, UnlockArgs},
     state::TokenState,
 };
@@ -14,7 +15,7 @@ pub fn unlock<'a>(
     let context = Unlock::to_context(accounts)?;
 
     match args {
-        UnlockArgs::V1 { .. } => toggle_asset_state(
+        UnlockArgs::V1 { authorization_data } => toggle_asset_state(
             program_id,
             super::ToggleAccounts {
                 payer_info: context.accounts.payer_info,
@@ -27,9 +28,21 @@ pub fn unlock<'a>(
                 system_program_info: context.accounts.system_program_info,
                 sysvar_instructions_info: context.accounts.sysvar_instructions_info,
                 spl_token_program_info: context.accounts.spl_token_program_info,
+                authorization_rules_program_info: context.accounts.authorization_rules_program_info,
+                authorization_rules_info: context.accounts.authorization_rules_info,
             },
             TokenState::Locked,
             TokenState::Unlocked,
-        ),
+            None,
+            authorization_data,
+        )?,
     }
+
+    MetadataEvent::Unlocked {
+        mint: *context.accounts.mint_info.key,
+        token: *context.accounts.token_info.key,
+    }
+    .emit()?;
+
+    Ok(())
 }
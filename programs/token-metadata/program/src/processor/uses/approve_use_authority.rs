@@ -7,7 +7,10 @@ use spl_token_2022::instruction::approve;
 use crate::{
     assertions::{
         metadata::assert_currently_holding,
-        uses::{assert_burner, assert_use_authority_derivation, process_use_authority_validation},
+        uses::{
+            assert_burner, assert_use_authority_derivation, assert_valid_use_token_accounts,
+            process_use_authority_validation,
+        },
     },
     error::MetadataError,
     processor::all_account_infos,
@@ -15,7 +18,6 @@ use crate::{
         Key, Metadata, TokenMetadataAccount, UseAuthorityRecord, UseMethod, PREFIX, USER,
         USE_AUTHORITY_RECORD_SIZE,
     },
-    utils::SPL_TOKEN_ID,
 };
 
 pub fn process_approve_use_authority(
@@ -42,9 +44,14 @@ pub fn process_approve_use_authority(
     if metadata.uses.is_none() {
         return Err(MetadataError::Unusable.into());
     }
-    if *token_program_account_info.key != SPL_TOKEN_ID {
-        return Err(MetadataError::InvalidTokenProgram.into());
-    }
+    assert_valid_use_token_accounts(
+        metadata_info,
+        &metadata,
+        mint_info,
+        token_account_info,
+        owner_info,
+        token_program_account_info,
+    )?;
     assert_signer(owner_info)?;
     assert_signer(payer)?;
     assert_currently_holding(
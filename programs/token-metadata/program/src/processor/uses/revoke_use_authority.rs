@@ -9,13 +9,14 @@ use crate::{
         assert_owned_by,
         metadata::assert_currently_holding,
         uses::{
-            assert_use_authority_derivation, assert_valid_bump, process_use_authority_validation,
+            assert_use_authority_derivation, assert_valid_bump, assert_valid_use_token_accounts,
+            process_use_authority_validation,
         },
     },
     error::MetadataError,
     processor::all_account_infos,
     state::{Key, Metadata, TokenMetadataAccount, UseAuthorityRecord, UseMethod},
-    utils::{close_program_account, SPL_TOKEN_ID},
+    utils::close_program_account,
 };
 
 pub fn process_revoke_use_authority(
@@ -37,9 +38,14 @@ pub fn process_revoke_use_authority(
     if metadata.uses.is_none() {
         return Err(MetadataError::Unusable.into());
     }
-    if *token_program_account_info.key != SPL_TOKEN_ID {
-        return Err(MetadataError::InvalidTokenProgram.into());
-    }
+    assert_valid_use_token_accounts(
+        metadata_info,
+        &metadata,
+        mint_info,
+        token_account_info,
+        owner_info,
+        token_program_account_info,
+    )?;
     assert_signer(owner_info)?;
     assert_currently_holding(
         program_id,
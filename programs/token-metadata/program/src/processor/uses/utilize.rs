@@ -5,6 +5,7 @@ use mpl_utils::{
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
     sysvar::SysvarId,
@@ -12,21 +13,32 @@ use solana_program::{
 
 use crate::{
     assertions::{
-        assert_owned_by,
+        assert_keys_equal, assert_owned_by,
         metadata::assert_currently_holding,
         uses::{
             assert_burner, assert_use_authority_derivation, assert_valid_bump,
-            process_use_authority_validation,
+            assert_valid_use_token_accounts, process_use_authority_validation,
         },
     },
     error::MetadataError,
-    state::{Metadata, TokenMetadataAccount, UseAuthorityRecord, UseMethod, Uses, BURN, PREFIX},
-    utils::SPL_TOKEN_ID,
+    pda::find_token_record_account,
+    state::{
+        Metadata, Operation, TokenMetadataAccount, TokenRecord, TokenStandard, UseAuthorityRecord,
+        UseMethod, Uses, BURN, PREFIX,
+    },
+    utils::{auth_rules_validate, AuthRulesValidateParams},
 };
 
-pub fn process_utilize(
+/// Number of accounts expected for programmable assets.
+const PROGRAMMABLE_ACCOUNTS_LEN: usize = 14;
+const USE_AUTHORITY_RECORD_INDEX: usize = 9;
+const TOKEN_RECORD_INDEX: usize = 11;
+const AUTHORIZATION_RULES_PROGRAM_INDEX: usize = 12;
+const AUTHORIZATION_RULES_INDEX: usize = 13;
+
+pub fn process_utilize<'a>(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
+    accounts: &'a [AccountInfo<'a>],
     number_of_uses: u64,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter().peekable();
@@ -40,8 +52,24 @@ pub fn process_utilize(
     let _ata_program_account_info = next_account_info(account_info_iter)?;
     let _system_program_account_info = next_account_info(account_info_iter)?;
 
-    // consume the next account only if it is Rent
-    let approved_authority_is_using = if account_info_iter
+    let metadata: Metadata = Metadata::from_account_info(metadata_info)?;
+
+    // programmable assets pass every account at a fixed position, with the program id
+    // in place of the use authority record and burner when they are not used
+    let is_programmable = matches!(
+        metadata.token_standard,
+        Some(TokenStandard::ProgrammableNonFungible)
+            | Some(TokenStandard::ProgrammableNonFungibleEdition)
+    );
+
+    // consume the next account only if it is Rent, which programmable assets always pass
+    let approved_authority_is_using = if is_programmable {
+        if accounts.len() < PROGRAMMABLE_ACCOUNTS_LEN {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        next_account_info(account_info_iter)?;
+        accounts[USE_AUTHORITY_RECORD_INDEX].key != program_id
+    } else if account_info_iter
         .next_if(|info| info.key == &Rent::id())
         .is_some()
     {
@@ -52,14 +80,17 @@ pub fn process_utilize(
         accounts.len() == 10
     };
 
-    let metadata: Metadata = Metadata::from_account_info(metadata_info)?;
-
     if metadata.uses.is_none() {
         return Err(MetadataError::Unusable.into());
     }
-    if *token_program_account_info.key != SPL_TOKEN_ID {
-        return Err(MetadataError::InvalidTokenProgram.into());
-    }
+    assert_valid_use_token_accounts(
+        metadata_info,
+        &metadata,
+        mint_info,
+        token_account_info,
+        owner_info,
+        token_program_account_info,
+    )?;
     assert_signer(user_info)?;
     assert_currently_holding(
         program_id,
@@ -109,6 +140,46 @@ pub fn process_utilize(
     } else if user_info.key != owner_info.key {
         return Err(MetadataError::InvalidUser.into());
     }
+
+    if is_programmable {
+        let token_record_info = &accounts[TOKEN_RECORD_INDEX];
+        let (pda_key, _) = find_token_record_account(mint_info.key, token_account_info.key);
+        // validates the derivation
+        assert_keys_equal(&pda_key, token_record_info.key)?;
+
+        if token_record_info.data_is_empty() {
+            return Err(MetadataError::MissingTokenRecord.into());
+        }
+        assert_owned_by(token_record_info, program_id)?;
+        let token_record = TokenRecord::from_account_info(token_record_info)?;
+
+        let authorization_rules_info =
+            Some(&accounts[AUTHORIZATION_RULES_INDEX]).filter(|info| info.key != program_id);
+
+        if authorization_rules_info.is_some() {
+            assert_keys_equal(
+                accounts[AUTHORIZATION_RULES_PROGRAM_INDEX].key,
+                &mpl_token_auth_rules::ID,
+            )?;
+        }
+
+        auth_rules_validate(AuthRulesValidateParams {
+            mint_info,
+            source_info: None,
+            destination_info: None,
+            authority_info: Some(user_info),
+            owner_info: None,
+            programmable_config: metadata.programmable_config.clone(),
+            amount: number_of_uses,
+            auth_data: None,
+            auth_rules_info: authorization_rules_info,
+            operation: Operation::Use,
+            is_wallet_to_wallet: false,
+            rule_set_revision: token_record
+                .rule_set_revision
+                .map(|revision| revision as usize),
+        })?;
+    }
     metadata.save(&mut metadata_info.try_borrow_mut_data()?)?;
     if remaining_uses == 0 && must_burn {
         if approved_authority_is_using {
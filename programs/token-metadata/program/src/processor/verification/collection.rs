@@ -1,15 +1,26 @@
+use borsh::BorshSerialize;
 use mpl_utils::{assert_signer, token::SPL_TOKEN_PROGRAM_IDS};
-use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey,
+};
 
 use crate::{
     assertions::{
-        assert_owned_by, assert_owner_in, collection::assert_collection_verify_is_valid,
+        assert_owned_by, assert_owner_in,
+        collection::{assert_collection_parent_is_valid, assert_collection_verify_is_valid},
         metadata::assert_metadata_derivation,
     },
     error::MetadataError,
-    instruction::{Context, MetadataDelegateRole, Unverify, Verify},
+    event::{MetadataEvent, VerificationTarget},
+    instruction::{
+        Context, MetadataDelegateRole, Unverify, VerificationResult, Verify, VerifyCollectionBatch,
+    },
     state::{AuthorityRequest, AuthorityType, Metadata, TokenMetadataAccount},
-    utils::{decrement_collection_size, increment_collection_size, metadata::clean_write_metadata},
+    utils::{
+        decrement_collection_size, decrement_collection_size_by, increment_collection_size,
+        increment_collection_size_by, metadata::clean_write_metadata,
+    },
 };
 
 pub(crate) fn verify_collection_v1(program_id: &Pubkey, ctx: Context<Verify>) -> ProgramResult {
@@ -97,7 +108,15 @@ pub(crate) fn verify_collection_v1(program_id: &Pubkey, ctx: Context<Verify>) ->
     };
 
     // Reserialize metadata.
-    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)
+    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)?;
+
+    MetadataEvent::Verified {
+        mint: metadata.mint,
+        target: VerificationTarget::Collection(*collection_mint_info.key),
+    }
+    .emit()?;
+
+    Ok(())
 }
 
 pub(crate) fn unverify_collection_v1(program_id: &Pubkey, ctx: Context<Unverify>) -> ProgramResult {
@@ -208,5 +227,307 @@ pub(crate) fn unverify_collection_v1(program_id: &Pubkey, ctx: Context<Unverify>
     collection.verified = false;
 
     // Reserialize metadata.
-    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)
+    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)?;
+
+    MetadataEvent::Unverified {
+        mint: metadata.mint,
+        target: VerificationTarget::Collection(*collection_mint_info.key),
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+pub(crate) fn verify_collection_batch_v1<'a>(
+    program_id: &Pubkey,
+    ctx: Context<VerifyCollectionBatch<'a>>,
+    items: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    // Assert program ownership/signers of the collection parent accounts.  These checks are
+    // done once for the whole batch.
+
+    assert_signer(ctx.accounts.authority_info)?;
+
+    // Note: `ctx.accounts.delegate_record_info` owner check done inside of `get_authority_type`.
+
+    let collection_mint_info = ctx.accounts.collection_mint_info;
+    assert_owner_in(collection_mint_info, &SPL_TOKEN_PROGRAM_IDS)?;
+
+    let collection_metadata_info = ctx.accounts.collection_metadata_info;
+    assert_owned_by(collection_metadata_info, program_id)?;
+
+    let collection_master_edition_info = ctx
+        .accounts
+        .collection_master_edition_info
+        .ok_or(MetadataError::MissingCollectionMasterEdition)?;
+    assert_owned_by(collection_master_edition_info, program_id)?;
+
+    let mut collection_metadata = Metadata::from_account_info(collection_metadata_info)?;
+
+    assert_collection_parent_is_valid(
+        &collection_metadata,
+        collection_mint_info,
+        collection_master_edition_info,
+    )?;
+
+    // The required authority is the same as in `verify_collection_v1`, and since it only
+    // depends on the collection parent, it is validated once for all items.
+    let authority_response = AuthorityType::get_authority_type(AuthorityRequest {
+        authority: ctx.accounts.authority_info.key,
+        update_authority: &collection_metadata.update_authority,
+        mint: collection_mint_info.key,
+        metadata_delegate_record_info: ctx.accounts.delegate_record_info,
+        metadata_delegate_roles: vec![MetadataDelegateRole::Collection],
+        precedence: &[AuthorityType::Metadata, AuthorityType::MetadataDelegate],
+        ..Default::default()
+    })?;
+
+    match authority_response.authority_type {
+        AuthorityType::Metadata | AuthorityType::MetadataDelegate => (),
+        _ => return Err(MetadataError::UpdateAuthorityIncorrect.into()),
+    }
+
+    let results: Vec<VerificationResult> = items
+        .iter()
+        .map(|metadata_info| {
+            item_result(
+                metadata_info,
+                verify_collection_item(
+                    program_id,
+                    metadata_info,
+                    collection_mint_info,
+                    collection_metadata_info,
+                ),
+            )
+        })
+        .collect();
+
+    // In the case of a sized collection, update the size on the collection parent once
+    // for all verified items.
+    let verified = count_updated(&results);
+
+    if verified > 0 && collection_metadata.collection_details.is_some() {
+        increment_collection_size_by(&mut collection_metadata, collection_metadata_info, verified)?;
+    }
+
+    set_return_data(&results.try_to_vec()?);
+
+    Ok(())
+}
+
+pub(crate) fn unverify_collection_batch_v1<'a>(
+    program_id: &Pubkey,
+    ctx: Context<VerifyCollectionBatch<'a>>,
+    items: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    // Assert program ownership/signers of the collection parent accounts.  These checks are
+    // done once for the whole batch.
+
+    assert_signer(ctx.accounts.authority_info)?;
+
+    // Note: `ctx.accounts.delegate_record_info` owner check done inside of `get_authority_type`.
+
+    let collection_mint_info = ctx.accounts.collection_mint_info;
+    assert_owner_in(collection_mint_info, &SPL_TOKEN_PROGRAM_IDS)?;
+
+    let collection_metadata_info = ctx.accounts.collection_metadata_info;
+    assert_metadata_derivation(program_id, collection_metadata_info, collection_mint_info)?;
+
+    // If the collection parent metadata account has been burned then its data will be empty.
+    let parent_burned =
+        collection_metadata_info.data_is_empty() || collection_metadata_info.data.borrow()[0] == 0;
+
+    let mut collection_metadata = if parent_burned {
+        None
+    } else {
+        assert_owned_by(collection_metadata_info, program_id)?;
+        let collection_metadata = Metadata::from_account_info(collection_metadata_info)?;
+
+        // If the collection parent is not burned, the required authority is the same for all
+        // items (see `unverify_collection_v1`), so it is validated once.
+        let authority_response = AuthorityType::get_authority_type(AuthorityRequest {
+            authority: ctx.accounts.authority_info.key,
+            update_authority: &collection_metadata.update_authority,
+            mint: collection_mint_info.key,
+            metadata_delegate_record_info: ctx.accounts.delegate_record_info,
+            metadata_delegate_roles: vec![MetadataDelegateRole::Collection],
+            precedence: &[AuthorityType::Metadata, AuthorityType::MetadataDelegate],
+            ..Default::default()
+        })?;
+
+        match authority_response.authority_type {
+            AuthorityType::Metadata | AuthorityType::MetadataDelegate => (),
+            _ => return Err(MetadataError::UpdateAuthorityIncorrect.into()),
+        }
+
+        Some(collection_metadata)
+    };
+
+    let results: Vec<VerificationResult> = items
+        .iter()
+        .map(|metadata_info| {
+            item_result(
+                metadata_info,
+                unverify_collection_item(program_id, &ctx, metadata_info, parent_burned),
+            )
+        })
+        .collect();
+
+    // In the case of a sized collection, update the size on the collection parent once
+    // for all unverified items.
+    let unverified = count_updated(&results);
+
+    if let Some(collection_metadata) = collection_metadata.as_mut() {
+        if unverified > 0 && collection_metadata.collection_details.is_some() {
+            decrement_collection_size_by(
+                collection_metadata,
+                collection_metadata_info,
+                unverified,
+            )?;
+        }
+    }
+
+    set_return_data(&results.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Verifies a single item of a batch. Returns `true` if the item was updated and `false`
+/// if it was already verified.
+fn verify_collection_item(
+    program_id: &Pubkey,
+    metadata_info: &AccountInfo,
+    collection_mint_info: &AccountInfo,
+    collection_metadata_info: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    assert_batch_item(program_id, metadata_info, collection_metadata_info)?;
+
+    let mut metadata = Metadata::from_account_info(metadata_info)?;
+
+    match metadata.collection.as_mut() {
+        Some(collection) if collection.key == *collection_mint_info.key => {
+            if collection.verified {
+                return Ok(false);
+            }
+            collection.verified = true;
+        }
+        _ => return Err(MetadataError::CollectionNotFound.into()),
+    }
+
+    clean_write_metadata(&mut metadata, metadata_info)?;
+
+    MetadataEvent::Verified {
+        mint: metadata.mint,
+        target: VerificationTarget::Collection(*collection_mint_info.key),
+    }
+    .emit()?;
+
+    Ok(true)
+}
+
+/// Unverifies a single item of a batch. Returns `true` if the item was updated and `false`
+/// if it was not verified.
+fn unverify_collection_item<'a>(
+    program_id: &Pubkey,
+    ctx: &Context<VerifyCollectionBatch<'a>>,
+    metadata_info: &AccountInfo,
+    parent_burned: bool,
+) -> Result<bool, ProgramError> {
+    assert_batch_item(
+        program_id,
+        metadata_info,
+        ctx.accounts.collection_metadata_info,
+    )?;
+
+    let mut metadata = Metadata::from_account_info(metadata_info)?;
+
+    match &metadata.collection {
+        // The collection parent must be the actual parent of the collection item.
+        Some(collection) if collection.verified => {
+            if collection.key != *ctx.accounts.collection_mint_info.key {
+                return Err(MetadataError::NotAMemberOfCollection.into());
+            }
+        }
+        _ => return Ok(false),
+    }
+
+    if parent_burned {
+        // If the collection parent is burned, the required authority is either the item's
+        // metadata update authority or a delegate for the item that can update the item's
+        // collection field.
+        let authority_response = AuthorityType::get_authority_type(AuthorityRequest {
+            authority: ctx.accounts.authority_info.key,
+            update_authority: &metadata.update_authority,
+            mint: &metadata.mint,
+            metadata_delegate_record_info: ctx.accounts.delegate_record_info,
+            metadata_delegate_roles: vec![
+                MetadataDelegateRole::Collection,
+                MetadataDelegateRole::CollectionItem,
+            ],
+            precedence: &[AuthorityType::Metadata, AuthorityType::MetadataDelegate],
+            ..Default::default()
+        })?;
+
+        match authority_response.authority_type {
+            AuthorityType::Metadata | AuthorityType::MetadataDelegate => (),
+            _ => return Err(MetadataError::UpdateAuthorityIncorrect.into()),
+        }
+    }
+
+    if let Some(collection) = metadata.collection.as_mut() {
+        collection.verified = false;
+    }
+
+    clean_write_metadata(&mut metadata, metadata_info)?;
+
+    MetadataEvent::Unverified {
+        mint: metadata.mint,
+        target: VerificationTarget::Collection(*ctx.accounts.collection_mint_info.key),
+    }
+    .emit()?;
+
+    Ok(true)
+}
+
+/// Checks that an item of a batch is a writable metadata account other than the collection
+/// parent, which is written once at the end of the batch.
+fn assert_batch_item(
+    program_id: &Pubkey,
+    metadata_info: &AccountInfo,
+    collection_metadata_info: &AccountInfo,
+) -> ProgramResult {
+    if metadata_info.key == collection_metadata_info.key {
+        return Err(MetadataError::CollectionCannotBeVerifiedInThisInstruction.into());
+    }
+
+    assert_owned_by(metadata_info, program_id)?;
+
+    if !metadata_info.is_writable {
+        return Err(MetadataError::AccountNotWritable.into());
+    }
+
+    Ok(())
+}
+
+fn item_result(
+    metadata_info: &AccountInfo,
+    result: Result<bool, ProgramError>,
+) -> VerificationResult {
+    match result {
+        Ok(true) => VerificationResult::Updated,
+        Ok(false) => VerificationResult::Unchanged,
+        Err(error) => {
+            msg!("Skipping item {}: {}", metadata_info.key, error);
+            VerificationResult::Failed {
+                error: error.into(),
+            }
+        }
+    }
+}
+
+fn count_updated(results: &[VerificationResult]) -> u64 {
+    results
+        .iter()
+        .filter(|result| matches!(result, VerificationResult::Updated))
+        .count() as u64
 }
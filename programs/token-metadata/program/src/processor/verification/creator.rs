@@ -4,6 +4,7 @@ use solana_program::{entrypoint::ProgramResult, pubkey::Pubkey};
 use crate::{
     assertions::assert_owned_by,
     error::MetadataError,
+    event::{MetadataEvent, VerificationTarget},
     instruction::{Context, Unverify, Verify},
     state::{Creator, Metadata, TokenMetadataAccount},
     utils::metadata::clean_write_metadata,
@@ -27,7 +28,15 @@ pub(crate) fn verify_creator_v1(program_id: &Pubkey, ctx: Context<Verify>) -> Pr
     )?;
 
     // Reserialize item metadata.
-    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)
+    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)?;
+
+    MetadataEvent::Verified {
+        mint: metadata.mint,
+        target: VerificationTarget::Creator(*ctx.accounts.authority_info.key),
+    }
+    .emit()?;
+
+    Ok(())
 }
 
 pub(crate) fn unverify_creator_v1(program_id: &Pubkey, ctx: Context<Unverify>) -> ProgramResult {
@@ -48,7 +57,15 @@ pub(crate) fn unverify_creator_v1(program_id: &Pubkey, ctx: Context<Unverify>) -
     )?;
 
     // Reserialize item metadata.
-    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)
+    clean_write_metadata(&mut metadata, ctx.accounts.metadata_info)?;
+
+    MetadataEvent::Unverified {
+        mint: metadata.mint,
+        target: VerificationTarget::Creator(*ctx.accounts.authority_info.key),
+    }
+    .emit()?;
+
+    Ok(())
 }
 
 fn find_and_set_creator(
@@ -1,9 +1,17 @@
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
 
 use crate::{
-    instruction::{Unverify, VerificationArgs, Verify},
+    instruction::{
+        Unverify, VerificationArgs, Verify, VerifyCollectionBatch, VerifyCollectionBatchArgs,
+    },
     processor::verification::{
-        collection::{unverify_collection_v1, verify_collection_v1},
+        collection::{
+            unverify_collection_batch_v1, unverify_collection_v1, verify_collection_batch_v1,
+            verify_collection_v1,
+        },
         creator::{unverify_creator_v1, verify_creator_v1},
     },
 };
@@ -33,3 +41,29 @@ pub fn unverify<'a>(
         VerificationArgs::CollectionV1 => unverify_collection_v1(program_id, context),
     }
 }
+
+/// Number of accounts of the `VerifyCollectionBatch` instruction that precede the
+/// item metadata accounts.
+const VERIFY_COLLECTION_BATCH_ACCOUNTS: usize = 7;
+
+pub fn verify_collection_batch<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: VerifyCollectionBatchArgs,
+) -> ProgramResult {
+    let context = VerifyCollectionBatch::to_context(accounts)?;
+    let items = &accounts[VERIFY_COLLECTION_BATCH_ACCOUNTS..];
+
+    if items.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    match args {
+        VerifyCollectionBatchArgs::VerifyV1 => {
+            verify_collection_batch_v1(program_id, context, items)
+        }
+        VerifyCollectionBatchArgs::UnverifyV1 => {
+            unverify_collection_batch_v1(program_id, context, items)
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::mem::size_of;
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::{deserialize, BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER},
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+};
+
+use super::SimulatedAccount;
+
+/// Program input serialized using the (aligned) layout of the runtime.
+///
+/// The buffer is backed by `u64` words so that every field read by `deserialize`
+/// is properly aligned.
+pub(crate) struct InputBuffer {
+    words: Vec<u64>,
+}
+
+impl InputBuffer {
+    pub fn serialize(
+        accounts: &[(AccountMeta, SimulatedAccount)],
+        instruction_data: &[u8],
+        program_id: &Pubkey,
+    ) -> Self {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&(accounts.len() as u64).to_le_bytes());
+
+        for (index, (meta, account)) in accounts.iter().enumerate() {
+            let position = accounts[..index]
+                .iter()
+                .position(|(other, _)| other.pubkey == meta.pubkey);
+
+            if let Some(position) = position {
+                buffer.push(position as u8);
+                buffer.extend_from_slice(&[0; 7]);
+                continue;
+            }
+
+            // duplicated accounts share the privileges of all their occurrences
+            let (is_signer, is_writable) = accounts
+                .iter()
+                .filter(|(other, _)| other.pubkey == meta.pubkey)
+                .fold((false, false), |(signer, writable), (other, _)| {
+                    (signer || other.is_signer, writable || other.is_writable)
+                });
+
+            buffer.push(NON_DUP_MARKER);
+            buffer.push(is_signer as u8);
+            buffer.push(is_writable as u8);
+            buffer.push(account.executable as u8);
+            // original data length, set by `deserialize`
+            buffer.extend_from_slice(&[0; 4]);
+            buffer.extend_from_slice(meta.pubkey.as_ref());
+            buffer.extend_from_slice(account.owner.as_ref());
+            buffer.extend_from_slice(&account.lamports.to_le_bytes());
+            buffer.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            buffer.extend_from_slice(&account.data);
+            buffer.resize(buffer.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            buffer.resize(align(buffer.len()), 0);
+            // rent epoch
+            buffer.extend_from_slice(&u64::MAX.to_le_bytes());
+        }
+
+        buffer.extend_from_slice(&(instruction_data.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(instruction_data);
+        buffer.extend_from_slice(program_id.as_ref());
+
+        let mut words = vec![0u64; buffer.len().div_ceil(size_of::<u64>())];
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                words.as_mut_ptr() as *mut u8,
+                words.len() * size_of::<u64>(),
+            )
+        };
+        bytes[..buffer.len()].copy_from_slice(&buffer);
+
+        Self { words }
+    }
+
+    /// Returns the program id, account infos and instruction data of the input.
+    ///
+    /// # Safety
+    ///
+    /// The returned references point into the buffer, which must outlive them.
+    pub unsafe fn deserialize<'a>(&mut self) -> (&'a Pubkey, Vec<AccountInfo<'a>>, &'a [u8]) {
+        deserialize(self.words.as_mut_ptr() as *mut u8)
+    }
+
+    /// Reads the current state of the accounts.
+    pub fn accounts(account_infos: &[AccountInfo]) -> Vec<(Pubkey, SimulatedAccount)> {
+        account_infos
+            .iter()
+            .map(|info| {
+                (
+                    *info.key,
+                    SimulatedAccount {
+                        lamports: info.lamports(),
+                        data: info.data.borrow().to_vec(),
+                        owner: *info.owner,
+                        executable: info.executable,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+fn align(offset: usize) -> usize {
+    offset.div_ceil(BPF_ALIGN_OF_U128) * BPF_ALIGN_OF_U128
+}
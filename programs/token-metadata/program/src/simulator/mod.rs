@@ -0,0 +1,264 @@
+//! Offline simulator for the Token Metadata program.
+//!
+//! The simulator executes `process_instruction` natively against an in-memory
+//! account store, without requiring the BPF loader used by `solana-program-test`.
+//! Accounts are serialized using the same input layout as the runtime, so account
+//! reallocation and ownership changes behave as they do on-chain.
+//!
+//! CPIs to the System, SPL Token, SPL Token-2022, Associated Token Account and
+//! Token Auth Rules programs are executed natively by the simulator; any other
+//! program invoked by an instruction results in an `IncorrectProgramId` error.
+//!
+//! The simulator does not meter compute units, check rent exemption or enforce
+//! the runtime account ownership rules beyond read-only accounts and balance
+//! conservation. Only one simulation can run at a time on each thread, and the
+//! syscall stubs installed by the simulator replace any previously installed
+//! stubs (e.g., the ones from `solana-program-test`).
+
+mod input;
+mod stubs;
+
+use std::collections::HashMap;
+
+use num_traits::FromPrimitive;
+use solana_program::{
+    clock::Clock,
+    instruction::Instruction,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program, sysvar,
+    sysvar::instructions::{construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction},
+};
+
+use crate::{error::MetadataError, processor::process_instruction};
+
+/// An account in the simulator store.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SimulatedAccount {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+impl SimulatedAccount {
+    pub fn new(lamports: u64, data: Vec<u8>, owner: Pubkey) -> Self {
+        Self {
+            lamports,
+            data,
+            owner,
+            executable: false,
+        }
+    }
+}
+
+/// Change of an account caused by an instruction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountDiff {
+    pub address: Pubkey,
+    /// State before the instruction (`None` if the account did not exist).
+    pub before: Option<SimulatedAccount>,
+    /// State after the instruction (`None` if the account was closed).
+    pub after: Option<SimulatedAccount>,
+}
+
+/// Reason for a failed simulation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SimulationError {
+    /// A program returned an error.
+    Program {
+        program_id: Pubkey,
+        error: ProgramError,
+    },
+    /// A read-only account was modified.
+    ReadonlyAccountModified(Pubkey),
+    /// The total lamports of the accounts changed.
+    UnbalancedInstruction,
+}
+
+/// Outcome of a simulated instruction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SimulationResult {
+    pub result: Result<(), SimulationError>,
+    /// Program logs, using the same format as the runtime.
+    pub logs: Vec<String>,
+    /// Accounts changed by the instruction (empty when the instruction failed).
+    pub diffs: Vec<AccountDiff>,
+}
+
+impl SimulationResult {
+    /// Returns the `MetadataError` raised by the Token Metadata program, if any.
+    pub fn metadata_error(&self) -> Option<MetadataError> {
+        match &self.result {
+            Err(SimulationError::Program {
+                program_id,
+                error: ProgramError::Custom(code),
+            }) if *program_id == crate::ID => MetadataError::from_u32(*code),
+            _ => None,
+        }
+    }
+}
+
+/// In-memory executor of Token Metadata instructions.
+#[derive(Clone, Debug, Default)]
+pub struct Simulator {
+    accounts: HashMap<Pubkey, SimulatedAccount>,
+    clock: Clock,
+}
+
+impl Simulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds (or replaces) an account in the store.
+    pub fn add_account(&mut self, address: Pubkey, account: SimulatedAccount) {
+        self.accounts.insert(address, account);
+    }
+
+    /// Returns an account from the store.
+    pub fn account(&self, address: &Pubkey) -> Option<&SimulatedAccount> {
+        self.accounts.get(address)
+    }
+
+    /// Sets the clock returned by the `Clock` sysvar.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    /// Executes an instruction, updating the store when it succeeds.
+    ///
+    /// Accounts referenced by the instruction that are not in the store are treated
+    /// as empty system accounts; the instructions sysvar is generated automatically.
+    pub fn process(&mut self, instruction: &Instruction) -> SimulationResult {
+        stubs::install();
+
+        let mut metas = Vec::with_capacity(instruction.accounts.len());
+        for meta in &instruction.accounts {
+            let account = if meta.pubkey == sysvar::instructions::ID {
+                self.instructions_sysvar(instruction)
+            } else {
+                self.accounts
+                    .get(&meta.pubkey)
+                    .cloned()
+                    .unwrap_or_else(|| empty_account(&meta.pubkey))
+            };
+            metas.push((meta.clone(), account));
+        }
+
+        let mut input = input::InputBuffer::serialize(&metas, &instruction.data, &crate::ID);
+
+        stubs::begin(self.clock.clone());
+        let (result, accounts) = {
+            let (program_id, account_infos, data) = unsafe { input.deserialize() };
+            let result = stubs::execute(program_id, || {
+                process_instruction(program_id, &account_infos, data)
+            });
+            (result, input::InputBuffer::accounts(&account_infos))
+        };
+        let context = stubs::end();
+
+        let mut result = result.map_err(|error| SimulationError::Program {
+            program_id: context.failed_program.unwrap_or(crate::ID),
+            error,
+        });
+
+        let mut diffs = Vec::new();
+
+        if result.is_ok() {
+            // duplicated accounts are only counted once
+            let unique = |index: usize, address: &Pubkey| {
+                !instruction.accounts[..index]
+                    .iter()
+                    .any(|meta| meta.pubkey == *address)
+            };
+
+            let before: u128 = metas
+                .iter()
+                .enumerate()
+                .filter(|(index, (meta, _))| unique(*index, &meta.pubkey))
+                .map(|(_, (_, account))| account.lamports as u128)
+                .sum();
+            let after: u128 = accounts
+                .iter()
+                .enumerate()
+                .filter(|(index, (address, _))| unique(*index, address))
+                .map(|(_, (_, account))| account.lamports as u128)
+                .sum();
+
+            if before != after {
+                result = Err(SimulationError::UnbalancedInstruction);
+            }
+
+            for ((_, original), (address, account)) in metas.iter().zip(accounts.iter()) {
+                let is_writable = instruction
+                    .accounts
+                    .iter()
+                    .any(|meta| meta.pubkey == *address && meta.is_writable);
+
+                if original != account && !is_writable {
+                    result = Err(SimulationError::ReadonlyAccountModified(*address));
+                }
+            }
+        }
+
+        if result.is_ok() {
+            for (address, account) in accounts {
+                if address == sysvar::instructions::ID
+                    || diffs.iter().any(|d: &AccountDiff| d.address == address)
+                {
+                    continue;
+                }
+
+                let before = self.accounts.get(&address).cloned();
+                // accounts without lamports are removed at the end of the instruction
+                let after = (account.lamports > 0).then_some(account);
+
+                if before != after {
+                    match &after {
+                        Some(account) => self.accounts.insert(address, account.clone()),
+                        None => self.accounts.remove(&address),
+                    };
+                    diffs.push(AccountDiff {
+                        address,
+                        before,
+                        after,
+                    });
+                }
+            }
+        }
+
+        SimulationResult {
+            result,
+            logs: context.logs,
+            diffs,
+        }
+    }
+
+    fn instructions_sysvar(&self, instruction: &Instruction) -> SimulatedAccount {
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|meta| BorrowedAccountMeta {
+                pubkey: &meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect();
+
+        let data = construct_instructions_data(&[BorrowedInstruction {
+            program_id: &instruction.program_id,
+            accounts,
+            data: &instruction.data,
+        }]);
+
+        SimulatedAccount::new(1, data, sysvar::ID)
+    }
+}
+
+/// Returns the default state of an account not present in the store.
+fn empty_account(address: &Pubkey) -> SimulatedAccount {
+    let mut account = SimulatedAccount::new(0, vec![], system_program::ID);
+    account.executable = stubs::is_supported_program(address);
+    account
+}
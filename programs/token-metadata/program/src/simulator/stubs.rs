@@ -0,0 +1,397 @@
+use std::{cell::RefCell, sync::Once};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::{
+    account_info::AccountInfo,
+    bpf_loader,
+    clock::Clock,
+    entrypoint::{ProgramResult, SUCCESS},
+    instruction::Instruction,
+    program_error::{ProgramError, UNSUPPORTED_SYSVAR},
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{SystemError, MAX_PERMITTED_DATA_LENGTH},
+    system_program,
+};
+
+use crate::processor::process_instruction;
+
+/// Execution state of the simulation running on the current thread.
+#[derive(Default)]
+pub(crate) struct Context {
+    pub logs: Vec<String>,
+    pub failed_program: Option<Pubkey>,
+    stack: Vec<Pubkey>,
+    return_data: Option<(Pubkey, Vec<u8>)>,
+    clock: Clock,
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Context> = RefCell::new(Context::default());
+}
+
+static INSTALL: Once = Once::new();
+
+/// Installs the simulator syscall stubs.
+pub(crate) fn install() {
+    INSTALL.call_once(|| {
+        set_syscall_stubs(Box::new(SimulatorStubs));
+    });
+}
+
+/// Resets the execution state of the current thread.
+pub(crate) fn begin(clock: Clock) {
+    CONTEXT.with(|context| {
+        *context.borrow_mut() = Context {
+            clock,
+            ..Default::default()
+        }
+    });
+}
+
+/// Returns the execution state of the current thread.
+pub(crate) fn end() -> Context {
+    CONTEXT.with(|context| context.take())
+}
+
+/// Executes a program invocation, logging its outcome.
+pub(crate) fn execute<F>(program_id: &Pubkey, process: F) -> ProgramResult
+where
+    F: FnOnce() -> ProgramResult,
+{
+    CONTEXT.with(|context| {
+        let mut context = context.borrow_mut();
+        context.stack.push(*program_id);
+        let depth = context.stack.len();
+        context
+            .logs
+            .push(format!("Program {} invoke [{}]", program_id, depth));
+    });
+
+    let result = process();
+
+    CONTEXT.with(|context| {
+        let mut context = context.borrow_mut();
+        context.stack.pop();
+
+        match &result {
+            Ok(()) => context.logs.push(format!("Program {} success", program_id)),
+            Err(error) => {
+                context
+                    .logs
+                    .push(format!("Program {} failed: {}", program_id, error));
+                // the innermost failure is the one reported
+                context.failed_program.get_or_insert(*program_id);
+            }
+        }
+    });
+
+    result
+}
+
+/// Programs that can be invoked through CPI.
+const SUPPORTED_PROGRAMS: [Pubkey; 6] = [
+    system_program::ID,
+    spl_token::ID,
+    spl_token_2022::ID,
+    spl_associated_token_account::ID,
+    mpl_token_auth_rules::ID,
+    crate::ID,
+];
+
+/// Indicates whether CPIs to the program can be executed by the simulator.
+pub(crate) fn is_supported_program(program_id: &Pubkey) -> bool {
+    SUPPORTED_PROGRAMS.contains(program_id)
+}
+
+thread_local! {
+    /// Executable accounts of the supported programs. As in the runtime, CPIs can
+    /// reference them without the caller providing their account info.
+    static PROGRAM_ACCOUNTS: Vec<AccountInfo<'static>> = SUPPORTED_PROGRAMS
+        .iter()
+        .map(|program_id| {
+            AccountInfo::new(
+                Box::leak(Box::new(*program_id)),
+                false,
+                false,
+                Box::leak(Box::new(1)),
+                Box::leak(Box::new([])),
+                &bpf_loader::ID,
+                true,
+                0,
+            )
+        })
+        .collect();
+}
+
+fn current_program() -> Pubkey {
+    CONTEXT.with(|context| context.borrow().stack.last().copied().unwrap_or(crate::ID))
+}
+
+fn log(message: String) {
+    CONTEXT.with(|context| context.borrow_mut().logs.push(message));
+}
+
+struct SimulatorStubs;
+
+impl SyscallStubs for SimulatorStubs {
+    fn sol_log(&self, message: &str) {
+        log(format!("Program log: {}", message));
+    }
+
+    fn sol_log_compute_units(&self) {}
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        let fields = fields
+            .iter()
+            .map(|field| STANDARD.encode(field))
+            .collect::<Vec<_>>();
+        log(format!("Program data: {}", fields.join(" ")));
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let caller = current_program();
+
+        let signers = signers_seeds
+            .iter()
+            .map(|seeds| Pubkey::create_program_address(seeds, &caller))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ProgramError::InvalidSeeds)?;
+
+        let mut accounts = Vec::with_capacity(instruction.accounts.len());
+
+        for meta in &instruction.accounts {
+            let info = match account_infos.iter().find(|info| *info.key == meta.pubkey) {
+                // the account infos share their lamports and data with the caller, so
+                // the callee only needs them for the duration of the invocation
+                Some(info) => unsafe {
+                    std::mem::transmute::<AccountInfo, AccountInfo<'static>>(info.clone())
+                },
+                None => PROGRAM_ACCOUNTS
+                    .with(|programs| programs.iter().find(|p| *p.key == meta.pubkey).cloned())
+                    .ok_or_else(|| {
+                        log(format!(
+                            "Instruction references an unknown account {}",
+                            meta.pubkey
+                        ));
+                        ProgramError::NotEnoughAccountKeys
+                    })?,
+            };
+
+            // duplicated accounts share the privileges of all their occurrences
+            let (is_signer, is_writable) = instruction
+                .accounts
+                .iter()
+                .filter(|other| other.pubkey == meta.pubkey)
+                .fold((false, false), |(signer, writable), other| {
+                    (signer || other.is_signer, writable || other.is_writable)
+                });
+
+            if is_writable && !info.is_writable {
+                log(format!("{}'s writable privilege escalated", meta.pubkey));
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if is_signer && !info.is_signer && !signers.contains(&meta.pubkey) {
+                log(format!("{}'s signer privilege escalated", meta.pubkey));
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            let mut info = info;
+            info.is_signer = is_signer;
+            info.is_writable = is_writable;
+            accounts.push(info);
+        }
+
+        let accounts: &[AccountInfo] = unsafe { std::mem::transmute(accounts.as_slice()) };
+
+        execute(&instruction.program_id, || {
+            dispatch(&instruction.program_id, accounts, &instruction.data)
+        })
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = CONTEXT.with(|context| context.borrow().clock.clone());
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_get_epoch_schedule_sysvar(&self, _var_addr: *mut u8) -> u64 {
+        UNSUPPORTED_SYSVAR
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        CONTEXT.with(|context| context.borrow().return_data.clone())
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        let program_id = current_program();
+        CONTEXT.with(|context| {
+            context.borrow_mut().return_data = if data.is_empty() {
+                None
+            } else {
+                Some((program_id, data.to_vec()))
+            }
+        });
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        CONTEXT.with(|context| context.borrow().stack.len() as u64)
+    }
+}
+
+/// Executes an instruction of one of the supported programs.
+fn dispatch<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    data: &[u8],
+) -> ProgramResult {
+    if *program_id == system_program::ID {
+        process_system_instruction(accounts, data)
+    } else if *program_id == spl_token::ID {
+        spl_token::processor::Processor::process(program_id, accounts, data)
+    } else if *program_id == spl_token_2022::ID {
+        spl_token_2022::processor::Processor::process(program_id, accounts, data)
+    } else if *program_id == spl_associated_token_account::ID {
+        spl_associated_token_account::processor::process_instruction(program_id, accounts, data)
+    } else if *program_id == mpl_token_auth_rules::ID {
+        mpl_token_auth_rules::processor::Processor::process_instruction(program_id, accounts, data)
+    } else if *program_id == crate::ID {
+        process_instruction(&crate::ID, accounts, data)
+    } else {
+        log(format!(
+            "Program {} is not supported by the simulator",
+            program_id
+        ));
+        Err(ProgramError::IncorrectProgramId)
+    }
+}
+
+/// Executes the subset of System program instructions used by the supported programs.
+fn process_system_instruction(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let account = |index: usize| {
+        accounts
+            .get(index)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
+
+    match read_u32(data, 0)? {
+        // CreateAccount { lamports, space, owner }
+        0 => {
+            let (from, to) = (account(0)?, account(1)?);
+            allocate(to, read_u64(data, 12)?)?;
+            assign(to, &read_pubkey(data, 20)?)?;
+            transfer(from, to, read_u64(data, 4)?)
+        }
+        // Assign { owner }
+        1 => assign(account(0)?, &read_pubkey(data, 4)?),
+        // Transfer { lamports }
+        2 => transfer(account(0)?, account(1)?, read_u64(data, 4)?),
+        // Allocate { space }
+        8 => allocate(account(0)?, read_u64(data, 4)?),
+        discriminator => {
+            log(format!(
+                "System instruction {} is not supported by the simulator",
+                discriminator
+            ));
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+}
+
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !account.data_is_empty() || *account.owner != system_program::ID {
+        log(format!("Allocate: account {} already in use", account.key));
+        return Err(ProgramError::Custom(
+            SystemError::AccountAlreadyInUse as u32,
+        ));
+    }
+
+    if space > MAX_PERMITTED_DATA_LENGTH {
+        return Err(ProgramError::Custom(
+            SystemError::InvalidAccountDataLength as u32,
+        ));
+    }
+
+    account.realloc(space as usize, true)
+}
+
+fn assign(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner == owner {
+        return Ok(());
+    }
+
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if *account.owner != system_program::ID {
+        return Err(ProgramError::Custom(SystemError::InvalidProgramId as u32));
+    }
+
+    account.assign(owner);
+    Ok(())
+}
+
+fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if !from.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !from.data_is_empty() || *from.owner != system_program::ID {
+        log("Transfer: `from` must not carry data".to_string());
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if from.lamports() < lamports {
+        log(format!(
+            "Transfer: insufficient lamports {}, need {}",
+            from.lamports(),
+            lamports
+        ));
+        return Err(ProgramError::Custom(
+            SystemError::ResultWithNegativeLamports as u32,
+        ));
+    }
+
+    **from.try_borrow_mut_lamports()? -= lamports;
+    **to.try_borrow_mut_lamports()? += lamports;
+
+    Ok(())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, ProgramError> {
+    data.get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+    data.get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey, ProgramError> {
+    data.get(offset..offset + 32)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(Pubkey::new_from_array)
+        .ok_or(ProgramError::InvalidInstructionData)
+}
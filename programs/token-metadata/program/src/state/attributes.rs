@@ -0,0 +1,207 @@
+use super::*;
+
+pub const ATTRIBUTES_SEED: &str = "attributes";
+
+pub const MAX_ATTRIBUTES: usize = 32;
+
+pub const MAX_ATTRIBUTE_KEY_LENGTH: usize = 32;
+
+pub const MAX_ATTRIBUTE_VALUE_LENGTH: usize = 64;
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Attribute {
+    pub key: String,
+    pub value: String,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankAccount)]
+/// SEEDS = [
+///     "metadata",
+///     program id,
+///     mint id,
+///     "attributes"
+/// ]
+pub struct MetadataAttributes {
+    pub key: Key, // 1
+    pub bump: u8, // 1
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    pub mint: Pubkey, // 32
+    /// Key/value pairs, in insertion order. The account is resized as pairs are
+    /// added or removed.
+    pub attributes: Vec<Attribute>, // 4 + (4 + key + 4 + value) * len
+}
+
+impl Default for MetadataAttributes {
+    fn default() -> Self {
+        Self {
+            key: Key::MetadataAttributes,
+            bump: 255,
+            mint: Pubkey::default(),
+            attributes: vec![],
+        }
+    }
+}
+
+impl TokenMetadataAccount for MetadataAttributes {
+    fn key() -> Key {
+        Key::MetadataAttributes
+    }
+
+    fn size() -> usize {
+        0
+    }
+}
+
+impl MetadataAttributes {
+    pub fn from_bytes(data: &[u8]) -> Result<MetadataAttributes, ProgramError> {
+        let attributes: MetadataAttributes =
+            try_from_slice_checked(data, Key::MetadataAttributes, 0)?;
+        Ok(attributes)
+    }
+
+    /// Reads the attributes of a mint from its (CPI) account info, checking that the
+    /// account is the attributes PDA of the mint.
+    pub fn from_account_info_checked(
+        attributes_info: &AccountInfo,
+        mint: &Pubkey,
+    ) -> Result<MetadataAttributes, ProgramError> {
+        let attributes = Self::from_account_info(attributes_info)?;
+
+        let expected = Pubkey::create_program_address(
+            &[
+                PREFIX.as_bytes(),
+                crate::ID.as_ref(),
+                mint.as_ref(),
+                ATTRIBUTES_SEED.as_bytes(),
+                &[attributes.bump],
+            ],
+            &crate::ID,
+        )?;
+
+        if attributes.mint != *mint || expected != *attributes_info.key {
+            return Err(MetadataError::DerivedKeyInvalid.into());
+        }
+
+        Ok(attributes)
+    }
+
+    /// Returns the value of the attribute with the specified key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    /// Sets the value of an attribute, adding it if the key is not present.
+    pub fn set(&mut self, key: String, value: String) -> Result<(), MetadataError> {
+        if key.len() > MAX_ATTRIBUTE_KEY_LENGTH {
+            return Err(MetadataError::AttributeKeyTooLong);
+        }
+
+        if value.len() > MAX_ATTRIBUTE_VALUE_LENGTH {
+            return Err(MetadataError::AttributeValueTooLong);
+        }
+
+        match self.attributes.iter_mut().find(|a| a.key == key) {
+            Some(attribute) => attribute.value = value,
+            None => {
+                if self.attributes.len() >= MAX_ATTRIBUTES {
+                    return Err(MetadataError::TooManyAttributes);
+                }
+                self.attributes.push(Attribute { key, value });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the attribute with the specified key, returning whether it was present.
+    pub fn remove(&mut self, key: &str) -> bool {
+        let len = self.attributes.len();
+        self.attributes.retain(|attribute| attribute.key != key);
+        self.attributes.len() != len
+    }
+
+    /// Saves the attributes to the specified account, resizing the account to the
+    /// serialized length.
+    pub(crate) fn save<'a>(
+        &self,
+        account_info: &'a AccountInfo<'a>,
+        payer_info: &'a AccountInfo<'a>,
+        system_program_info: &'a AccountInfo<'a>,
+    ) -> ProgramResult {
+        let data = self.try_to_vec()?;
+
+        if account_info.data_len() != data.len() {
+            resize_or_reallocate_account_raw(
+                account_info,
+                payer_info,
+                system_program_info,
+                data.len(),
+            )?;
+        }
+
+        account_info.try_borrow_mut_data()?[..].copy_from_slice(&data);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_remove_attributes() {
+        let mut attributes = MetadataAttributes::default();
+
+        attributes
+            .set("level".to_string(), "1".to_string())
+            .unwrap();
+        attributes
+            .set("class".to_string(), "mage".to_string())
+            .unwrap();
+        attributes
+            .set("level".to_string(), "2".to_string())
+            .unwrap();
+
+        assert_eq!(attributes.attributes.len(), 2);
+        assert_eq!(attributes.get("level"), Some("2"));
+        assert_eq!(attributes.get("class"), Some("mage"));
+
+        assert!(attributes.remove("level"));
+        assert!(!attributes.remove("level"));
+        assert_eq!(attributes.get("level"), None);
+
+        let bytes = attributes.try_to_vec().unwrap();
+        assert_eq!(MetadataAttributes::from_bytes(&bytes).unwrap(), attributes);
+    }
+
+    #[test]
+    fn fail_invalid_attributes() {
+        let mut attributes = MetadataAttributes::default();
+
+        assert_eq!(
+            attributes.set("k".repeat(MAX_ATTRIBUTE_KEY_LENGTH + 1), String::new()),
+            Err(MetadataError::AttributeKeyTooLong)
+        );
+        assert_eq!(
+            attributes.set(String::new(), "v".repeat(MAX_ATTRIBUTE_VALUE_LENGTH + 1)),
+            Err(MetadataError::AttributeValueTooLong)
+        );
+
+        for i in 0..MAX_ATTRIBUTES {
+            attributes.set(i.to_string(), String::new()).unwrap();
+        }
+
+        assert_eq!(
+            attributes.set("other".to_string(), String::new()),
+            Err(MetadataError::TooManyAttributes)
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+pub const PENDING_UPDATE_AUTHORITY_SEED: &str = "pending_update_authority";
+
+const SIZE: usize = 98;
+
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankAccount)]
+/// SEEDS = [
+///     "metadata",
+///     program id,
+///     mint id,
+///     "pending_update_authority"
+/// ]
+pub struct PendingUpdateAuthority {
+    pub key: Key, // 1
+    pub bump: u8, // 1
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    pub mint: Pubkey, // 32
+    /// Update authority that proposed the handoff (and receives the rent back).
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    pub update_authority: Pubkey, // 32
+    /// Proposed update authority, which must sign to accept the handoff.
+    #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
+    pub new_update_authority: Pubkey, // 32
+}
+
+impl Default for PendingUpdateAuthority {
+    fn default() -> Self {
+        Self {
+            key: Key::PendingUpdateAuthority,
+            bump: 255,
+            mint: Pubkey::default(),
+            update_authority: Pubkey::default(),
+            new_update_authority: Pubkey::default(),
+        }
+    }
+}
+
+impl TokenMetadataAccount for PendingUpdateAuthority {
+    fn key() -> Key {
+        Key::PendingUpdateAuthority
+    }
+
+    fn size() -> usize {
+        SIZE
+    }
+}
+
+impl PendingUpdateAuthority {
+    pub fn from_bytes(data: &[u8]) -> Result<PendingUpdateAuthority, ProgramError> {
+        let pending: PendingUpdateAuthority = try_from_slice_checked(
+            data,
+            Key::PendingUpdateAuthority,
+            PendingUpdateAuthority::size(),
+        )?;
+        Ok(pending)
+    }
+}
@@ -1,7 +1,43 @@
+use solana_program::{clock::Clock, sysvar::Sysvar};
+
 use super::*;
 
 const SIZE: usize = 98;
 
+/// Size of an optional `DelegateExpiry`.
+pub const DELEGATE_EXPIRY_SIZE: usize = 10;
+
+pub const METADATA_DELEGATE_RECORD_SIZE: usize = SIZE + DELEGATE_EXPIRY_SIZE;
+
+/// Point in time at which a delegate stops being valid.
+#[repr(C)]
+#[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DelegateExpiry {
+    /// The delegate expires once the cluster reaches the slot.
+    Slot(u64),
+    /// The delegate expires once the cluster clock reaches the unix timestamp.
+    UnixTimestamp(i64),
+}
+
+impl DelegateExpiry {
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        match self {
+            Self::Slot(slot) => clock.slot >= *slot,
+            Self::UnixTimestamp(timestamp) => clock.unix_timestamp >= *timestamp,
+        }
+    }
+}
+
+/// Checks whether an (optional) expiry has been reached, reading the `Clock` sysvar
+/// only when an expiry is set.
+pub(crate) fn has_expired(expiry: &Option<DelegateExpiry>) -> Result<bool, ProgramError> {
+    match expiry {
+        Some(expiry) => Ok(expiry.is_expired(&Clock::get()?)),
+        None => Ok(false),
+    }
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "serde-feature", derive(Serialize, Deserialize))]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankAccount)]
@@ -22,6 +58,9 @@ pub struct MetadataDelegateRecord {
     pub delegate: Pubkey, // 32
     #[cfg_attr(feature = "serde-feature", serde(with = "As::<DisplayFromStr>"))]
     pub update_authority: Pubkey, // 32
+    /// Expiry of the delegate, if any. Records created before expiries were supported
+    /// do not store this field.
+    pub expiry: Option<DelegateExpiry>, // 10
 }
 
 impl Default for MetadataDelegateRecord {
@@ -32,6 +71,7 @@ impl Default for MetadataDelegateRecord {
             mint: Pubkey::default(),
             delegate: Pubkey::default(),
             update_authority: Pubkey::default(),
+            expiry: None,
         }
     }
 }
@@ -42,15 +82,72 @@ impl TokenMetadataAccount for MetadataDelegateRecord {
     }
 
     fn size() -> usize {
-        SIZE
+        METADATA_DELEGATE_RECORD_SIZE
+    }
+
+    fn safe_deserialize(data: &[u8]) -> Result<Self, BorshError> {
+        Self::from_bytes(data).map_err(|e| BorshError::new(ErrorKind::Other, e.to_string()))
+    }
+
+    fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        let record = Self::from_bytes(&account_info.try_borrow_data()?)?;
+
+        // Check that this is a `token-metadata` owned account.
+        assert_owned_by(account_info, &ID)?;
+
+        Ok(record)
     }
 }
 
 impl MetadataDelegateRecord {
     pub fn from_bytes(data: &[u8]) -> Result<MetadataDelegateRecord, ProgramError> {
-        let delegate: MetadataDelegateRecord =
-            try_from_slice_checked(data, Key::MetadataDelegate, MetadataDelegateRecord::size())?;
-        Ok(delegate)
+        <Self as Resizable>::from_bytes(data)
+    }
+
+    /// Indicates whether the delegate has expired.
+    pub fn is_expired(&self) -> Result<bool, ProgramError> {
+        has_expired(&self.expiry)
+    }
+}
+
+impl Resizable for MetadataDelegateRecord {
+    fn from_bytes(account_data: &[u8]) -> Result<MetadataDelegateRecord, ProgramError> {
+        // records created before expiries were supported do not have space
+        // for the expiry
+        let length = MetadataDelegateRecord::size() as i64 - account_data.len() as i64;
+
+        if !(length == 0 || length == DELEGATE_EXPIRY_SIZE as i64)
+            || !MetadataDelegateRecord::is_correct_account_type(
+                account_data,
+                Key::MetadataDelegate,
+                account_data.len(),
+            )
+        {
+            return Err(MetadataError::DataTypeMismatch.into());
+        }
+        // mutable "pointer" to the account data
+        let mut data = account_data;
+
+        let key: Key = BorshDeserialize::deserialize(&mut data)?;
+        let bump: u8 = BorshDeserialize::deserialize(&mut data)?;
+        let mint: Pubkey = BorshDeserialize::deserialize(&mut data)?;
+        let delegate: Pubkey = BorshDeserialize::deserialize(&mut data)?;
+        let update_authority: Pubkey = BorshDeserialize::deserialize(&mut data)?;
+
+        let expiry: Option<DelegateExpiry> = if length == 0 {
+            BorshDeserialize::deserialize(&mut data)?
+        } else {
+            None
+        };
+
+        Ok(MetadataDelegateRecord {
+            key,
+            bump,
+            mint,
+            delegate,
+            update_authority,
+            expiry,
+        })
     }
 }
 
@@ -1,4 +1,6 @@
 pub(crate) mod asset_data;
+pub(crate) mod attributes;
+pub(crate) mod authority;
 pub(crate) mod collection;
 pub(crate) mod creator;
 pub(crate) mod data;
@@ -19,6 +21,8 @@ pub(crate) mod uses;
 use std::io::ErrorKind;
 
 pub use asset_data::*;
+pub use attributes::*;
+pub use authority::*;
 use borsh::{maybestd::io::Error as BorshError, BorshDeserialize, BorshSerialize};
 pub use collection::*;
 pub use creator::*;
@@ -155,6 +159,8 @@ pub enum Key {
     MetadataDelegate,
     EditionMarkerV2,
     HolderDelegate,
+    PendingUpdateAuthority,
+    MetadataAttributes,
 }
 
 #[cfg(feature = "serde-feature")]
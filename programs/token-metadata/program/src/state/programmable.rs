@@ -29,13 +29,17 @@ pub const TOKEN_STATE_INDEX: usize = 2;
 
 pub const LOCKED_TRANSFER_SIZE: usize = 33; // Optional Pubkey
 
+pub const UNLOCK_AFTER_SIZE: usize = 9; // Optional i64
+
 pub const TOKEN_RECORD_SIZE: usize = 1 // Key
 + 1   // bump
 + 1   // state
 + 9   // rule set revision
 + 33  // delegate
 + 2   // delegate role
-+ 33; // locked transfer
++ 33  // locked transfer
++ 10  // delegate expiry
++ 9; // unlock after
 
 /// The `TokenRecord` struct represents the state of the token account holding a `pNFT`. Given
 /// that the token account is always frozen, it includes a `state` that provides an abstraction
@@ -84,6 +88,10 @@ pub struct TokenRecord {
     /// Stores the destination pubkey when a transfer is lock to an allowed address. This
     /// pubkey gets set when a 'LockTransfer' delegate is approved.
     pub locked_transfer: Option<Pubkey>,
+    /// Expiry of the current token delegate, if any.
+    pub delegate_expiry: Option<DelegateExpiry>,
+    /// Unix timestamp after which the holder can unlock the token without the delegate.
+    pub unlock_after: Option<i64>,
 }
 
 impl Default for TokenRecord {
@@ -97,6 +105,8 @@ impl Default for TokenRecord {
             delegate: None,
             delegate_role: None,
             locked_transfer: None,
+            delegate_expiry: None,
+            unlock_after: None,
         }
     }
 }
@@ -133,18 +143,53 @@ impl TokenRecord {
         self.delegate = None;
         self.delegate_role = None;
         self.locked_transfer = None;
+        self.delegate_expiry = None;
+        self.unlock_after = None;
+    }
+
+    /// Clears the token delegate information, leaving the token state and the unlock
+    /// timestamp unchanged.
+    #[allow(deprecated)]
+    pub fn clear_delegate(&mut self) {
+        self.rule_set_revision = None;
+        self.delegate = None;
+        self.delegate_role = None;
+        self.locked_transfer = None;
+        self.delegate_expiry = None;
+    }
+
+    /// Indicates whether the current token delegate has expired.
+    pub fn is_delegate_expired(&self) -> Result<bool, ProgramError> {
+        has_expired(&self.delegate_expiry)
+    }
+
+    /// Indicates whether the current lock has reached its unlock timestamp. Locks
+    /// without an unlock timestamp never expire.
+    pub fn is_lock_expired(&self) -> Result<bool, ProgramError> {
+        has_expired(&self.unlock_after.map(DelegateExpiry::UnixTimestamp))
     }
 }
 
 impl Resizable for TokenRecord {
     fn from_bytes<'a>(account_data: &[u8]) -> Result<TokenRecord, ProgramError> {
         // we perform a manual deserialization since we are potentially dealing
-        // with accounts of different sizes
+        // with accounts of different sizes: records created before the unlock
+        // timestamp, the delegate expiry (and the locked transfer) were supported
+        // do not store them
         let length = TokenRecord::size() as i64 - account_data.len() as i64;
+        let without_unlock_after = UNLOCK_AFTER_SIZE as i64;
+        let without_delegate_expiry = without_unlock_after + DELEGATE_EXPIRY_SIZE as i64;
+        let without_locked_transfer = without_delegate_expiry + LOCKED_TRANSFER_SIZE as i64;
 
         // we use the account length in the 'is_correct_account_type' since we are
         // manually checking that the account length is valid
-        if !(length == 0 || length == LOCKED_TRANSFER_SIZE as i64)
+        if ![
+            0,
+            without_unlock_after,
+            without_delegate_expiry,
+            without_locked_transfer,
+        ]
+        .contains(&length)
             || !TokenRecord::is_correct_account_type(
                 account_data,
                 Key::TokenRecord,
@@ -163,7 +208,19 @@ impl Resizable for TokenRecord {
         let delegate: Option<Pubkey> = BorshDeserialize::deserialize(&mut data)?;
         let delegate_role: Option<TokenDelegateRole> = BorshDeserialize::deserialize(&mut data)?;
 
-        let locked_transfer: Option<Pubkey> = if length == 0 {
+        let locked_transfer: Option<Pubkey> = if length < without_locked_transfer {
+            BorshDeserialize::deserialize(&mut data)?
+        } else {
+            None
+        };
+
+        let delegate_expiry: Option<DelegateExpiry> = if length < without_delegate_expiry {
+            BorshDeserialize::deserialize(&mut data)?
+        } else {
+            None
+        };
+
+        let unlock_after: Option<i64> = if length == 0 {
             BorshDeserialize::deserialize(&mut data)?
         } else {
             None
@@ -178,6 +235,8 @@ impl Resizable for TokenRecord {
             delegate,
             delegate_role,
             locked_transfer,
+            delegate_expiry,
+            unlock_after,
         })
     }
 }
@@ -317,6 +376,8 @@ impl AuthorityType {
                                 && Some(*request.authority) == token_record.delegate
                                 && role_matches
                                 && (COption::from(token_record.delegate) == token_account.delegate)
+                                // expired delegates are treated as absent
+                                && !token_record.is_delegate_expired()?
                             {
                                 return Ok(AuthorityResponse {
                                     authority_type: AuthorityType::TokenDelegate,
@@ -350,7 +411,10 @@ impl AuthorityType {
                                     metadata_delegate_record_info,
                                 )?;
 
-                                if delegate_record.delegate == *request.authority {
+                                // expired delegates are treated as absent
+                                if delegate_record.delegate == *request.authority
+                                    && !delegate_record.is_expired()?
+                                {
                                     return Ok(AuthorityResponse {
                                         authority_type: AuthorityType::MetadataDelegate,
                                         metadata_delegate_role: Some(*role),
@@ -377,7 +441,10 @@ impl AuthorityType {
                                             metadata_delegate_record_info,
                                         )?;
 
-                                    if delegate_record.delegate == *request.authority {
+                                    // expired delegates are treated as absent
+                                    if delegate_record.delegate == *request.authority
+                                        && !delegate_record.is_expired()?
+                                    {
                                         return Ok(AuthorityResponse {
                                             authority_type: AuthorityType::MetadataDelegate,
                                             metadata_delegate_role: Some(*role),
@@ -420,11 +487,43 @@ impl AuthorityType {
     }
 }
 
+/// Operations validated against the rule set of a programmable asset.
+///
+/// `Burn`, `Lock`, `Unlock`, `Use` and `Print` are only enforced, with the
+/// `rule-set-operations` feature, when the rule set accounts are provided and the rule
+/// set defines them, so existing callers and rule sets keep working.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Operation {
-    Transfer { scenario: TransferScenario },
-    Update { scenario: UpdateScenario },
-    Delegate { scenario: DelegateScenario },
+    Transfer {
+        scenario: TransferScenario,
+    },
+    Update {
+        scenario: UpdateScenario,
+    },
+    Delegate {
+        scenario: DelegateScenario,
+    },
+    /// Payload: `Amount` and `Authority`.
+    Burn,
+    /// Payload: `Authority`.
+    Lock,
+    /// Payload: `Authority`.
+    Unlock,
+    /// Payload: `Amount` and `Authority`.
+    Use,
+    /// Payload: `Authority` and `Destination` (owner of the new edition).
+    Print,
+}
+
+impl Operation {
+    /// Indicates whether the operation is only enforced when the rule set defines it
+    /// (see `Operation`).
+    pub fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            Self::Burn | Self::Lock | Self::Unlock | Self::Use | Self::Print
+        )
+    }
 }
 
 impl ToString for Operation {
@@ -433,6 +532,11 @@ impl ToString for Operation {
             Self::Transfer { scenario } => format!("Transfer:{}", scenario),
             Self::Update { scenario } => format!("Update:{}", scenario),
             Self::Delegate { scenario } => format!("Delegate:{}", scenario),
+            Self::Burn => "Burn".to_string(),
+            Self::Lock => "Lock".to_string(),
+            Self::Unlock => "Unlock".to_string(),
+            Self::Use => "Use".to_string(),
+            Self::Print => "Print".to_string(),
         }
     }
 }
@@ -7,6 +7,15 @@ use crate::state::CollectionDetails;
 pub fn increment_collection_size(
     metadata: &mut Metadata,
     metadata_info: &AccountInfo,
+) -> ProgramResult {
+    increment_collection_size_by(metadata, metadata_info, 1)
+}
+
+/// Increments the size of a sized collection by `amount` items with a single write.
+pub fn increment_collection_size_by(
+    metadata: &mut Metadata,
+    metadata_info: &AccountInfo,
+    amount: u64,
 ) -> ProgramResult {
     if let Some(ref details) = metadata.collection_details {
         match details {
@@ -14,7 +23,7 @@ pub fn increment_collection_size(
             CollectionDetails::V1 { size } => {
                 metadata.collection_details = Some(CollectionDetails::V1 {
                     size: size
-                        .checked_add(1)
+                        .checked_add(amount)
                         .ok_or(MetadataError::NumericalOverflowError)?,
                 });
                 msg!("Clean write collection metadata");
@@ -32,6 +41,15 @@ pub fn increment_collection_size(
 pub fn decrement_collection_size(
     metadata: &mut Metadata,
     metadata_info: &AccountInfo,
+) -> ProgramResult {
+    decrement_collection_size_by(metadata, metadata_info, 1)
+}
+
+/// Decrements the size of a sized collection by `amount` items with a single write.
+pub fn decrement_collection_size_by(
+    metadata: &mut Metadata,
+    metadata_info: &AccountInfo,
+    amount: u64,
 ) -> ProgramResult {
     if let Some(ref details) = metadata.collection_details {
         match details {
@@ -39,7 +57,7 @@ pub fn decrement_collection_size(
             CollectionDetails::V1 { size } => {
                 metadata.collection_details = Some(CollectionDetails::V1 {
                     size: size
-                        .checked_sub(1)
+                        .checked_sub(amount)
                         .ok_or(MetadataError::NumericalOverflowError)?,
                 });
                 clean_write_metadata(metadata, metadata_info)?;
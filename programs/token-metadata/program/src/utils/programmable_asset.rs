@@ -1,3 +1,10 @@
+#[cfg(feature = "rule-set-operations")]
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    state::{RuleSetV1, RuleSetV2},
+    types::{LibVersion, RuleSet},
+    utils::get_existing_revision_map,
+};
 use mpl_token_auth_rules::{
     instruction::{builders::ValidateBuilder, InstructionBuilder, ValidateArgs},
     payload::PayloadType,
@@ -192,6 +199,87 @@ pub fn validate<'a>(
     invoke_signed(&validate_ix, account_infos.as_slice(), &[])
 }
 
+/// Returns whether the specified revision (or the latest one) of the rule set defines
+/// a rule for the operation.
+///
+/// This follows the same lookup used by the Token Auth Rules program when validating.
+#[cfg(feature = "rule-set-operations")]
+pub fn rule_set_has_operation(
+    rule_set_info: &AccountInfo,
+    operation: &Operation,
+    rule_set_revision: Option<usize>,
+) -> Result<bool, ProgramError> {
+    let (revision_map, rev_map_location) = get_existing_revision_map(rule_set_info)?;
+
+    let (start, end) = match rule_set_revision {
+        Some(revision) => {
+            let start = revision_map
+                .rule_set_revisions
+                .get(revision)
+                .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?;
+            let end = revision_map
+                .rule_set_revisions
+                .get(revision + 1)
+                .unwrap_or(&rev_map_location);
+            (*start, *end)
+        }
+        None => {
+            let start = revision_map
+                .rule_set_revisions
+                .last()
+                .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?;
+            (*start, rev_map_location)
+        }
+    };
+
+    let data = rule_set_info.try_borrow_data()?;
+
+    if start >= end || end > data.len() {
+        return Err(RuleSetError::DataTypeMismatch.into());
+    }
+
+    let rule_set: Box<dyn RuleSet> = match LibVersion::try_from(data[start])? {
+        LibVersion::V1 => Box::new(
+            rmp_serde::from_slice::<RuleSetV1>(&data[start + 1..end])
+                .map_err(|_| RuleSetError::MessagePackDeserializationError)?,
+        ),
+        LibVersion::V2 => Box::new(RuleSetV2::from_bytes(&data[start..end])?),
+    };
+
+    Ok(rule_set.get_rule(operation.to_string()).is_ok())
+}
+
+/// Returns whether an optional operation is enforced by the rule set of the asset.
+///
+/// Optional operations are only validated when the rule set accounts are provided and
+/// the rule set defines the operation. Reading the rule set requires the
+/// `rule-set-operations` feature; without it, optional operations are not validated.
+#[cfg(feature = "rule-set-operations")]
+fn is_operation_enforced(
+    auth_rules_info: Option<&AccountInfo>,
+    config: &ProgrammableConfig,
+    operation: &Operation,
+    rule_set_revision: Option<usize>,
+) -> Result<bool, ProgramError> {
+    match auth_rules_info {
+        Some(rule_set_info) => {
+            assert_valid_authorization(auth_rules_info, config)?;
+            rule_set_has_operation(rule_set_info, operation, rule_set_revision)
+        }
+        None => Ok(false),
+    }
+}
+
+#[cfg(not(feature = "rule-set-operations"))]
+fn is_operation_enforced(
+    _auth_rules_info: Option<&AccountInfo>,
+    _config: &ProgrammableConfig,
+    _operation: &Operation,
+    _rule_set_revision: Option<usize>,
+) -> Result<bool, ProgramError> {
+    Ok(false)
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthRulesValidateParams<'a> {
     pub mint_info: &'a AccountInfo<'a>,
@@ -238,6 +326,15 @@ pub fn auth_rules_validate(params: AuthRulesValidateParams) -> ProgramResult {
 
     if let Some(ref config) = programmable_config {
         if let ProgrammableConfig::V1 { rule_set: Some(_) } = config {
+            // Operations added after rule sets were introduced are only validated when
+            // the rule set defines them, and their rule set accounts are not required
+            // otherwise.
+            if operation.is_optional()
+                && !is_operation_enforced(auth_rules_info, config, &operation, rule_set_revision)?
+            {
+                return Ok(());
+            }
+
             assert_valid_authorization(auth_rules_info, config)?;
 
             // We can safely unwrap here because they were all checked for existence
@@ -312,6 +409,46 @@ pub fn auth_rules_validate(params: AuthRulesValidateParams) -> ProgramResult {
                         PayloadType::Pubkey(*destination_info.key),
                     );
                 }
+                Operation::Burn | Operation::Use => {
+                    let authority_info = authority_info.ok_or(MetadataError::InvalidOperation)?;
+
+                    // burn/use amount
+                    auth_data
+                        .payload
+                        .insert(PayloadKey::Amount.to_string(), PayloadType::Number(amount));
+
+                    // burn/use authority
+                    auth_data.payload.insert(
+                        PayloadKey::Authority.to_string(),
+                        PayloadType::Pubkey(*authority_info.key),
+                    );
+                }
+                Operation::Lock | Operation::Unlock => {
+                    let authority_info = authority_info.ok_or(MetadataError::InvalidOperation)?;
+
+                    // lock/unlock authority
+                    auth_data.payload.insert(
+                        PayloadKey::Authority.to_string(),
+                        PayloadType::Pubkey(*authority_info.key),
+                    );
+                }
+                Operation::Print => {
+                    let authority_info = authority_info.ok_or(MetadataError::InvalidOperation)?;
+                    let destination_info =
+                        destination_info.ok_or(MetadataError::InvalidOperation)?;
+
+                    // print authority
+                    auth_data.payload.insert(
+                        PayloadKey::Authority.to_string(),
+                        PayloadType::Pubkey(*authority_info.key),
+                    );
+
+                    // owner of the printed edition
+                    auth_data.payload.insert(
+                        PayloadKey::Destination.to_string(),
+                        PayloadType::Pubkey(*destination_info.key),
+                    );
+                }
                 _ => {
                     return Err(MetadataError::InvalidOperation.into());
                 }
@@ -209,11 +209,11 @@ pub(crate) fn validate_token(
     mint: &AccountInfo,
     token: &AccountInfo,
     token_owner: Option<&AccountInfo>,
-    spl_token_program: &AccountInfo,
+    spl_token_program: &Pubkey,
     token_standard: Option<TokenStandard>,
     required_amount: Option<u64>,
 ) -> Result<Account, ProgramError> {
-    if token.owner != spl_token_program.key {
+    if token.owner != spl_token_program {
         return Err(MetadataError::IncorrectOwner.into());
     }
 
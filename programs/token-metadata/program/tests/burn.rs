@@ -113,6 +113,84 @@ mod pnft {
         da.assert_burned(&mut context).await.unwrap();
     }
 
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn owner_burn_with_rule_set_without_burn_operation(spl_token_program: Pubkey) {
+        // Rule sets that do not define a burn operation do not restrict burning.
+        let mut program_test = program_test();
+        program_test.add_program("mpl_token_auth_rules", mpl_token_auth_rules::ID, None);
+        let mut context = program_test.start_with_context().await;
+
+        let owner = context.payer.dirty_clone();
+        let creator = context.payer.dirty_clone();
+        let (rule_set, auth_data) =
+            create_default_metaplex_rule_set(&mut context, creator, false).await;
+
+        let mut da = DigitalAsset::new();
+        da.create_and_mint(
+            &mut context,
+            TokenStandard::ProgrammableNonFungible,
+            Some(rule_set),
+            Some(auth_data),
+            1,
+            spl_token_program,
+        )
+        .await
+        .unwrap();
+
+        let args = BurnArgs::V1 { amount: 1 };
+
+        da.burn(&mut context, owner, args, None, None, spl_token_program)
+            .await
+            .unwrap();
+
+        // Assert that metadata, edition, token and token record accounts are closed.
+        da.assert_burned(&mut context).await.unwrap();
+    }
+
+    // rule sets are only read on-chain with the `rule-set-operations` feature
+    #[cfg(feature = "rule-set-operations")]
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn rule_set_restricts_burn(spl_token_program: Pubkey) {
+        // The rule set only allows a different authority to burn the asset.
+        let mut program_test = program_test();
+        program_test.add_program("mpl_token_auth_rules", mpl_token_auth_rules::ID, None);
+        let mut context = program_test.start_with_context().await;
+
+        let owner = context.payer.dirty_clone();
+        let creator = context.payer.dirty_clone();
+        let (rule_set, auth_data) =
+            create_lifecycle_rule_set(&mut context, creator, Pubkey::new_unique()).await;
+
+        let mut da = DigitalAsset::new();
+        da.create_and_mint(
+            &mut context,
+            TokenStandard::ProgrammableNonFungible,
+            Some(rule_set),
+            Some(auth_data),
+            1,
+            spl_token_program,
+        )
+        .await
+        .unwrap();
+
+        let args = BurnArgs::V1 { amount: 1 };
+
+        let err = da
+            .burn(&mut context, owner, args, None, None, spl_token_program)
+            .await
+            .unwrap_err();
+
+        assert_custom_error_ix!(
+            0,
+            err,
+            mpl_token_auth_rules::error::RuleSetError::PubkeyMatchCheckFailed
+        );
+    }
+
     #[test_case::test_case(spl_token::id() ; "Token Program")]
     #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
     #[tokio::test]
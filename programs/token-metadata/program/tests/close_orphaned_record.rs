@@ -0,0 +1,210 @@
+#![cfg(feature = "test-bpf")]
+pub mod utils;
+
+use num_traits::FromPrimitive;
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_program_test::*;
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use token_metadata::{
+    error::MetadataError,
+    instruction::{
+        builders::CloseOrphanedRecordBuilder, CloseOrphanedRecordArgs, DelegateArgs,
+        InstructionBuilder, MetadataDelegateRole,
+    },
+    pda::{find_metadata_delegate_record_account, find_token_record_account},
+    state::TokenStandard,
+};
+use utils::*;
+
+mod close_orphaned_record {
+
+    use super::*;
+
+    async fn close_orphaned_record(
+        context: &mut ProgramTestContext,
+        asset: &DigitalAsset,
+        record: Pubkey,
+        destination: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = context.payer.dirty_clone();
+
+        let mut builder = CloseOrphanedRecordBuilder::new();
+        builder
+            .record(record)
+            .mint(asset.mint.pubkey())
+            .token(asset.token.unwrap_or(token_metadata::ID))
+            .metadata(asset.metadata)
+            .destination(destination);
+
+        let ix = builder
+            .build(CloseOrphanedRecordArgs::V1)
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn close_account(context: &mut ProgramTestContext, address: &Pubkey) {
+        // keeps the lamports so the capitalization of the bank does not change
+        let account = get_account(context, address).await;
+        context.set_account(
+            address,
+            &AccountSharedData::new(account.lamports, 0, &system_program::ID),
+        );
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn close_orphaned_metadata_delegate_record(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let update_authority = context.payer.dirty_clone();
+        let delegate = Keypair::new().pubkey();
+
+        asset
+            .delegate(
+                &mut context,
+                update_authority.dirty_clone(),
+                delegate,
+                DelegateArgs::DataV1 {
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (delegate_record, _) = find_metadata_delegate_record_account(
+            &asset.mint.pubkey(),
+            MetadataDelegateRole::Data,
+            &update_authority.pubkey(),
+            &delegate,
+        );
+
+        // the metadata account still exists
+
+        let error = close_orphaned_record(
+            &mut context,
+            &asset,
+            delegate_record,
+            update_authority.pubkey(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::RecordNotOrphaned);
+
+        // simulates a closed metadata account
+        close_account(&mut context, &asset.metadata).await;
+        context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+        // the rent can only go to the update authority that approved the delegate
+
+        let error = close_orphaned_record(
+            &mut context,
+            &asset,
+            delegate_record,
+            Keypair::new().pubkey(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidRentDestination);
+
+        close_orphaned_record(
+            &mut context,
+            &asset,
+            delegate_record,
+            update_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+        assert!(context
+            .banks_client
+            .get_account(delegate_record)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn close_orphaned_token_record(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let token_owner = context.payer.pubkey();
+        let (token_record, _) =
+            find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+
+        // the token account still exists
+
+        let error = close_orphaned_record(&mut context, &asset, token_record, token_owner)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::RecordNotOrphaned);
+
+        // simulates a closed token account
+        close_account(&mut context, &asset.token.unwrap()).await;
+        context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+        // the rent can only go to the owner of the token account
+
+        let error =
+            close_orphaned_record(&mut context, &asset, token_record, Keypair::new().pubkey())
+                .await
+                .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidRentDestination);
+
+        close_orphaned_record(&mut context, &asset, token_record, token_owner)
+            .await
+            .unwrap();
+
+        assert!(context
+            .banks_client
+            .get_account(token_record)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
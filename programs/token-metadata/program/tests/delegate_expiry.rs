@@ -0,0 +1,416 @@
+#![cfg(feature = "test-bpf")]
+pub mod utils;
+
+use num_traits::FromPrimitive;
+use solana_program::{clock::Clock, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use token_metadata::{
+    error::MetadataError,
+    instruction::{
+        builders::RevokeExpiredDelegateBuilder, DelegateArgs, InstructionBuilder,
+        MetadataDelegateRole, RevokeExpiredDelegateArgs,
+    },
+    pda::{find_metadata_delegate_record_account, find_token_record_account},
+    state::{
+        DelegateExpiry, MetadataDelegateRecord, TokenDelegateRole, TokenMetadataAccount,
+        TokenRecord, TokenStandard, TokenState,
+    },
+};
+use utils::*;
+
+mod delegate_expiry {
+
+    use super::*;
+
+    async fn current_slot(context: &mut ProgramTestContext) -> u64 {
+        context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .slot
+    }
+
+    async fn revoke_expired(
+        context: &mut ProgramTestContext,
+        asset: &DigitalAsset,
+        delegate_record: Option<Pubkey>,
+        token_record: Option<Pubkey>,
+        destination: Pubkey,
+        payer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let mut builder = RevokeExpiredDelegateBuilder::new();
+        builder
+            .mint(asset.mint.pubkey())
+            .destination(destination)
+            .payer(payer.pubkey());
+
+        if let Some(delegate_record) = delegate_record {
+            builder.delegate_record(delegate_record);
+        }
+
+        if let Some(token_record) = token_record {
+            builder
+                .token(asset.token.unwrap())
+                .token_record(token_record);
+        }
+
+        let ix = builder
+            .build(RevokeExpiredDelegateArgs::V1)
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn revoke_expired_metadata_delegate(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // creates a data delegate that expires in a few slots
+
+        let expiry = DelegateExpiry::Slot(current_slot(&mut context).await + 10);
+        let update_authority = context.payer.dirty_clone();
+        let delegate = Keypair::new().pubkey();
+
+        asset
+            .delegate(
+                &mut context,
+                update_authority.dirty_clone(),
+                delegate,
+                DelegateArgs::DataV2 {
+                    expiry: Some(expiry),
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (delegate_record, _) = find_metadata_delegate_record_account(
+            &asset.mint.pubkey(),
+            MetadataDelegateRole::Data,
+            &update_authority.pubkey(),
+            &delegate,
+        );
+
+        let account = get_account(&mut context, &delegate_record).await;
+        assert_eq!(account.data.len(), MetadataDelegateRecord::size());
+        let record = MetadataDelegateRecord::from_bytes(&account.data).unwrap();
+        assert_eq!(record.delegate, delegate);
+        assert_eq!(record.expiry, Some(expiry));
+
+        // the delegate has not expired yet
+
+        let cranker = Keypair::new();
+        airdrop(&mut context, &cranker.pubkey(), LAMPORTS_PER_SOL)
+            .await
+            .unwrap();
+
+        let error = revoke_expired(
+            &mut context,
+            &asset,
+            Some(delegate_record),
+            None,
+            update_authority.pubkey(),
+            &cranker,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::DelegateNotExpired);
+
+        // once it expires, anyone can close the record, but the rent can only be
+        // returned to the update authority that approved the delegate
+
+        context.warp_to_slot(100).unwrap();
+
+        let error = revoke_expired(
+            &mut context,
+            &asset,
+            Some(delegate_record),
+            None,
+            cranker.pubkey(),
+            &cranker,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidRentDestination);
+
+        let update_authority_lamports = get_account(&mut context, &update_authority.pubkey())
+            .await
+            .lamports;
+
+        revoke_expired(
+            &mut context,
+            &asset,
+            Some(delegate_record),
+            None,
+            update_authority.pubkey(),
+            &cranker,
+        )
+        .await
+        .unwrap();
+
+        assert!(context
+            .banks_client
+            .get_account(delegate_record)
+            .await
+            .unwrap()
+            .is_none());
+
+        let destination_account = get_account(&mut context, &update_authority.pubkey()).await;
+        assert_eq!(
+            destination_account.lamports,
+            update_authority_lamports + account.lamports
+        );
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn revoke_expired_token_delegate(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // creates a transfer delegate that expires in a few slots
+
+        let expiry = DelegateExpiry::Slot(current_slot(&mut context).await + 10);
+        let delegate = Keypair::new();
+        let payer = context.payer.dirty_clone();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::TransferV2 {
+                    amount: 1,
+                    expiry: Some(expiry),
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (token_record, _) =
+            find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+
+        let destination = Keypair::new().pubkey();
+        let payer = context.payer.dirty_clone();
+        let error = revoke_expired(
+            &mut context,
+            &asset,
+            None,
+            Some(token_record),
+            destination,
+            &payer,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::DelegateNotExpired);
+
+        context.warp_to_slot(100).unwrap();
+
+        // anyone can clean up the expired delegate
+
+        let payer = context.payer.dirty_clone();
+        revoke_expired(
+            &mut context,
+            &asset,
+            None,
+            Some(token_record),
+            destination,
+            &payer,
+        )
+        .await
+        .unwrap();
+
+        let pda = get_account(&mut context, &token_record).await;
+        let record = TokenRecord::safe_deserialize(&pda.data).unwrap();
+        assert_eq!(record.state, TokenState::Unlocked);
+        assert_eq!(record.delegate, None);
+        assert_eq!(record.delegate_role, None);
+        assert_eq!(record.delegate_expiry, None);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_revoke_expired_locked_token_delegate(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // creates a utility delegate that expires in a few slots
+
+        let expiry = DelegateExpiry::Slot(current_slot(&mut context).await + 10);
+        let delegate = Keypair::new();
+        let payer = context.payer.dirty_clone();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::UtilityV2 {
+                    amount: 1,
+                    expiry: Some(expiry),
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (token_record, _) =
+            find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+
+        // the delegate locks the asset while it is valid
+
+        let payer = context.payer.dirty_clone();
+        asset
+            .lock(
+                &mut context,
+                delegate.dirty_clone(),
+                Some(token_record),
+                payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        context.warp_to_slot(100).unwrap();
+
+        // an expired delegate is no longer an authority of the asset
+
+        let payer = context.payer.dirty_clone();
+        let error = asset
+            .unlock(
+                &mut context,
+                delegate.dirty_clone(),
+                Some(token_record),
+                payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidAuthorityType);
+
+        // cleaning up the expired delegate cannot unlock the asset
+
+        let destination = Keypair::new().pubkey();
+        let payer = context.payer.dirty_clone();
+        let error = revoke_expired(
+            &mut context,
+            &asset,
+            None,
+            Some(token_record),
+            destination,
+            &payer,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::LockedToken);
+
+        let pda = get_account(&mut context, &token_record).await;
+        let record = TokenRecord::safe_deserialize(&pda.data).unwrap();
+        assert_eq!(record.state, TokenState::Locked);
+        assert_eq!(record.delegate, Some(delegate.pubkey()));
+        assert_eq!(record.delegate_role, Some(TokenDelegateRole::Utility));
+        assert_eq!(record.delegate_expiry, Some(expiry));
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_delegate_with_past_expiry(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        context.warp_to_slot(100).unwrap();
+
+        let payer = context.payer.dirty_clone();
+        let error = asset
+            .delegate(
+                &mut context,
+                payer,
+                Keypair::new().pubkey(),
+                DelegateArgs::StakingV2 {
+                    amount: 1,
+                    expiry: Some(DelegateExpiry::Slot(50)),
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidDelegateExpiry);
+    }
+}
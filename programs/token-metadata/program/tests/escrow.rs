@@ -1,10 +1,33 @@
 #![cfg(feature = "test-bpf")]
 pub mod utils;
 
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
 use solana_program_test::*;
-use solana_sdk::{signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    instruction::InstructionError,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+use token_metadata::{
+    error::MetadataError,
+    instruction::TransferArgs,
+    pda::find_token_record_account,
+    state::{Key, PrintSupply, TokenRecord, TokenStandard},
+};
 use utils::*;
 
+async fn get_token_account(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+) -> spl_token_2022::state::Account {
+    let account = get_account(context, address).await;
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+}
+
 mod escrow {
     use borsh::BorshDeserialize;
     use solana_program::program_pack::Pack;
@@ -176,6 +199,7 @@ mod escrow {
             payer_attribute_token_account,
             parent_test_metadata.mint.pubkey(),
             parent_test_metadata.token.pubkey(),
+            spl_token::ID,
             None,
             1,
         );
@@ -371,6 +395,7 @@ mod escrow {
             payer_attribute_token_account,
             parent_test_metadata.mint.pubkey(),
             parent_test_metadata.token.pubkey(),
+            spl_token::ID,
             None,
             1,
         );
@@ -385,6 +410,7 @@ mod escrow {
             payer_attribute_token_account,
             parent_test_metadata.mint.pubkey(),
             parent_test_metadata.token.pubkey(),
+            spl_token::ID,
             None,
             1,
         );
@@ -414,4 +440,554 @@ mod escrow {
         assert!(attribute_dst.owner == context.payer.pubkey());
         println!("Payer Post-Transfer: {:#?}", attribute_dst);
     }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn escrow_lifecycle(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        // Create Escrow
+        let mut parent = DigitalAsset::new();
+        parent
+            .create_and_mint_nonfungible(&mut context, PrintSupply::Zero, spl_token_program)
+            .await
+            .unwrap();
+
+        let escrow_address =
+            find_escrow_account(&parent.mint.pubkey(), &EscrowAuthority::TokenOwner).0;
+
+        let ix = token_metadata::escrow::create_escrow_account(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            parent.mint.pubkey(),
+            parent.token.unwrap(),
+            parent.edition.unwrap(),
+            context.payer.pubkey(),
+            None,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let escrow_account = get_account(&mut context, &escrow_address).await;
+        let escrow: token_metadata::state::TokenOwnedEscrow =
+            BorshDeserialize::deserialize(&mut &escrow_account.data[..]).unwrap();
+        assert_eq!(escrow.base_token, parent.mint.pubkey());
+
+        // Transfer In
+        let mut attribute = DigitalAsset::new();
+        attribute
+            .create_and_mint(
+                &mut context,
+                TokenStandard::Fungible,
+                None,
+                None,
+                10,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let mint_account = get_account(&mut context, &attribute.mint.pubkey()).await;
+        let decimals = StateWithExtensions::<Mint>::unpack(&mint_account.data)
+            .unwrap()
+            .base
+            .decimals;
+
+        let escrow_attribute_token_account =
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &escrow_address,
+                &attribute.mint.pubkey(),
+                &spl_token_program,
+            );
+
+        let ix0 = spl_associated_token_account::instruction::create_associated_token_account(
+            &context.payer.pubkey(),
+            &escrow_address,
+            &attribute.mint.pubkey(),
+            &spl_token_program,
+        );
+        let ix1 = spl_token_2022::instruction::transfer_checked(
+            &spl_token_program,
+            &attribute.token.unwrap(),
+            &attribute.mint.pubkey(),
+            &escrow_attribute_token_account,
+            &context.payer.pubkey(),
+            &[],
+            2,
+            decimals,
+        )
+        .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix0, ix1],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let attribute_dst = get_token_account(&mut context, &escrow_attribute_token_account).await;
+        assert_eq!(attribute_dst.amount, 2);
+        assert_eq!(attribute_dst.owner, escrow_address);
+
+        // Transfer Out
+        let payer_attribute_token_account =
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &context.payer.pubkey(),
+                &attribute.mint.pubkey(),
+                &spl_token_program,
+            );
+
+        let ix = token_metadata::escrow::transfer_out_of_escrow(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            context.payer.pubkey(),
+            attribute.mint.pubkey(),
+            escrow_attribute_token_account,
+            payer_attribute_token_account,
+            parent.mint.pubkey(),
+            parent.token.unwrap(),
+            spl_token_program,
+            None,
+            2,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let attribute_dst = get_token_account(&mut context, &payer_attribute_token_account).await;
+        assert_eq!(attribute_dst.amount, 10);
+        assert_eq!(attribute_dst.owner, context.payer.pubkey());
+
+        // the empty escrow token account is closed
+        let escrow_attribute = context
+            .banks_client
+            .get_account(escrow_attribute_token_account)
+            .await
+            .unwrap();
+        assert!(escrow_attribute.is_none());
+
+        // Close Escrow
+        let ix = token_metadata::escrow::close_escrow_account(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            parent.mint.pubkey(),
+            parent.edition.unwrap(),
+            context.payer.pubkey(),
+            parent.token.unwrap(),
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let escrow_account = context
+            .banks_client
+            .get_account(escrow_address)
+            .await
+            .unwrap();
+        assert!(escrow_account.is_none());
+    }
+
+    #[tokio::test]
+    async fn fail_transfer_out_with_wrong_token_program() {
+        let mut context = program_test().start_with_context().await;
+
+        let mut parent = DigitalAsset::new();
+        parent
+            .create_and_mint_nonfungible(&mut context, PrintSupply::Zero, spl_token_2022::ID)
+            .await
+            .unwrap();
+
+        let escrow_address =
+            find_escrow_account(&parent.mint.pubkey(), &EscrowAuthority::TokenOwner).0;
+
+        let ix = token_metadata::escrow::create_escrow_account(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            parent.mint.pubkey(),
+            parent.token.unwrap(),
+            parent.edition.unwrap(),
+            context.payer.pubkey(),
+            None,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let mut attribute = DigitalAsset::new();
+        attribute
+            .create_and_mint(
+                &mut context,
+                TokenStandard::Fungible,
+                None,
+                None,
+                10,
+                spl_token_2022::ID,
+            )
+            .await
+            .unwrap();
+
+        // the token program does not own the attribute mint
+        let ix = token_metadata::escrow::transfer_out_of_escrow(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            context.payer.pubkey(),
+            attribute.mint.pubkey(),
+            attribute.token.unwrap(),
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &context.payer.pubkey(),
+                &attribute.mint.pubkey(),
+                &spl_token_2022::ID,
+            ),
+            parent.mint.pubkey(),
+            parent.token.unwrap(),
+            spl_token::ID,
+            None,
+            1,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        let error = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidTokenProgram);
+    }
+
+    #[tokio::test]
+    async fn transfer_non_programmable_with_attribute_metadata() {
+        let mut context = program_test().start_with_context().await;
+
+        // Create Escrow
+        let parent_test_metadata = Metadata::new();
+        let parent_test_master_edition = MasterEditionV2::new(&parent_test_metadata);
+        parent_test_metadata
+            .create_v3(
+                &mut context,
+                "Test".to_string(),
+                "TST".to_string(),
+                "uri".to_string(),
+                None,
+                10,
+                true,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        parent_test_master_edition
+            .create_v3(&mut context, Some(0))
+            .await
+            .unwrap();
+
+        let escrow_address = find_escrow_account(
+            &parent_test_metadata.mint.pubkey(),
+            &EscrowAuthority::TokenOwner,
+        )
+        .0;
+
+        let ix = token_metadata::escrow::create_escrow_account(
+            token_metadata::ID,
+            escrow_address,
+            parent_test_metadata.pubkey,
+            parent_test_metadata.mint.pubkey(),
+            parent_test_metadata.token.pubkey(),
+            parent_test_master_edition.pubkey,
+            context.payer.pubkey(),
+            None,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Transfer In
+        let attribute_test_metadata = Metadata::new();
+        let attribute_test_master_edition = MasterEditionV2::new(&attribute_test_metadata);
+        attribute_test_metadata
+            .create_v3(
+                &mut context,
+                "Test".to_string(),
+                "TST".to_string(),
+                "uri".to_string(),
+                None,
+                10,
+                true,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        attribute_test_master_edition
+            .create_v3(&mut context, Some(0))
+            .await
+            .unwrap();
+
+        let escrow_attribute_token_account =
+            spl_associated_token_account::get_associated_token_address(
+                &escrow_address,
+                &attribute_test_metadata.mint.pubkey(),
+            );
+
+        let ix0 = spl_associated_token_account::instruction::create_associated_token_account(
+            &context.payer.pubkey(),
+            &escrow_address,
+            &attribute_test_metadata.mint.pubkey(),
+            &spl_token::ID,
+        );
+        let ix1 = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &attribute_test_metadata.token.pubkey(),
+            &escrow_attribute_token_account,
+            &context.payer.pubkey(),
+            &[&context.payer.pubkey()],
+            1,
+        )
+        .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix0, ix1],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Transfer Out
+        let payer_attribute_token_account =
+            spl_associated_token_account::get_associated_token_address(
+                &context.payer.pubkey(),
+                &attribute_test_metadata.mint.pubkey(),
+            );
+
+        // the attribute is not programmable, so it is transferred without the programmable accounts
+        let ix = token_metadata::escrow::transfer_programmable_out_of_escrow(
+            token_metadata::ID,
+            escrow_address,
+            parent_test_metadata.pubkey,
+            context.payer.pubkey(),
+            attribute_test_metadata.mint.pubkey(),
+            escrow_attribute_token_account,
+            payer_attribute_token_account,
+            parent_test_metadata.mint.pubkey(),
+            parent_test_metadata.token.pubkey(),
+            spl_token::ID,
+            None,
+            None,
+            None,
+            1,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let attribute_dst = get_token_account(&mut context, &payer_attribute_token_account).await;
+        assert_eq!(attribute_dst.amount, 1);
+        assert!(!attribute_dst.is_frozen());
+    }
+
+    #[tokio::test]
+    async fn transfer_programmable_out_of_escrow() {
+        let mut context = program_test().start_with_context().await;
+
+        // Create Escrow
+        let mut parent = DigitalAsset::new();
+        parent
+            .create_and_mint_nonfungible(&mut context, PrintSupply::Zero, spl_token::ID)
+            .await
+            .unwrap();
+
+        let escrow_address =
+            find_escrow_account(&parent.mint.pubkey(), &EscrowAuthority::TokenOwner).0;
+
+        let ix = token_metadata::escrow::create_escrow_account(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            parent.mint.pubkey(),
+            parent.token.unwrap(),
+            parent.edition.unwrap(),
+            context.payer.pubkey(),
+            None,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Transfer In
+        let mut attribute = DigitalAsset::new();
+        attribute
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token::ID,
+            )
+            .await
+            .unwrap();
+
+        let payer = context.payer.dirty_clone();
+        let payer_pubkey = payer.pubkey();
+
+        attribute
+            .transfer(
+                TransferParams {
+                    context: &mut context,
+                    authority: &payer,
+                    source_owner: &payer_pubkey,
+                    destination_owner: escrow_address,
+                    destination_token: None,
+                    payer: &payer,
+                    authorization_rules: None,
+                    args: TransferArgs::V1 {
+                        authorization_data: None,
+                        amount: 1,
+                    },
+                },
+                spl_token::ID,
+            )
+            .await
+            .unwrap();
+
+        let escrow_attribute_token_account =
+            spl_associated_token_account::get_associated_token_address(
+                &escrow_address,
+                &attribute.mint.pubkey(),
+            );
+
+        // Transfer Out
+        let payer_attribute_token_account =
+            spl_associated_token_account::get_associated_token_address(
+                &payer_pubkey,
+                &attribute.mint.pubkey(),
+            );
+
+        // the programmable accounts are required since the escrow token account is frozen
+        let ix = token_metadata::escrow::transfer_out_of_escrow(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            payer_pubkey,
+            attribute.mint.pubkey(),
+            escrow_attribute_token_account,
+            payer_attribute_token_account,
+            parent.mint.pubkey(),
+            parent.token.unwrap(),
+            spl_token::ID,
+            None,
+            1,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer_pubkey),
+            &[&payer],
+            context.last_blockhash,
+        );
+        let error = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::MissingTokenRecord);
+
+        let ix = token_metadata::escrow::transfer_programmable_out_of_escrow(
+            token_metadata::ID,
+            escrow_address,
+            parent.metadata,
+            payer_pubkey,
+            attribute.mint.pubkey(),
+            escrow_attribute_token_account,
+            payer_attribute_token_account,
+            parent.mint.pubkey(),
+            parent.token.unwrap(),
+            spl_token::ID,
+            None,
+            None,
+            None,
+            1,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer_pubkey),
+            &[&payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let attribute_dst = get_token_account(&mut context, &payer_attribute_token_account).await;
+        assert_eq!(attribute_dst.amount, 1);
+        assert!(attribute_dst.is_frozen());
+
+        // the destination token record is created and the source one is closed
+        let (destination_token_record, _) =
+            find_token_record_account(&attribute.mint.pubkey(), &payer_attribute_token_account);
+        let token_record_account = get_account(&mut context, &destination_token_record).await;
+        let token_record: TokenRecord =
+            TokenRecord::deserialize(&mut &token_record_account.data[..]).unwrap();
+        assert_eq!(token_record.key, Key::TokenRecord);
+
+        let (source_token_record, _) =
+            find_token_record_account(&attribute.mint.pubkey(), &escrow_attribute_token_account);
+        let source_token_record = context
+            .banks_client
+            .get_account(source_token_record)
+            .await
+            .unwrap();
+        assert!(source_token_record.is_none());
+    }
 }
@@ -106,11 +106,18 @@ mod lock {
 
         // lock the token
 
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
         let approver = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
         let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
 
         let error = asset
-            .lock(&mut context, approver, None, payer, spl_token_program)
+            .lock(
+                &mut context,
+                approver,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
             .await
             .unwrap_err();
 
@@ -237,10 +244,17 @@ mod lock {
 
         // lock the token
 
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
         let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
 
         asset
-            .lock(&mut context, delegate, None, payer, spl_token_program)
+            .lock(
+                &mut context,
+                delegate,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
             .await
             .unwrap();
 
@@ -428,4 +442,113 @@ mod lock {
         assert_eq!(token_record.state, TokenState::Locked);
         assert_eq!(locked_transfer, Some(Pubkey::default()));
     }
+
+    // rule sets are only read on-chain with the `rule-set-operations` feature
+    #[cfg(feature = "rule-set-operations")]
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn rule_set_restricts_lock_programmable_nonfungible(spl_token_program: Pubkey) {
+        let mut program_test = program_test();
+        program_test.add_program("mpl_token_auth_rules", mpl_token_auth_rules::ID, None);
+        let mut context = program_test.start_with_context().await;
+
+        // rule set only allowing the first delegate to lock/unlock
+
+        let allowed = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let (rule_set, auth_data) =
+            create_lifecycle_rule_set(&mut context, payer, allowed.pubkey()).await;
+
+        // asset
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                Some(rule_set),
+                Some(auth_data),
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+
+        // a utility delegate not allowed by the rule set cannot lock
+
+        let delegate = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::UtilityV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        let error = asset
+            .lock(
+                &mut context,
+                delegate,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap_err();
+
+        assert_custom_error_ix!(
+            0,
+            error,
+            mpl_token_auth_rules::error::RuleSetError::PubkeyMatchCheckFailed
+        );
+
+        // the allowed delegate can lock
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                allowed.pubkey(),
+                DelegateArgs::UtilityV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .lock(
+                &mut context,
+                allowed,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let pda = get_account(&mut context, &pda_key).await;
+        let token_record: TokenRecord = BorshDeserialize::deserialize(&mut &pda.data[..]).unwrap();
+
+        assert_eq!(token_record.state, TokenState::Locked);
+    }
 }
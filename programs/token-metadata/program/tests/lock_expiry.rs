@@ -0,0 +1,534 @@
+#![cfg(feature = "test-bpf")]
+pub mod utils;
+
+use num_traits::FromPrimitive;
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use spl_token_2022::state::Account;
+use token_metadata::{
+    error::MetadataError,
+    instruction::{DelegateArgs, LockArgs},
+    pda::find_token_record_account,
+    state::{TokenMetadataAccount, TokenRecord, TokenStandard, TokenState},
+    utils::unpack,
+};
+use utils::*;
+
+mod lock_expiry {
+
+    use super::*;
+
+    async fn current_timestamp(context: &mut ProgramTestContext) -> i64 {
+        context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+    }
+
+    async fn set_timestamp(context: &mut ProgramTestContext, unix_timestamp: i64) {
+        // avoids processing a transaction identical to a previous one
+        context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+        let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        context.set_sysvar(&Clock {
+            unix_timestamp,
+            ..clock
+        });
+    }
+
+    async fn lock_with_unlock_after(
+        context: &mut ProgramTestContext,
+        asset: &mut DigitalAsset,
+        delegate: &Keypair,
+        token_record: Option<Pubkey>,
+        unlock_after: i64,
+        spl_token_program: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let delegate = Keypair::from_bytes(&delegate.to_bytes()).unwrap();
+
+        asset
+            .lock_with_args(
+                context,
+                delegate,
+                token_record,
+                payer,
+                spl_token_program,
+                LockArgs::V2 {
+                    unlock_after: Some(unlock_after),
+                    authorization_data: None,
+                },
+            )
+            .await
+    }
+
+    async fn holder_unlock(
+        context: &mut ProgramTestContext,
+        asset: &mut DigitalAsset,
+        token_record: Option<Pubkey>,
+        spl_token_program: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        // the payer is the holder of the asset
+        let holder = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .unlock(context, holder, token_record, payer, spl_token_program)
+            .await
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn holder_unlock_expired_lock_programmable_nonfungible(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // set a staking delegate
+
+        let delegate = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::StakingV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // locks with an unlock timestamp
+
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+        let unlock_after = current_timestamp(&mut context).await + 3600;
+
+        lock_with_unlock_after(
+            &mut context,
+            &mut asset,
+            &delegate,
+            Some(pda_key),
+            unlock_after,
+            spl_token_program,
+        )
+        .await
+        .unwrap();
+
+        let pda = get_account(&mut context, &pda_key).await;
+        let token_record = TokenRecord::safe_deserialize(&pda.data).unwrap();
+        assert_eq!(token_record.state, TokenState::Locked);
+        assert_eq!(token_record.unlock_after, Some(unlock_after));
+
+        // the holder cannot unlock before the unlock timestamp
+
+        let error = holder_unlock(&mut context, &mut asset, Some(pda_key), spl_token_program)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::LockNotExpired);
+
+        // the holder can unlock once the unlock timestamp passes
+
+        set_timestamp(&mut context, unlock_after).await;
+
+        holder_unlock(&mut context, &mut asset, Some(pda_key), spl_token_program)
+            .await
+            .unwrap();
+
+        let pda = get_account(&mut context, &pda_key).await;
+        let token_record = TokenRecord::safe_deserialize(&pda.data).unwrap();
+        assert_eq!(token_record.state, TokenState::Unlocked);
+        assert_eq!(token_record.unlock_after, None);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn holder_unlock_expired_lock_nonfungible(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // set a standard delegate
+
+        let delegate = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::StandardV1 { amount: 1 },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // locks with an unlock timestamp, which creates the token record
+
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+        let unlock_after = current_timestamp(&mut context).await + 3600;
+
+        lock_with_unlock_after(
+            &mut context,
+            &mut asset,
+            &delegate,
+            Some(pda_key),
+            unlock_after,
+            spl_token_program,
+        )
+        .await
+        .unwrap();
+
+        let token_account = get_account(&mut context, &asset.token.unwrap()).await;
+        let token = unpack::<Account>(&token_account.data).unwrap();
+        assert!(token.is_frozen());
+
+        let pda = get_account(&mut context, &pda_key).await;
+        let token_record = TokenRecord::safe_deserialize(&pda.data).unwrap();
+        assert_eq!(token_record.delegate, Some(delegate.pubkey()));
+        assert_eq!(token_record.unlock_after, Some(unlock_after));
+
+        // the holder cannot unlock before the unlock timestamp
+
+        let error = holder_unlock(&mut context, &mut asset, Some(pda_key), spl_token_program)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::LockNotExpired);
+
+        // the holder can unlock once the unlock timestamp passes
+
+        set_timestamp(&mut context, unlock_after).await;
+
+        holder_unlock(&mut context, &mut asset, Some(pda_key), spl_token_program)
+            .await
+            .unwrap();
+
+        let token_account = get_account(&mut context, &asset.token.unwrap()).await;
+        let token = unpack::<Account>(&token_account.data).unwrap();
+        assert!(!token.is_frozen());
+
+        let pda = get_account(&mut context, &pda_key).await;
+        let token_record = TokenRecord::safe_deserialize(&pda.data).unwrap();
+        assert_eq!(token_record.unlock_after, None);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_holder_unlock_without_unlock_timestamp(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let delegate = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::StakingV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // locks without an unlock timestamp
+
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .lock(
+                &mut context,
+                delegate,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // only the delegate can unlock
+
+        let error = holder_unlock(&mut context, &mut asset, Some(pda_key), spl_token_program)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidAuthorityType);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_lock_with_past_unlock_timestamp(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let delegate = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::StakingV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+        let unlock_after = current_timestamp(&mut context).await;
+
+        let error = lock_with_unlock_after(
+            &mut context,
+            &mut asset,
+            &delegate,
+            Some(pda_key),
+            unlock_after,
+            spl_token_program,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidUnlockTimestamp);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_lock_nonfungible_with_unlock_timestamp_without_token_record(
+        spl_token_program: Pubkey,
+    ) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let delegate = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::StandardV1 { amount: 1 },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let unlock_after = current_timestamp(&mut context).await + 3600;
+
+        let error = lock_with_unlock_after(
+            &mut context,
+            &mut asset,
+            &delegate,
+            None,
+            unlock_after,
+            spl_token_program,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::MissingTokenRecord);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_holder_unlock_with_stale_lock_record_nonfungible(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let delegate = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        asset
+            .delegate(
+                &mut context,
+                payer,
+                delegate.pubkey(),
+                DelegateArgs::StandardV1 { amount: 1 },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // locks with an unlock timestamp, which is stored on the token record
+
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
+        let unlock_after = current_timestamp(&mut context).await + 3600;
+
+        lock_with_unlock_after(
+            &mut context,
+            &mut asset,
+            &delegate,
+            Some(pda_key),
+            unlock_after,
+            spl_token_program,
+        )
+        .await
+        .unwrap();
+
+        // the delegate cannot unlock without the token record, which would leave
+        // the unlock timestamp behind
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let token_delegate = Keypair::from_bytes(&delegate.to_bytes()).unwrap();
+
+        let error = asset
+            .unlock(&mut context, token_delegate, None, payer, spl_token_program)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::MissingTokenRecord);
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let token_delegate = Keypair::from_bytes(&delegate.to_bytes()).unwrap();
+
+        asset
+            .unlock(
+                &mut context,
+                token_delegate,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // a lock without an unlock timestamp also requires the token record
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let token_delegate = Keypair::from_bytes(&delegate.to_bytes()).unwrap();
+
+        let error = asset
+            .lock(&mut context, token_delegate, None, payer, spl_token_program)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::MissingTokenRecord);
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let token_delegate = Keypair::from_bytes(&delegate.to_bytes()).unwrap();
+
+        asset
+            .lock(
+                &mut context,
+                token_delegate,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // the holder cannot unlock once the previous unlock timestamp passes
+
+        set_timestamp(&mut context, unlock_after).await;
+
+        let error = holder_unlock(&mut context, &mut asset, Some(pda_key), spl_token_program)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InvalidAuthorityType);
+
+        let token_account = get_account(&mut context, &asset.token.unwrap()).await;
+        let token = unpack::<Account>(&token_account.data).unwrap();
+        assert!(token.is_frozen());
+    }
+}
@@ -0,0 +1,292 @@
+#![cfg(feature = "test-bpf")]
+pub mod utils;
+
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use token_metadata::{
+    error::MetadataError,
+    instruction::{
+        builders::UpdateAttributesBuilder, DelegateArgs, InstructionBuilder, MetadataDelegateRole,
+        UpdateArgs, UpdateAttributesArgs,
+    },
+    pda::{find_metadata_attributes_account, find_metadata_delegate_record_account},
+    state::{Attribute, MetadataAttributes, TokenStandard, MAX_ATTRIBUTE_VALUE_LENGTH},
+};
+use utils::*;
+
+mod metadata_attributes {
+
+    use super::*;
+
+    fn attribute(key: &str, value: &str) -> Attribute {
+        Attribute {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    async fn update_attributes(
+        context: &mut ProgramTestContext,
+        asset: &DigitalAsset,
+        authority: &Keypair,
+        delegate_record: Option<Pubkey>,
+        set: Vec<Attribute>,
+        remove: Vec<String>,
+    ) -> Result<(), BanksClientError> {
+        let payer = context.payer.dirty_clone();
+        let (attributes, _) = find_metadata_attributes_account(&asset.mint.pubkey());
+
+        let mut builder = UpdateAttributesBuilder::new();
+        builder
+            .authority(authority.pubkey())
+            .metadata(asset.metadata)
+            .mint(asset.mint.pubkey())
+            .attributes(attributes)
+            .payer(payer.pubkey());
+
+        if let Some(delegate_record) = delegate_record {
+            builder.delegate_record(delegate_record);
+        }
+
+        let ix = builder
+            .build(UpdateAttributesArgs::V1 { set, remove })
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer, authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn get_attributes(
+        context: &mut ProgramTestContext,
+        asset: &DigitalAsset,
+    ) -> (MetadataAttributes, usize) {
+        let (attributes, _) = find_metadata_attributes_account(&asset.mint.pubkey());
+        let account = get_account(context, &attributes).await;
+
+        (
+            MetadataAttributes::from_bytes(&account.data).unwrap(),
+            account.data.len(),
+        )
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn success_update_attributes(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let update_authority = context.payer.dirty_clone();
+
+        update_attributes(
+            &mut context,
+            &asset,
+            &update_authority,
+            None,
+            vec![attribute("level", "1"), attribute("class", "mage")],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+        let (attributes, size) = get_attributes(&mut context, &asset).await;
+        assert_eq!(attributes.mint, asset.mint.pubkey());
+        assert_eq!(attributes.get("level"), Some("1"));
+        assert_eq!(attributes.get("class"), Some("mage"));
+
+        // the account grows and shrinks with the attributes
+
+        update_attributes(
+            &mut context,
+            &asset,
+            &update_authority,
+            None,
+            vec![attribute("level", "10")],
+            vec![String::from("class")],
+        )
+        .await
+        .unwrap();
+
+        let (attributes, new_size) = get_attributes(&mut context, &asset).await;
+        assert_eq!(attributes.attributes, vec![attribute("level", "10")]);
+        assert!(new_size < size);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn success_update_attributes_as_data_delegate(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let update_authority = context.payer.dirty_clone();
+        let delegate = Keypair::new();
+        airdrop(&mut context, &delegate.pubkey(), 1_000_000_000)
+            .await
+            .unwrap();
+
+        // a signer that is not an authority of the asset cannot update attributes
+
+        let error = update_attributes(
+            &mut context,
+            &asset,
+            &delegate,
+            None,
+            vec![attribute("level", "1")],
+            vec![],
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::UpdateAuthorityIncorrect);
+
+        asset
+            .delegate(
+                &mut context,
+                update_authority,
+                delegate.pubkey(),
+                DelegateArgs::DataItemV1 {
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (delegate_record, _) = find_metadata_delegate_record_account(
+            &asset.mint.pubkey(),
+            MetadataDelegateRole::DataItem,
+            &context.payer.pubkey(),
+            &delegate.pubkey(),
+        );
+
+        update_attributes(
+            &mut context,
+            &asset,
+            &delegate,
+            Some(delegate_record),
+            vec![attribute("level", "1")],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+        let (attributes, _) = get_attributes(&mut context, &asset).await;
+        assert_eq!(attributes.get("level"), Some("1"));
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_attribute_value_too_long(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let update_authority = context.payer.dirty_clone();
+        let value = "v".repeat(MAX_ATTRIBUTE_VALUE_LENGTH + 1);
+
+        let error = update_attributes(
+            &mut context,
+            &asset,
+            &update_authority,
+            None,
+            vec![attribute("level", &value)],
+            vec![],
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::AttributeValueTooLong);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_update_attributes_immutable(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let update_authority = context.payer.dirty_clone();
+
+        // makes the metadata immutable
+
+        let mut args = UpdateArgs::default_as_update_authority();
+        match &mut args {
+            UpdateArgs::AsUpdateAuthorityV2 { is_mutable, .. } => {
+                *is_mutable = Some(false);
+            }
+            _ => panic!("Unexpected enum variant"),
+        }
+
+        asset
+            .update(&mut context, update_authority.dirty_clone(), args)
+            .await
+            .unwrap();
+
+        let error = update_attributes(
+            &mut context,
+            &asset,
+            &update_authority,
+            None,
+            vec![attribute("level", "1")],
+            vec![],
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::DataIsImmutable);
+    }
+}
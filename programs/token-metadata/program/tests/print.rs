@@ -17,7 +17,6 @@ mod print {
 
     use borsh::BorshDeserialize;
     use solana_program::pubkey::Pubkey;
-    use solana_sdk::{signature::Keypair, signer::Signer};
     use token_metadata::state::{PrintSupply, ProgrammableConfig, TokenStandard};
 
     use super::*;
@@ -76,8 +75,15 @@ mod print {
     #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
     #[tokio::test]
     async fn pnft_editions_rule_set_inheritance_success(spl_token_program: Pubkey) {
-        let mut context = program_test().start_with_context().await;
-        let rule_set_key = Keypair::new().pubkey();
+        let mut program_test = program_test();
+        program_test.add_program("mpl_token_auth_rules", mpl_token_auth_rules::ID, None);
+        let mut context = program_test.start_with_context().await;
+
+        // prints are validated against the rule set of the master edition, so it
+        // must be an existing rule set
+        let creator = context.payer.dirty_clone();
+        let (rule_set_key, _) =
+            create_default_metaplex_rule_set(&mut context, creator, false).await;
         let mut asset = DigitalAsset::default();
         asset
             .create_and_mint_with_supply(
@@ -18,7 +18,8 @@ mod process_legacy_instruction {
     use spl_token_2022::state::Account;
     use token_metadata::{
         error::MetadataError,
-        instruction::{sign_metadata, DelegateArgs},
+        instruction::{approve_use_authority, sign_metadata, DelegateArgs},
+        pda::{find_program_as_burner_account, find_use_authority_account},
         state::{Metadata, TokenStandard},
         utils::unpack,
     };
@@ -92,6 +93,62 @@ mod process_legacy_instruction {
         assert_custom_error!(error, MetadataError::InstructionNotSupported);
     }
 
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn programmable_nft_in_use_authority_instruction(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        // asset
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                None,
+                None,
+                1,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        // tries to approve a use authority for a pNFT, which is only supported
+        // for Token-2022 assets
+
+        let use_authority = Keypair::new();
+        let (record, _) = find_use_authority_account(&asset.mint.pubkey(), &use_authority.pubkey());
+        let (burner, _) = find_program_as_burner_account();
+
+        let approve_ix = approve_use_authority(
+            token_metadata::ID,
+            record,
+            use_authority.pubkey(),
+            context.payer.pubkey(),
+            context.payer.pubkey(),
+            asset.token.unwrap(),
+            asset.metadata,
+            asset.mint.pubkey(),
+            burner,
+            1,
+        );
+        let approve_tx = Transaction::new_signed_with_payer(
+            &[approve_ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let error = context
+            .banks_client
+            .process_transaction(approve_tx)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::InstructionNotSupported);
+    }
+
     #[test_case::test_case(spl_token::id() ; "Token Program")]
     #[tokio::test]
     async fn thaw_programmable_nft(spl_token_program: Pubkey) {
@@ -0,0 +1,168 @@
+#![cfg(feature = "simulator")]
+
+use solana_program::{
+    program_pack::Pack, pubkey::Pubkey, system_program, sysvar::instructions::ID as SYSVAR_ID,
+};
+use token_metadata::{
+    error::MetadataError,
+    instruction::{
+        builders::{CreateBuilder, MintBuilder, UpdateBuilder},
+        CreateArgs, InstructionBuilder, MintArgs, UpdateArgs,
+    },
+    pda::{find_master_edition_account, find_metadata_account},
+    simulator::{SimulatedAccount, Simulator},
+    state::{AssetData, Metadata, PrintSupply, TokenMetadataAccount, TokenStandard},
+    ID,
+};
+
+const LAMPORTS: u64 = 10_000_000_000;
+
+struct Asset {
+    mint: Pubkey,
+    metadata: Pubkey,
+    master_edition: Pubkey,
+}
+
+fn create(simulator: &mut Simulator, payer: &Pubkey) -> Asset {
+    let mint = Pubkey::new_unique();
+    let (metadata, _) = find_metadata_account(&mint);
+    let (master_edition, _) = find_master_edition_account(&mint);
+
+    let create_ix = CreateBuilder::new()
+        .metadata(metadata)
+        .master_edition(master_edition)
+        .mint(mint)
+        .authority(*payer)
+        .payer(*payer)
+        .update_authority(*payer)
+        .initialize_mint(true)
+        .update_authority_as_signer(true)
+        .build(CreateArgs::V1 {
+            asset_data: AssetData::new(
+                TokenStandard::NonFungible,
+                String::from("Simulated"),
+                String::from("SIM"),
+                String::from("https://simulated.asset"),
+            ),
+            decimals: Some(0),
+            print_supply: Some(PrintSupply::Zero),
+        })
+        .unwrap()
+        .instruction();
+
+    let result = simulator.process(&create_ix);
+    assert_eq!(result.result, Ok(()), "{:#?}", result.logs);
+
+    Asset {
+        mint,
+        metadata,
+        master_edition,
+    }
+}
+
+mod simulator {
+    use super::*;
+
+    #[test]
+    fn create_and_mint() {
+        let mut simulator = Simulator::new();
+        let payer = Pubkey::new_unique();
+        simulator.add_account(
+            payer,
+            SimulatedAccount::new(LAMPORTS, vec![], system_program::ID),
+        );
+
+        let asset = create(&mut simulator, &payer);
+
+        let metadata_account = simulator.account(&asset.metadata).unwrap();
+        assert_eq!(metadata_account.owner, ID);
+        let metadata = Metadata::safe_deserialize(&metadata_account.data).unwrap();
+        assert_eq!(metadata.mint, asset.mint);
+        assert_eq!(metadata.data.name.trim_matches('\0'), "Simulated");
+        assert_eq!(simulator.account(&asset.mint).unwrap().owner, spl_token::ID);
+
+        let token = spl_associated_token_account::get_associated_token_address(&payer, &asset.mint);
+
+        let mint_ix = MintBuilder::new()
+            .token(token)
+            .token_owner(payer)
+            .metadata(asset.metadata)
+            .master_edition(asset.master_edition)
+            .mint(asset.mint)
+            .payer(payer)
+            .authority(payer)
+            .build(MintArgs::V1 {
+                amount: 1,
+                authorization_data: None,
+            })
+            .unwrap()
+            .instruction();
+
+        let result = simulator.process(&mint_ix);
+        assert_eq!(result.result, Ok(()), "{:#?}", result.logs);
+
+        // the token account is created through the associated token account program
+        let diff = result.diffs.iter().find(|d| d.address == token).unwrap();
+        assert!(diff.before.is_none());
+
+        let token_account =
+            spl_token::state::Account::unpack(&diff.after.as_ref().unwrap().data).unwrap();
+        assert_eq!(token_account.amount, 1);
+        assert_eq!(token_account.owner, payer);
+
+        assert!(result
+            .logs
+            .iter()
+            .any(|log| log == &format!("Program {} invoke [2]", spl_associated_token_account::ID)));
+    }
+
+    #[test]
+    fn fail_with_metadata_error() {
+        let mut simulator = Simulator::new();
+        let payer = Pubkey::new_unique();
+        simulator.add_account(
+            payer,
+            SimulatedAccount::new(LAMPORTS, vec![], system_program::ID),
+        );
+
+        let asset = create(&mut simulator, &payer);
+        let before = simulator.account(&asset.metadata).cloned();
+
+        let authority = Pubkey::new_unique();
+
+        let update_ix = UpdateBuilder::new()
+            .authority(authority)
+            .metadata(asset.metadata)
+            .edition(asset.master_edition)
+            .mint(asset.mint)
+            .payer(authority)
+            .build(UpdateArgs::default_as_update_authority())
+            .unwrap()
+            .instruction();
+
+        let result = simulator.process(&update_ix);
+
+        assert!(result.result.is_err());
+        assert_eq!(
+            result.metadata_error(),
+            Some(MetadataError::InvalidAuthorityType)
+        );
+        assert!(result.diffs.is_empty());
+        assert_eq!(simulator.account(&asset.metadata).cloned(), before);
+    }
+
+    #[test]
+    fn generate_instructions_sysvar() {
+        let mut simulator = Simulator::new();
+        let payer = Pubkey::new_unique();
+        simulator.add_account(
+            payer,
+            SimulatedAccount::new(LAMPORTS, vec![], system_program::ID),
+        );
+
+        create(&mut simulator, &payer);
+
+        // the sysvar is generated per instruction and never stored
+        assert!(simulator.account(&SYSVAR_ID).is_none());
+    }
+}
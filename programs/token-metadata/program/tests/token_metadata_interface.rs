@@ -0,0 +1,146 @@
+#![cfg(feature = "test-bpf")]
+pub mod utils;
+
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signature::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token_metadata_interface::{
+    borsh::BorshDeserialize,
+    instruction::{emit, update_field},
+    state::{Field, TokenMetadata},
+};
+use token_metadata::{error::MetadataError, state::TokenStandard};
+use utils::*;
+
+mod token_metadata_interface {
+
+    use super::*;
+
+    async fn process_with_return_data(
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+    ) -> Result<Vec<u8>, BanksClientError> {
+        let payer = context.payer.dirty_clone();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        let return_data = result.metadata.unwrap().return_data.unwrap();
+        assert_eq!(return_data.program_id, token_metadata::ID);
+
+        Ok(return_data.data)
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn emit_token_metadata(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let metadata = asset.get_metadata(&mut context).await;
+
+        let data = process_with_return_data(
+            &mut context,
+            emit(&token_metadata::ID, &asset.metadata, None, None),
+        )
+        .await
+        .unwrap();
+
+        // trailing zeros of the return data are stripped by the runtime, which
+        // includes the (empty) length of the additional metadata
+        let mut padded = data.clone();
+        padded.resize(data.len() + 4, 0);
+        let token_metadata = TokenMetadata::deserialize(&mut padded.as_slice()).unwrap();
+        assert_eq!(
+            Option::<Pubkey>::from(token_metadata.update_authority),
+            Some(metadata.update_authority)
+        );
+        assert_eq!(token_metadata.mint, asset.mint.pubkey());
+        // the padding of the metadata strings is not emitted
+        assert_eq!(token_metadata.name, DEFAULT_NAME);
+        assert_eq!(token_metadata.symbol, DEFAULT_SYMBOL);
+        assert_eq!(token_metadata.uri, DEFAULT_URI);
+        assert!(token_metadata.additional_metadata.is_empty());
+
+        // emits a range of the data
+        let range = process_with_return_data(
+            &mut context,
+            emit(&token_metadata::ID, &asset.metadata, Some(32), Some(64)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(range, data[32..64]);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_unsupported_instruction(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut asset = DigitalAsset::new();
+        asset
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let payer = context.payer.dirty_clone();
+        let ix = update_field(
+            &token_metadata::ID,
+            &asset.metadata,
+            &payer.pubkey(),
+            Field::Name,
+            String::from("Updated"),
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer],
+            context.last_blockhash,
+        );
+
+        let error = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(
+            error,
+            MetadataError::UnsupportedTokenMetadataInterfaceInstruction
+        );
+    }
+}
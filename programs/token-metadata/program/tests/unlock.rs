@@ -163,11 +163,18 @@ mod utility {
 
         // lock
 
+        let (pda_key, _) = find_token_record_account(&asset.mint.pubkey(), &asset.token.unwrap());
         let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
         let token_delegate = Keypair::from_bytes(&delegate.to_bytes()).unwrap();
 
         asset
-            .lock(&mut context, token_delegate, None, payer, spl_token_program)
+            .lock(
+                &mut context,
+                token_delegate,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
             .await
             .unwrap();
 
@@ -182,7 +189,13 @@ mod utility {
         let token_delegate = Keypair::from_bytes(&delegate.to_bytes()).unwrap();
 
         asset
-            .unlock(&mut context, token_delegate, None, payer, spl_token_program)
+            .unlock(
+                &mut context,
+                token_delegate,
+                Some(pda_key),
+                payer,
+                spl_token_program,
+            )
             .await
             .unwrap();
 
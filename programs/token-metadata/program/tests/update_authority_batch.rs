@@ -0,0 +1,362 @@
+#![cfg(feature = "test-bpf")]
+pub mod utils;
+
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use token_metadata::{
+    error::MetadataError,
+    instruction::{
+        builders::UpdateAuthorityBatchBuilder, DelegateArgs, InstructionBuilder,
+        MetadataDelegateRole, UpdateAuthorityBatchArgs, VerificationArgs,
+    },
+    pda::find_metadata_delegate_record_account,
+    state::{Collection, PrintSupply, TokenStandard},
+};
+use utils::*;
+
+mod update_authority_batch {
+
+    use super::*;
+
+    async fn create_items(
+        context: &mut ProgramTestContext,
+        collection: &Option<Collection>,
+        count: usize,
+        spl_token_program: Pubkey,
+    ) -> Vec<DigitalAsset> {
+        let mut items = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let mut da = DigitalAsset::new();
+            da.create_advanced(
+                context,
+                TokenStandard::NonFungible,
+                String::from(DEFAULT_NAME),
+                String::from(DEFAULT_SYMBOL),
+                String::from(DEFAULT_URI),
+                500,
+                None,
+                collection.clone(),
+                None,
+                None,
+                PrintSupply::Zero,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+            items.push(da);
+        }
+
+        items
+    }
+
+    async fn create_verified_items(
+        context: &mut ProgramTestContext,
+        collection_parent_da: &DigitalAsset,
+        count: usize,
+        spl_token_program: Pubkey,
+    ) -> Vec<DigitalAsset> {
+        let collection = Some(Collection {
+            key: collection_parent_da.mint.pubkey(),
+            verified: false,
+        });
+
+        let mut items = create_items(context, &collection, count, spl_token_program).await;
+
+        for da in items.iter_mut() {
+            let payer = context.payer.dirty_clone();
+            da.verify(
+                context,
+                payer,
+                VerificationArgs::CollectionV1,
+                None,
+                None,
+                Some(collection_parent_da.mint.pubkey()),
+                Some(collection_parent_da.metadata),
+                collection_parent_da.edition,
+            )
+            .await
+            .unwrap();
+        }
+
+        items
+    }
+
+    async fn update_authority_batch(
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        delegate_record: Option<Pubkey>,
+        items: &[Pubkey],
+        new_update_authority: Pubkey,
+        collection: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let payer = context.payer.dirty_clone();
+
+        let mut builder = UpdateAuthorityBatchBuilder::new();
+        builder.authority(authority.pubkey()).items(items.to_vec());
+
+        if let Some(delegate_record) = delegate_record {
+            builder.delegate_record(delegate_record);
+        }
+
+        let ix = builder
+            .build(UpdateAuthorityBatchArgs::V1 {
+                new_update_authority,
+                collection,
+            })
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer, authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn success_rotate_update_authority(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let items = create_items(&mut context, &None, 3, spl_token_program).await;
+        let batch: Vec<Pubkey> = items.iter().map(|da| da.metadata).collect();
+
+        let update_authority = context.payer.dirty_clone();
+        let new_update_authority = Keypair::new().pubkey();
+
+        update_authority_batch(
+            &mut context,
+            &update_authority,
+            None,
+            &batch,
+            new_update_authority,
+            None,
+        )
+        .await
+        .unwrap();
+
+        for da in &items {
+            let metadata = da.get_metadata(&mut context).await;
+            assert_eq!(metadata.update_authority, new_update_authority);
+        }
+
+        // a batch that includes items that were already rotated can be retried
+
+        let mut other = create_items(&mut context, &None, 1, spl_token_program).await;
+        let mut retry = batch.clone();
+        retry.push(other[0].metadata);
+
+        context.warp_to_slot(100).unwrap();
+
+        update_authority_batch(
+            &mut context,
+            &update_authority,
+            None,
+            &retry,
+            new_update_authority,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let metadata = other.remove(0).get_metadata(&mut context).await;
+        assert_eq!(metadata.update_authority, new_update_authority);
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_item_with_different_update_authority(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let items = create_items(&mut context, &None, 2, spl_token_program).await;
+
+        // rotates the update authority of the first item only
+        let update_authority = context.payer.dirty_clone();
+        let other_update_authority = Keypair::new();
+
+        update_authority_batch(
+            &mut context,
+            &update_authority,
+            None,
+            &[items[0].metadata],
+            other_update_authority.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let batch: Vec<Pubkey> = items.iter().map(|da| da.metadata).collect();
+        let error = update_authority_batch(
+            &mut context,
+            &update_authority,
+            None,
+            &batch,
+            Keypair::new().pubkey(),
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::UpdateAuthorityIncorrect);
+
+        // the batch is not applied partially
+        let metadata = items[1].get_metadata(&mut context).await;
+        assert_eq!(metadata.update_authority, update_authority.pubkey());
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_item_outside_of_collection(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut collection_parent_da = DigitalAsset::new();
+        collection_parent_da
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let items =
+            create_verified_items(&mut context, &collection_parent_da, 2, spl_token_program).await;
+        let other = create_items(&mut context, &None, 1, spl_token_program).await;
+
+        let mut batch: Vec<Pubkey> = items.iter().map(|da| da.metadata).collect();
+        batch.push(other[0].metadata);
+
+        let update_authority = context.payer.dirty_clone();
+        let new_update_authority = Keypair::new().pubkey();
+
+        let error = update_authority_batch(
+            &mut context,
+            &update_authority,
+            None,
+            &batch,
+            new_update_authority,
+            Some(collection_parent_da.mint.pubkey()),
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::NotAMemberOfCollection);
+
+        // only the verified members of the collection
+        batch.pop();
+
+        update_authority_batch(
+            &mut context,
+            &update_authority,
+            None,
+            &batch,
+            new_update_authority,
+            Some(collection_parent_da.mint.pubkey()),
+        )
+        .await
+        .unwrap();
+
+        for da in &items {
+            let metadata = da.get_metadata(&mut context).await;
+            assert_eq!(metadata.update_authority, new_update_authority);
+        }
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn success_rotate_as_collection_authority_item_delegate(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut collection_parent_da = DigitalAsset::new();
+        collection_parent_da
+            .create(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let items =
+            create_verified_items(&mut context, &collection_parent_da, 2, spl_token_program).await;
+        let batch: Vec<Pubkey> = items.iter().map(|da| da.metadata).collect();
+
+        // approves an authority item delegate on the collection
+
+        let update_authority = context.payer.dirty_clone();
+        let delegate = Keypair::new();
+        airdrop(&mut context, &delegate.pubkey(), 1_000_000_000)
+            .await
+            .unwrap();
+
+        collection_parent_da
+            .delegate(
+                &mut context,
+                update_authority.dirty_clone(),
+                delegate.pubkey(),
+                DelegateArgs::AuthorityItemV1 {
+                    authorization_data: None,
+                },
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let (delegate_record, _) = find_metadata_delegate_record_account(
+            &collection_parent_da.mint.pubkey(),
+            MetadataDelegateRole::AuthorityItem,
+            &update_authority.pubkey(),
+            &delegate.pubkey(),
+        );
+
+        let new_update_authority = Keypair::new().pubkey();
+
+        // the delegate is only valid for items of the collection
+
+        let error = update_authority_batch(
+            &mut context,
+            &delegate,
+            Some(delegate_record),
+            &batch,
+            new_update_authority,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(error, MetadataError::UpdateAuthorityIncorrect);
+
+        update_authority_batch(
+            &mut context,
+            &delegate,
+            Some(delegate_record),
+            &batch,
+            new_update_authority,
+            Some(collection_parent_da.mint.pubkey()),
+        )
+        .await
+        .unwrap();
+
+        for da in &items {
+            let metadata = da.get_metadata(&mut context).await;
+            assert_eq!(metadata.update_authority, new_update_authority);
+        }
+    }
+}
@@ -0,0 +1,352 @@
+#![cfg(feature = "test-bpf")]
+pub mod utils;
+
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use token_metadata::{
+    error::MetadataError,
+    instruction::{
+        builders::{
+            AcceptUpdateAuthorityBuilder, CancelUpdateAuthorityBuilder,
+            ProposeUpdateAuthorityBuilder, UpdateBuilder,
+        },
+        AcceptUpdateAuthorityArgs, CancelUpdateAuthorityArgs, InstructionBuilder,
+        ProposeUpdateAuthorityArgs, UpdateArgs,
+    },
+    pda::find_pending_update_authority_account,
+    state::{PendingUpdateAuthority, TokenStandard},
+};
+use utils::{DigitalAsset, *};
+
+mod update_authority_handoff {
+
+    use super::*;
+
+    async fn propose(
+        context: &mut ProgramTestContext,
+        da: &DigitalAsset,
+        authority: &Keypair,
+        new_update_authority: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = context.payer.dirty_clone();
+        let (pending_update_authority, _) =
+            find_pending_update_authority_account(&da.mint.pubkey());
+
+        let ix = ProposeUpdateAuthorityBuilder::new()
+            .authority(authority.pubkey())
+            .metadata(da.metadata)
+            .mint(da.mint.pubkey())
+            .pending_update_authority(pending_update_authority)
+            .payer(payer.pubkey())
+            .build(ProposeUpdateAuthorityArgs::V1 {
+                new_update_authority,
+            })
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer, authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn accept(
+        context: &mut ProgramTestContext,
+        da: &DigitalAsset,
+        new_update_authority: &Keypair,
+        update_authority: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = context.payer.dirty_clone();
+        let (pending_update_authority, _) =
+            find_pending_update_authority_account(&da.mint.pubkey());
+
+        let ix = AcceptUpdateAuthorityBuilder::new()
+            .new_update_authority(new_update_authority.pubkey())
+            .metadata(da.metadata)
+            .pending_update_authority(pending_update_authority)
+            .update_authority(update_authority)
+            .build(AcceptUpdateAuthorityArgs::V1)
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer, new_update_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn cancel(
+        context: &mut ProgramTestContext,
+        da: &DigitalAsset,
+        authority: &Keypair,
+        update_authority: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = context.payer.dirty_clone();
+        let (pending_update_authority, _) =
+            find_pending_update_authority_account(&da.mint.pubkey());
+
+        let ix = CancelUpdateAuthorityBuilder::new()
+            .authority(authority.pubkey())
+            .metadata(da.metadata)
+            .pending_update_authority(pending_update_authority)
+            .update_authority(update_authority)
+            .build(CancelUpdateAuthorityArgs::V1)
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[&payer, authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn get_pending_update_authority(
+        context: &mut ProgramTestContext,
+        da: &DigitalAsset,
+    ) -> Option<PendingUpdateAuthority> {
+        let (pending_update_authority, _) =
+            find_pending_update_authority_account(&da.mint.pubkey());
+
+        context
+            .banks_client
+            .get_account(pending_update_authority)
+            .await
+            .unwrap()
+            .map(|account| PendingUpdateAuthority::from_bytes(&account.data).unwrap())
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn success_propose_and_accept(spl_token_program: Pubkey) {
+        let context = &mut program_test().start_with_context().await;
+
+        let update_authority = context.payer.dirty_clone();
+
+        let mut da = DigitalAsset::new();
+        da.create(context, TokenStandard::NonFungible, None, spl_token_program)
+            .await
+            .unwrap();
+
+        // Propose a new update authority.
+        let new_update_authority = Keypair::new();
+        propose(
+            context,
+            &da,
+            &update_authority,
+            new_update_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+        let pending = get_pending_update_authority(context, &da).await.unwrap();
+        assert_eq!(pending.mint, da.mint.pubkey());
+        assert_eq!(pending.update_authority, update_authority.pubkey());
+        assert_eq!(pending.new_update_authority, new_update_authority.pubkey());
+
+        // The update authority does not change until the proposal is accepted.
+        let metadata = da.get_metadata(context).await;
+        assert_eq!(metadata.update_authority, update_authority.pubkey());
+
+        accept(
+            context,
+            &da,
+            &new_update_authority,
+            update_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+        let metadata = da.get_metadata(context).await;
+        assert_eq!(metadata.update_authority, new_update_authority.pubkey());
+
+        assert!(get_pending_update_authority(context, &da).await.is_none());
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_propose_by_non_update_authority(spl_token_program: Pubkey) {
+        let context = &mut program_test().start_with_context().await;
+
+        let mut da = DigitalAsset::new();
+        da.create(context, TokenStandard::NonFungible, None, spl_token_program)
+            .await
+            .unwrap();
+
+        let authority = Keypair::new();
+        let err = propose(context, &da, &authority, authority.pubkey())
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(err, MetadataError::UpdateAuthorityIncorrect);
+        assert!(get_pending_update_authority(context, &da).await.is_none());
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_accept_by_other_authority(spl_token_program: Pubkey) {
+        let context = &mut program_test().start_with_context().await;
+
+        let update_authority = context.payer.dirty_clone();
+
+        let mut da = DigitalAsset::new();
+        da.create(context, TokenStandard::NonFungible, None, spl_token_program)
+            .await
+            .unwrap();
+
+        let new_update_authority = Keypair::new();
+        propose(
+            context,
+            &da,
+            &update_authority,
+            new_update_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+        // Only the proposed authority can accept.
+        let other = Keypair::new();
+        let err = accept(context, &da, &other, update_authority.pubkey())
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(err, MetadataError::PendingUpdateAuthorityMismatch);
+
+        let metadata = da.get_metadata(context).await;
+        assert_eq!(metadata.update_authority, update_authority.pubkey());
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn success_cancel(spl_token_program: Pubkey) {
+        let context = &mut program_test().start_with_context().await;
+
+        let update_authority = context.payer.dirty_clone();
+
+        let mut da = DigitalAsset::new();
+        da.create(context, TokenStandard::NonFungible, None, spl_token_program)
+            .await
+            .unwrap();
+
+        let new_update_authority = Keypair::new();
+        propose(
+            context,
+            &da,
+            &update_authority,
+            new_update_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+        // A third party cannot cancel the proposal.
+        let other = Keypair::new();
+        let err = cancel(context, &da, &other, update_authority.pubkey())
+            .await
+            .unwrap_err();
+
+        assert_custom_error!(err, MetadataError::PendingUpdateAuthorityMismatch);
+
+        cancel(context, &da, &update_authority, update_authority.pubkey())
+            .await
+            .unwrap();
+
+        assert!(get_pending_update_authority(context, &da).await.is_none());
+
+        // The proposal can no longer be accepted.
+        accept(
+            context,
+            &da,
+            &new_update_authority,
+            update_authority.pubkey(),
+        )
+        .await
+        .unwrap_err();
+
+        let metadata = da.get_metadata(context).await;
+        assert_eq!(metadata.update_authority, update_authority.pubkey());
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn fail_accept_stale_proposal(spl_token_program: Pubkey) {
+        let context = &mut program_test().start_with_context().await;
+
+        let update_authority = context.payer.dirty_clone();
+
+        let mut da = DigitalAsset::new();
+        da.create(context, TokenStandard::NonFungible, None, spl_token_program)
+            .await
+            .unwrap();
+
+        let new_update_authority = Keypair::new();
+        propose(
+            context,
+            &da,
+            &update_authority,
+            new_update_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+        // The update authority is changed directly after the proposal.
+        let mut args = UpdateArgs::default_as_update_authority();
+        match &mut args {
+            UpdateArgs::AsUpdateAuthorityV2 {
+                new_update_authority,
+                ..
+            } => *new_update_authority = Some(Keypair::new().pubkey()),
+            _ => panic!("Unexpected enum variant"),
+        }
+
+        let update_ix = UpdateBuilder::new()
+            .authority(update_authority.pubkey())
+            .metadata(da.metadata)
+            .mint(da.mint.pubkey())
+            .edition(da.edition.unwrap())
+            .payer(update_authority.pubkey())
+            .build(args)
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[update_ix],
+            Some(&update_authority.pubkey()),
+            &[&update_authority],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let err = accept(
+            context,
+            &da,
+            &new_update_authority,
+            update_authority.pubkey(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(err, MetadataError::StalePendingUpdateAuthority);
+    }
+}
@@ -568,4 +568,111 @@ mod uses {
             Account::unpack_from_slice(token_account_after_burn.data.as_slice()).unwrap();
         assert_eq!(token_account_after_burn_data.amount, 0);
     }
+
+    // rule sets are only read on-chain with the `rule-set-operations` feature
+    #[cfg(feature = "rule-set-operations")]
+    #[tokio::test]
+    async fn rule_set_restricts_use_programmable_nonfungible() {
+        use solana_program::{instruction::AccountMeta, pubkey::Pubkey, sysvar};
+        use token_metadata::{
+            instruction::{UpdateArgs, UsesToggle},
+            pda::find_token_record_account,
+            state::TokenStandard,
+        };
+
+        let mut program_test = program_test();
+        program_test.add_program("mpl_token_auth_rules", mpl_token_auth_rules::ID, None);
+        let mut context = program_test.start_with_context().await;
+
+        // rule set only allowing another authority to use the asset
+
+        let allowed = Keypair::new();
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        let (rule_set, auth_data) =
+            create_lifecycle_rule_set(&mut context, payer, allowed.pubkey()).await;
+
+        // asset
+
+        let mut asset = DigitalAsset::default();
+        asset
+            .create_and_mint(
+                &mut context,
+                TokenStandard::ProgrammableNonFungible,
+                Some(rule_set),
+                Some(auth_data),
+                1,
+                spl_token::ID,
+            )
+            .await
+            .unwrap();
+
+        let mut args = UpdateArgs::default_v1();
+        match &mut args {
+            UpdateArgs::V1 { uses, .. } => {
+                *uses = UsesToggle::Set(Uses {
+                    use_method: UseMethod::Multiple,
+                    total: 2,
+                    remaining: 2,
+                })
+            }
+            _ => panic!("Unexpected enum variant"),
+        }
+
+        let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        asset.update(&mut context, payer, args).await.unwrap();
+
+        let token = asset.token.unwrap();
+        let (token_record, _) = find_token_record_account(&asset.mint.pubkey(), &token);
+
+        // programmable assets pass every account, with the program id in place of
+        // the use authority record and burner
+
+        let owner = context.payer.pubkey();
+        let utilize_ix = |authorization_rules: Pubkey| {
+            let mut ix = token_metadata::instruction::utilize(
+                token_metadata::ID,
+                asset.metadata,
+                token,
+                asset.mint.pubkey(),
+                None,
+                owner,
+                owner,
+                None,
+                1,
+            );
+            ix.accounts.extend([
+                AccountMeta::new_readonly(sysvar::rent::ID, false),
+                AccountMeta::new_readonly(token_metadata::ID, false),
+                AccountMeta::new_readonly(token_metadata::ID, false),
+                AccountMeta::new_readonly(token_record, false),
+                AccountMeta::new_readonly(mpl_token_auth_rules::ID, false),
+                AccountMeta::new_readonly(authorization_rules, false),
+            ]);
+            ix
+        };
+
+        // the rule set rejects the owner
+
+        let tx = Transaction::new_signed_with_payer(
+            &[utilize_ix(rule_set)],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_custom_error_ix!(
+            0,
+            err,
+            mpl_token_auth_rules::error::RuleSetError::PubkeyMatchCheckFailed
+        );
+
+        let metadata = asset.get_metadata(&mut context).await;
+        assert_eq!(metadata.uses.unwrap().remaining, 2);
+    }
 }
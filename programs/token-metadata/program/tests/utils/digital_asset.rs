@@ -1,5 +1,7 @@
 use borsh::BorshDeserialize;
-use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey};
+use solana_program::{
+    instruction::AccountMeta, program_option::COption, program_pack::Pack, pubkey::Pubkey,
+};
 use solana_program_test::{BanksClientError, ProgramTestContext};
 use solana_sdk::{
     account::AccountSharedData,
@@ -148,7 +150,18 @@ impl DigitalAsset {
             builder.collection_metadata(collection_metadata);
         }
 
-        let burn_ix = builder.build(args).unwrap().instruction();
+        let mut burn_ix = builder.build(args).unwrap().instruction();
+
+        // the rule set accounts are read from the remaining accounts
+        if let Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        }) = md.programmable_config
+        {
+            burn_ix.accounts.extend([
+                AccountMeta::new_readonly(mpl_token_auth_rules::ID, false),
+                AccountMeta::new_readonly(rule_set, false),
+            ]);
+        }
 
         let transaction = Transaction::new_signed_with_payer(
             &[burn_ix],
@@ -689,9 +702,13 @@ impl DigitalAsset {
         match args {
             // Token delegates.
             DelegateArgs::SaleV1 { .. }
+            | DelegateArgs::SaleV2 { .. }
             | DelegateArgs::TransferV1 { .. }
+            | DelegateArgs::TransferV2 { .. }
             | DelegateArgs::UtilityV1 { .. }
+            | DelegateArgs::UtilityV2 { .. }
             | DelegateArgs::StakingV1 { .. }
+            | DelegateArgs::StakingV2 { .. }
             | DelegateArgs::LockedTransferV1 { .. } => {
                 let (token_record, _) =
                     find_token_record_account(&self.mint.pubkey(), &self.token.unwrap());
@@ -701,7 +718,7 @@ impl DigitalAsset {
             DelegateArgs::StandardV1 { .. } => { /* nothing to add */ }
 
             // Metadata delegates.
-            DelegateArgs::CollectionV1 { .. } => {
+            DelegateArgs::CollectionV1 { .. } | DelegateArgs::CollectionV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::Collection,
@@ -711,7 +728,7 @@ impl DigitalAsset {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::DataV1 { .. } => {
+            DelegateArgs::DataV1 { .. } | DelegateArgs::DataV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::Data,
@@ -721,7 +738,8 @@ impl DigitalAsset {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::ProgrammableConfigV1 { .. } => {
+            DelegateArgs::ProgrammableConfigV1 { .. }
+            | DelegateArgs::ProgrammableConfigV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::ProgrammableConfig,
@@ -731,7 +749,7 @@ impl DigitalAsset {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::AuthorityItemV1 { .. } => {
+            DelegateArgs::AuthorityItemV1 { .. } | DelegateArgs::AuthorityItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::AuthorityItem,
@@ -741,7 +759,7 @@ impl DigitalAsset {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::DataItemV1 { .. } => {
+            DelegateArgs::DataItemV1 { .. } | DelegateArgs::DataItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::DataItem,
@@ -751,7 +769,7 @@ impl DigitalAsset {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::CollectionItemV1 { .. } => {
+            DelegateArgs::CollectionItemV1 { .. } | DelegateArgs::CollectionItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::CollectionItem,
@@ -761,7 +779,8 @@ impl DigitalAsset {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::ProgrammableConfigItemV1 { .. } => {
+            DelegateArgs::ProgrammableConfigItemV1 { .. }
+            | DelegateArgs::ProgrammableConfigItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::ProgrammableConfigItem,
@@ -1128,6 +1147,28 @@ impl DigitalAsset {
         token_record: Option<Pubkey>,
         payer: Keypair,
         spl_token_program: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.lock_with_args(
+            context,
+            delegate,
+            token_record,
+            payer,
+            spl_token_program,
+            LockArgs::V1 {
+                authorization_data: None,
+            },
+        )
+        .await
+    }
+
+    pub async fn lock_with_args(
+        &mut self,
+        context: &mut ProgramTestContext,
+        delegate: Keypair,
+        token_record: Option<Pubkey>,
+        payer: Keypair,
+        spl_token_program: Pubkey,
+        args: LockArgs,
     ) -> Result<(), BanksClientError> {
         let mut builder = LockBuilder::new();
         builder
@@ -1149,12 +1190,18 @@ impl DigitalAsset {
             builder.token(token);
         }
 
-        let utility_ix = builder
-            .build(LockArgs::V1 {
-                authorization_data: None,
-            })
-            .unwrap()
-            .instruction();
+        // determines if we need to set the rule set
+        let metadata = self.get_metadata(context).await;
+
+        if let Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        }) = metadata.programmable_config
+        {
+            builder.authorization_rules(rule_set);
+            builder.authorization_rules_program(mpl_token_auth_rules::ID);
+        }
+
+        let utility_ix = builder.build(args).unwrap().instruction();
 
         let tx = Transaction::new_signed_with_payer(
             &[utility_ix],
@@ -1194,6 +1241,17 @@ impl DigitalAsset {
             builder.token(token);
         }
 
+        // determines if we need to set the rule set
+        let metadata = self.get_metadata(context).await;
+
+        if let Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        }) = metadata.programmable_config
+        {
+            builder.authorization_rules(rule_set);
+            builder.authorization_rules_program(mpl_token_auth_rules::ID);
+        }
+
         let unlock_ix = builder
             .build(UnlockArgs::V1 {
                 authorization_data: None,
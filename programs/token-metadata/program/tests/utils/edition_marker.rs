@@ -328,8 +328,29 @@ impl EditionMarker {
             .system_program(system_program::ID)
             .initialize_mint(false);
 
+        let mut print_ix = builder.build(print_args).unwrap().instruction();
+
+        // the rule set accounts are read from the remaining accounts, after the
+        // (unused) holder delegate record and delegate accounts
+        if let Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        }) = master_metadata.programmable_config
+        {
+            let (master_token_record, _) =
+                find_token_record_account(&self.metadata_mint_pubkey, &self.metadata_token_pubkey);
+
+            print_ix.accounts.extend([
+                AccountMeta::new_readonly(ID, false),
+                AccountMeta::new_readonly(ID, false),
+                AccountMeta::new_readonly(mpl_token_auth_rules::ID, false),
+                AccountMeta::new_readonly(rule_set, false),
+                AccountMeta::new_readonly(self.metadata_mint_pubkey, false),
+                AccountMeta::new_readonly(master_token_record, false),
+            ]);
+        }
+
         let tx = Transaction::new_signed_with_payer(
-            &[builder.build(print_args).unwrap().instruction()],
+            &[print_ix],
             Some(&context.payer.pubkey()),
             &[&context.payer, &context.payer],
             context.last_blockhash,
@@ -697,9 +718,13 @@ impl EditionMarker {
         match args {
             // Token delegates.
             DelegateArgs::SaleV1 { .. }
+            | DelegateArgs::SaleV2 { .. }
             | DelegateArgs::TransferV1 { .. }
+            | DelegateArgs::TransferV2 { .. }
             | DelegateArgs::UtilityV1 { .. }
+            | DelegateArgs::UtilityV2 { .. }
             | DelegateArgs::StakingV1 { .. }
+            | DelegateArgs::StakingV2 { .. }
             | DelegateArgs::LockedTransferV1 { .. } => {
                 let (token_record, _) =
                     find_token_record_account(&self.mint.pubkey(), &self.token.pubkey());
@@ -709,7 +734,7 @@ impl EditionMarker {
             DelegateArgs::StandardV1 { .. } => { /* nothing to add */ }
 
             // Metadata delegates.
-            DelegateArgs::CollectionV1 { .. } => {
+            DelegateArgs::CollectionV1 { .. } | DelegateArgs::CollectionV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::Collection,
@@ -719,7 +744,7 @@ impl EditionMarker {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::DataV1 { .. } => {
+            DelegateArgs::DataV1 { .. } | DelegateArgs::DataV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::Data,
@@ -729,7 +754,8 @@ impl EditionMarker {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::ProgrammableConfigV1 { .. } => {
+            DelegateArgs::ProgrammableConfigV1 { .. }
+            | DelegateArgs::ProgrammableConfigV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::ProgrammableConfig,
@@ -739,7 +765,7 @@ impl EditionMarker {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::AuthorityItemV1 { .. } => {
+            DelegateArgs::AuthorityItemV1 { .. } | DelegateArgs::AuthorityItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::AuthorityItem,
@@ -749,7 +775,7 @@ impl EditionMarker {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::DataItemV1 { .. } => {
+            DelegateArgs::DataItemV1 { .. } | DelegateArgs::DataItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::DataItem,
@@ -759,7 +785,7 @@ impl EditionMarker {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::CollectionItemV1 { .. } => {
+            DelegateArgs::CollectionItemV1 { .. } | DelegateArgs::CollectionItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::CollectionItem,
@@ -769,7 +795,8 @@ impl EditionMarker {
                 builder.delegate_record(delegate_record);
                 delegate_or_token_record = Some(delegate_record);
             }
-            DelegateArgs::ProgrammableConfigItemV1 { .. } => {
+            DelegateArgs::ProgrammableConfigItemV1 { .. }
+            | DelegateArgs::ProgrammableConfigItemV2 { .. } => {
                 let (delegate_record, _) = find_metadata_delegate_record_account(
                     &self.mint.pubkey(),
                     MetadataDelegateRole::ProgrammableConfigItem,
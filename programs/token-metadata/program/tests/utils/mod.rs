@@ -11,7 +11,7 @@ pub use digital_asset::*;
 pub use edition_marker::*;
 pub use master_edition_v2::MasterEditionV2;
 pub use metadata::{assert_collection_size, Metadata};
-pub use programmable::create_default_metaplex_rule_set;
+pub use programmable::{create_default_metaplex_rule_set, create_lifecycle_rule_set};
 pub use rooster_manager::*;
 use solana_program_test::*;
 use solana_sdk::{
@@ -212,3 +212,74 @@ pub async fn create_default_metaplex_rule_set(
 
     (ruleset_addr, auth_data)
 }
+
+/// Creates a rule set that restricts burning, locking, unlocking and using the asset to
+/// the specified authority. Utility delegates can be approved without restrictions.
+pub async fn create_lifecycle_rule_set(
+    context: &mut ProgramTestContext,
+    creator: Keypair,
+    authority: Pubkey,
+) -> (Pubkey, AuthorizationData) {
+    let name = String::from("Lifecycle Enforcement");
+    let (ruleset_addr, _ruleset_bump) =
+        mpl_token_auth_rules::pda::find_rule_set_address(creator.pubkey(), name.clone());
+
+    let authority_match = Rule::PubkeyMatch {
+        pubkey: authority,
+        field: PayloadKey::Authority.to_string(),
+    };
+
+    let delegate_utility_operation = Operation::Delegate {
+        scenario: DelegateScenario::Token(TokenDelegateRole::Utility),
+    };
+
+    let mut lifecycle_rule_set = RuleSetV1::new(name, creator.pubkey());
+    lifecycle_rule_set
+        .add(delegate_utility_operation.to_string(), Rule::Pass)
+        .unwrap();
+
+    for operation in [
+        Operation::Burn,
+        Operation::Lock,
+        Operation::Unlock,
+        Operation::Use,
+    ] {
+        lifecycle_rule_set
+            .add(operation.to_string(), authority_match.clone())
+            .unwrap();
+    }
+
+    // Serialize the RuleSet using RMP serde.
+    let mut serialized_data = Vec::new();
+    lifecycle_rule_set
+        .serialize(&mut Serializer::new(&mut serialized_data))
+        .unwrap();
+
+    let create_ix = CreateOrUpdateBuilder::new()
+        .rule_set_pda(ruleset_addr)
+        .payer(creator.pubkey())
+        .build(CreateOrUpdateArgs::V1 {
+            serialized_rule_set: serialized_data,
+        })
+        .unwrap()
+        .instruction();
+
+    let create_tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&creator.pubkey()),
+        &[&creator],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(create_tx)
+        .await
+        .expect("creation should succeed");
+
+    let auth_data = AuthorizationData {
+        payload: Payload::new(),
+    };
+
+    (ruleset_addr, auth_data)
+}
@@ -0,0 +1,279 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod utils;
+
+use borsh::BorshDeserialize;
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    program_error::ProgramError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_metadata::{
+    error::MetadataError,
+    instruction::{
+        builders::VerifyCollectionBatchBuilder, InstructionBuilder, VerificationResult,
+        VerifyCollectionBatchArgs,
+    },
+    state::{Collection, CollectionDetails, TokenStandard},
+};
+use utils::*;
+
+mod verify_collection_batch {
+    use super::*;
+
+    async fn create_items(
+        context: &mut ProgramTestContext,
+        collection: &Option<Collection>,
+        count: usize,
+        spl_token_program: Pubkey,
+    ) -> Vec<DigitalAsset> {
+        let mut items = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let mut da = DigitalAsset::new();
+            da.create_and_mint_item_with_collection(
+                context,
+                TokenStandard::NonFungible,
+                None,
+                None,
+                1,
+                collection.clone(),
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+            items.push(da);
+        }
+
+        items
+    }
+
+    async fn process_batch(
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        collection_parent_da: &DigitalAsset,
+        items: &[Pubkey],
+        args: VerifyCollectionBatchArgs,
+    ) -> Result<Vec<VerificationResult>, BanksClientError> {
+        let ix = VerifyCollectionBatchBuilder::new()
+            .authority(authority.pubkey())
+            .collection_mint(collection_parent_da.mint.pubkey())
+            .collection_metadata(collection_parent_da.metadata)
+            .collection_master_edition(collection_parent_da.edition.unwrap())
+            .items(items.to_vec())
+            .build(args)
+            .unwrap()
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        let return_data = result.metadata.unwrap().return_data.unwrap();
+        assert_eq!(return_data.program_id, token_metadata::ID);
+
+        Ok(Vec::<VerificationResult>::try_from_slice(&return_data.data).unwrap())
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn pass_verify_and_unverify_sized_collection(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        #[allow(deprecated)]
+        let collection_details = Some(CollectionDetails::V1 { size: 0 });
+
+        let mut collection_parent_da = DigitalAsset::new();
+        collection_parent_da
+            .create_and_mint_collection_parent(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                None,
+                1,
+                collection_details,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let collection = Some(Collection {
+            key: collection_parent_da.mint.pubkey(),
+            verified: false,
+        });
+
+        let items = create_items(&mut context, &collection, 3, spl_token_program).await;
+
+        // An item that is not a member of the collection.
+        let mut other = DigitalAsset::new();
+        other
+            .create_and_mint_item_with_collection(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                None,
+                1,
+                None,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let mut batch: Vec<Pubkey> = items.iter().map(|da| da.metadata).collect();
+        batch.push(other.metadata);
+
+        // Verify the batch.
+        let payer = context.payer.dirty_clone();
+        let results = process_batch(
+            &mut context,
+            &payer,
+            &collection_parent_da,
+            &batch,
+            VerifyCollectionBatchArgs::VerifyV1,
+        )
+        .await
+        .unwrap();
+
+        let not_found: u64 = ProgramError::from(MetadataError::CollectionNotFound).into();
+        assert_eq!(
+            results,
+            vec![
+                VerificationResult::Updated,
+                VerificationResult::Updated,
+                VerificationResult::Updated,
+                VerificationResult::Failed { error: not_found },
+            ]
+        );
+
+        let verified_collection = Some(Collection {
+            key: collection_parent_da.mint.pubkey(),
+            verified: true,
+        });
+
+        for da in &items {
+            da.assert_item_collection_matches_on_chain(&mut context, &verified_collection)
+                .await;
+        }
+        other
+            .assert_item_collection_matches_on_chain(&mut context, &None)
+            .await;
+
+        #[allow(deprecated)]
+        collection_parent_da
+            .assert_collection_details_matches_on_chain(
+                &mut context,
+                &Some(CollectionDetails::V1 { size: 3 }),
+            )
+            .await;
+
+        // Verifying the same items again leaves them unchanged.
+        let results = process_batch(
+            &mut context,
+            &payer,
+            &collection_parent_da,
+            &batch[..3],
+            VerifyCollectionBatchArgs::VerifyV1,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(results, vec![VerificationResult::Unchanged; 3]);
+
+        // Unverify two of the items.
+        let results = process_batch(
+            &mut context,
+            &payer,
+            &collection_parent_da,
+            &batch[..2],
+            VerifyCollectionBatchArgs::UnverifyV1,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(results, vec![VerificationResult::Updated; 2]);
+
+        for da in &items[..2] {
+            da.assert_item_collection_matches_on_chain(&mut context, &collection)
+                .await;
+        }
+        items[2]
+            .assert_item_collection_matches_on_chain(&mut context, &verified_collection)
+            .await;
+
+        #[allow(deprecated)]
+        collection_parent_da
+            .assert_collection_details_matches_on_chain(
+                &mut context,
+                &Some(CollectionDetails::V1 { size: 1 }),
+            )
+            .await;
+    }
+
+    #[test_case::test_case(spl_token::id() ; "Token Program")]
+    #[test_case::test_case(spl_token_2022::id() ; "Token-2022 Program")]
+    #[tokio::test]
+    async fn incorrect_collection_update_authority(spl_token_program: Pubkey) {
+        let mut context = program_test().start_with_context().await;
+
+        let mut collection_parent_da = DigitalAsset::new();
+        collection_parent_da
+            .create_and_mint_collection_parent(
+                &mut context,
+                TokenStandard::NonFungible,
+                None,
+                None,
+                1,
+                DEFAULT_COLLECTION_DETAILS,
+                spl_token_program,
+            )
+            .await
+            .unwrap();
+
+        let collection = Some(Collection {
+            key: collection_parent_da.mint.pubkey(),
+            verified: false,
+        });
+
+        let items = create_items(&mut context, &collection, 2, spl_token_program).await;
+        let batch: Vec<Pubkey> = items.iter().map(|da| da.metadata).collect();
+
+        // The whole batch fails when the authority is not the collection update authority.
+        let authority = Keypair::new();
+        airdrop(&mut context, &authority.pubkey(), 1_000_000_000)
+            .await
+            .unwrap();
+
+        let err = process_batch(
+            &mut context,
+            &authority,
+            &collection_parent_da,
+            &batch,
+            VerifyCollectionBatchArgs::VerifyV1,
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_error!(err, MetadataError::UpdateAuthorityIncorrect);
+
+        for da in &items {
+            da.assert_item_collection_matches_on_chain(&mut context, &collection)
+                .await;
+        }
+    }
+}